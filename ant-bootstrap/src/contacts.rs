@@ -6,7 +6,10 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-use crate::{cache_store::CacheData, craft_valid_multiaddr_from_str, BootstrapAddr, Error, Result};
+use crate::{
+    cache_store::CacheData, craft_valid_multiaddr_from_str, BootstrapAddr, Error, ProxyConfig,
+    Result,
+};
 use futures::stream::{self, StreamExt};
 use libp2p::Multiaddr;
 use reqwest::Client;
@@ -51,22 +54,34 @@ impl ContactsFetcher {
 
     /// Create a new struct with the provided endpoints
     pub fn with_endpoints(endpoints: Vec<Url>) -> Result<Self> {
-        #[cfg(not(target_arch = "wasm32"))]
-        let request_client = Client::builder()
-            .timeout(Duration::from_secs(FETCH_TIMEOUT_SECS))
-            .build()?;
-        // Wasm does not have the timeout method yet.
-        #[cfg(target_arch = "wasm32")]
-        let request_client = Client::builder().build()?;
-
         Ok(Self {
             max_addrs: usize::MAX,
             endpoints,
-            request_client,
+            request_client: Self::build_request_client(&ProxyConfig::System)?,
             ignore_peer_id: false,
         })
     }
 
+    fn build_request_client(proxy: &ProxyConfig) -> Result<Client> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let request_client = proxy
+            .apply(Client::builder().timeout(Duration::from_secs(FETCH_TIMEOUT_SECS)))?
+            .build()?;
+        // Wasm does not have the timeout method yet, and the proxy is up to the browser.
+        #[cfg(target_arch = "wasm32")]
+        let request_client = {
+            let _ = proxy;
+            Client::builder().build()?
+        };
+        Ok(request_client)
+    }
+
+    /// Set the proxy to fetch the endpoints through. The system proxy is used by default.
+    pub fn set_proxy(&mut self, proxy: &ProxyConfig) -> Result<()> {
+        self.request_client = Self::build_request_client(proxy)?;
+        Ok(())
+    }
+
     /// Set the number of addrs to fetch
     pub fn set_max_addrs(&mut self, max_addrs: usize) {
         self.max_addrs = max_addrs;
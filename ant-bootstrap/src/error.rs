@@ -22,6 +22,8 @@ pub enum Error {
     FailedToObtainAddrsFromUrl(String, usize),
     #[error("Failed to parse Url")]
     FailedToParseUrl,
+    #[error("Invalid proxy: {0}")]
+    InvalidProxy(String),
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
     #[error("JSON error: {0}")]
@@ -10,7 +10,7 @@ use crate::{
     config::cache_file_name,
     craft_valid_multiaddr, craft_valid_multiaddr_from_str,
     error::{Error, Result},
-    BootstrapAddr, BootstrapCacheConfig, BootstrapCacheStore, ContactsFetcher,
+    BootstrapAddr, BootstrapCacheConfig, BootstrapCacheStore, ContactsFetcher, ProxyConfig,
 };
 use clap::Args;
 use libp2p::Multiaddr;
@@ -76,6 +76,21 @@ pub struct PeersArgs {
     ///  - Windows: C:\Users\<username>\AppData\Roaming\autonomi\bootstrap_cache\bootstrap_cache_<network_id>.json
     #[clap(long)]
     pub bootstrap_cache_dir: Option<PathBuf>,
+    /// The proxy to fetch the network contacts through.
+    ///
+    /// By default, the proxy is taken from the `HTTPS_PROXY`, `HTTP_PROXY`, `ALL_PROXY` and
+    /// `NO_PROXY` environment variables or, on Windows and macOS, from the system settings. Set to
+    /// an http(s) URL to use a specific proxy, or to 'none' to connect directly.
+    ///
+    /// The connections to the peers are not proxied.
+    #[clap(
+        long,
+        env = "ANT_PROXY",
+        default_value_t,
+        value_name = "system|none|URL"
+    )]
+    #[serde(default)]
+    pub proxy: ProxyConfig,
 }
 
 impl PeersArgs {
@@ -188,6 +203,7 @@ impl PeersArgs {
                 .map(|url| url.parse::<Url>().map_err(|_| Error::FailedToParseUrl))
                 .collect::<Result<Vec<Url>>>()?;
             let mut contacts_fetcher = ContactsFetcher::with_endpoints(addrs)?;
+            contacts_fetcher.set_proxy(&self.proxy)?;
             if let Some(count) = count {
                 contacts_fetcher.set_max_addrs(count);
             }
@@ -206,6 +222,7 @@ impl PeersArgs {
 
         if !self.disable_mainnet_contacts {
             let mut contacts_fetcher = ContactsFetcher::with_mainnet_endpoints()?;
+            contacts_fetcher.set_proxy(&self.proxy)?;
             if let Some(count) = count {
                 contacts_fetcher.set_max_addrs(count);
             }
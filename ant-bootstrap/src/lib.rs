@@ -17,6 +17,7 @@
 //! - Concurrent Access: File locking for safe multi-process access
 //! - Atomic Operations: Safe cache updates using atomic file operations
 //! - Initial Peer Discovery: Fallback web endpoints for new/stale cache scenarios
+//! - Proxy Support: The web endpoints are fetched through the system proxy, or a configured one
 
 #[macro_use]
 extern crate tracing;
@@ -26,6 +27,7 @@ pub mod config;
 pub mod contacts;
 pub mod error;
 mod initial_peers;
+pub mod proxy;
 
 use ant_protocol::version::{get_network_id, get_truncate_version_str};
 use libp2p::{multiaddr::Protocol, Multiaddr, PeerId};
@@ -38,6 +40,7 @@ pub use config::BootstrapCacheConfig;
 pub use contacts::ContactsFetcher;
 pub use error::{Error, Result};
 pub use initial_peers::{PeersArgs, ANT_PEERS_ENV};
+pub use proxy::ProxyConfig;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 /// Set of addresses for a particular PeerId
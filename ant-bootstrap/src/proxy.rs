@@ -0,0 +1,361 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Proxy settings for the HTTP requests made to fetch the network contacts.
+//!
+//! Only these HTTP requests go through the proxy. The connections to the peers use QUIC, which
+//! HTTP proxies can't carry.
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+use url::Url;
+
+/// The proxy used to fetch the network contacts.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum ProxyConfig {
+    /// Use the proxy from the system settings, if there is one. See [`SystemProxy::detect`].
+    #[default]
+    System,
+    /// Connect directly, ignoring any system proxy.
+    Direct,
+    /// Use the given proxy URL for all requests.
+    Url(String),
+}
+
+impl FromStr for ProxyConfig {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "system" => Ok(Self::System),
+            "none" => Ok(Self::Direct),
+            url => {
+                let parsed =
+                    Url::parse(url).map_err(|err| Error::InvalidProxy(format!("{url}: {err}")))?;
+                if !matches!(parsed.scheme(), "http" | "https") {
+                    return Err(Error::InvalidProxy(format!(
+                        "{url}: only http and https proxies are supported"
+                    )));
+                }
+                Ok(Self::Url(url.to_string()))
+            }
+        }
+    }
+}
+
+impl fmt::Display for ProxyConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::System => write!(f, "system"),
+            Self::Direct => write!(f, "none"),
+            Self::Url(url) => write!(f, "{url}"),
+        }
+    }
+}
+
+impl From<ProxyConfig> for String {
+    fn from(proxy: ProxyConfig) -> Self {
+        proxy.to_string()
+    }
+}
+
+impl TryFrom<String> for ProxyConfig {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        s.parse()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ProxyConfig {
+    /// Set up the proxy of the request client.
+    ///
+    /// The detection built into reqwest is always disabled, so the same settings are used on
+    /// every platform.
+    pub(crate) fn apply(&self, builder: reqwest::ClientBuilder) -> Result<reqwest::ClientBuilder> {
+        let builder = builder.no_proxy();
+        match self {
+            Self::Direct => Ok(builder),
+            Self::Url(url) => {
+                info!("Fetching the network contacts through the proxy {url}");
+                Ok(builder.proxy(reqwest::Proxy::all(url)?))
+            }
+            Self::System => {
+                match SystemProxy::detect() {
+                    Some(system_proxy) => {
+                        info!("Fetching the network contacts through the system proxy {system_proxy:?}");
+                        system_proxy.apply(builder)
+                    }
+                    None => Ok(builder),
+                }
+            }
+        }
+    }
+}
+
+/// A proxy found in the settings of the system.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SystemProxy {
+    pub http: Option<String>,
+    pub https: Option<String>,
+    /// The hosts to connect to directly, comma separated as in `NO_PROXY`.
+    pub no_proxy: Option<String>,
+}
+
+impl SystemProxy {
+    /// Look for a proxy in the environment variables, then in the system settings on Windows and
+    /// macOS.
+    ///
+    /// The environment variables are `HTTPS_PROXY`, `HTTP_PROXY`, `ALL_PROXY` and `NO_PROXY`, in
+    /// either case.
+    pub fn detect() -> Option<Self> {
+        Self::from_env(|key| std::env::var(key).ok()).or_else(Self::from_os_settings)
+    }
+
+    fn from_env(var: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let lookup = |key: &str| {
+            var(&key.to_lowercase())
+                .or_else(|| var(key))
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let all = lookup("ALL_PROXY");
+        let proxy = Self {
+            http: lookup("HTTP_PROXY").or_else(|| all.clone()),
+            https: lookup("HTTPS_PROXY").or(all),
+            no_proxy: lookup("NO_PROXY"),
+        };
+        proxy.is_set().then_some(proxy)
+    }
+
+    #[cfg(target_os = "windows")]
+    fn from_os_settings() -> Option<Self> {
+        let output = std::process::Command::new("reg")
+            .args([
+                "query",
+                r"HKCU\Software\Microsoft\Windows\CurrentVersion\Internet Settings",
+            ])
+            .output()
+            .inspect_err(|err| debug!("Failed to read the Windows proxy settings: {err}"))
+            .ok()?;
+        Self::from_windows_registry(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    #[cfg(target_os = "macos")]
+    fn from_os_settings() -> Option<Self> {
+        let output = std::process::Command::new("scutil")
+            .arg("--proxy")
+            .output()
+            .inspect_err(|err| debug!("Failed to read the macOS proxy settings: {err}"))
+            .ok()?;
+        Self::from_scutil(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    fn from_os_settings() -> Option<Self> {
+        None
+    }
+
+    /// Parse the output of `reg query` for the Internet Settings key.
+    ///
+    /// `ProxyServer` is either a single `host:port` for all protocols, or a list like
+    /// `http=host:port;https=host:port`.
+    #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+    fn from_windows_registry(output: &str) -> Option<Self> {
+        let value = |name: &str| {
+            output.lines().find_map(|line| {
+                let mut fields = line.split_whitespace();
+                if fields.next()? != name {
+                    return None;
+                }
+                let _type = fields.next()?;
+                Some(fields.collect::<Vec<_>>().join(" "))
+            })
+        };
+        if value("ProxyEnable")? != "0x1" {
+            return None;
+        }
+
+        let server = value("ProxyServer")?;
+        let mut proxy = Self::default();
+        if server.contains('=') {
+            for entry in server.split(';') {
+                match entry.split_once('=') {
+                    Some(("http", addr)) => proxy.http = Some(addr.to_string()),
+                    Some(("https", addr)) => proxy.https = Some(addr.to_string()),
+                    _ => {}
+                }
+            }
+        } else {
+            proxy.http = Some(server.clone());
+            proxy.https = Some(server);
+        }
+        proxy.no_proxy = value("ProxyOverride").map(|overrides| {
+            overrides
+                .split(';')
+                .map(str::trim)
+                .filter(|host| !host.is_empty() && *host != "<local>")
+                .collect::<Vec<_>>()
+                .join(",")
+        });
+        proxy.is_set().then_some(proxy)
+    }
+
+    /// Parse the output of `scutil --proxy`.
+    #[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+    fn from_scutil(output: &str) -> Option<Self> {
+        let mut settings = std::collections::HashMap::new();
+        let mut exceptions = vec![];
+        let mut in_exceptions = false;
+        for line in output.lines().map(str::trim) {
+            if line.starts_with("ExceptionsList") {
+                in_exceptions = true;
+                continue;
+            }
+            if in_exceptions {
+                match line.split_once(" : ") {
+                    Some((_index, host)) => exceptions.push(host.trim().to_string()),
+                    None => in_exceptions = false,
+                }
+                continue;
+            }
+            if let Some((key, value)) = line.split_once(" : ") {
+                let _ = settings.insert(key.trim(), value.trim());
+            }
+        }
+
+        let proxy_for = |prefix: &str| {
+            if settings.get(format!("{prefix}Enable").as_str()) != Some(&"1") {
+                return None;
+            }
+            let host = settings.get(format!("{prefix}Proxy").as_str())?;
+            Some(match settings.get(format!("{prefix}Port").as_str()) {
+                Some(port) => format!("{host}:{port}"),
+                None => host.to_string(),
+            })
+        };
+        let proxy = Self {
+            http: proxy_for("HTTP"),
+            https: proxy_for("HTTPS"),
+            no_proxy: (!exceptions.is_empty()).then(|| exceptions.join(",")),
+        };
+        proxy.is_set().then_some(proxy)
+    }
+
+    fn is_set(&self) -> bool {
+        self.http.is_some() || self.https.is_some()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn apply(&self, mut builder: reqwest::ClientBuilder) -> Result<reqwest::ClientBuilder> {
+        let no_proxy = self
+            .no_proxy
+            .as_deref()
+            .and_then(reqwest::NoProxy::from_string);
+        if let Some(http) = &self.http {
+            builder = builder.proxy(reqwest::Proxy::http(http)?.no_proxy(no_proxy.clone()));
+        }
+        if let Some(https) = &self.https {
+            builder = builder.proxy(reqwest::Proxy::https(https)?.no_proxy(no_proxy));
+        }
+        Ok(builder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn proxy_config_is_parsed_and_displayed() {
+        assert_eq!(
+            "system".parse::<ProxyConfig>().unwrap(),
+            ProxyConfig::System
+        );
+        assert_eq!("none".parse::<ProxyConfig>().unwrap(), ProxyConfig::Direct);
+        let url: ProxyConfig = "http://proxy.example.com:3128".parse().unwrap();
+        assert_eq!(url.to_string(), "http://proxy.example.com:3128");
+
+        assert!("proxy.example.com:3128".parse::<ProxyConfig>().is_err());
+        assert!("socks5://proxy.example.com:1080"
+            .parse::<ProxyConfig>()
+            .is_err());
+
+        let json = serde_json::to_string(&url).unwrap();
+        assert_eq!(serde_json::from_str::<ProxyConfig>(&json).unwrap(), url);
+    }
+
+    #[test]
+    fn env_vars_are_detected_with_lowercase_first() {
+        let vars = HashMap::from([
+            ("HTTP_PROXY", "http://upper:3128"),
+            ("http_proxy", "http://lower:3128"),
+            ("ALL_PROXY", "http://all:3128"),
+            ("no_proxy", "localhost,.internal"),
+        ]);
+        let proxy = SystemProxy::from_env(|key| vars.get(key).map(|v| v.to_string())).unwrap();
+        assert_eq!(proxy.http.as_deref(), Some("http://lower:3128"));
+        assert_eq!(proxy.https.as_deref(), Some("http://all:3128"));
+        assert_eq!(proxy.no_proxy.as_deref(), Some("localhost,.internal"));
+
+        let vars = HashMap::from([("HTTPS_PROXY", " "), ("NO_PROXY", "localhost")]);
+        assert_eq!(
+            SystemProxy::from_env(|key| vars.get(key).map(|v| v.to_string())),
+            None
+        );
+    }
+
+    #[test]
+    fn windows_registry_settings_are_parsed() {
+        let output = r"
+HKEY_CURRENT_USER\Software\Microsoft\Windows\CurrentVersion\Internet Settings
+    ProxyEnable    REG_DWORD    0x1
+    ProxyServer    REG_SZ    http=proxy:3128;https=secure-proxy:3129;ftp=ftp-proxy:21
+    ProxyOverride    REG_SZ    *.corp.example.com;<local>
+";
+        let proxy = SystemProxy::from_windows_registry(output).unwrap();
+        assert_eq!(proxy.http.as_deref(), Some("proxy:3128"));
+        assert_eq!(proxy.https.as_deref(), Some("secure-proxy:3129"));
+        assert_eq!(proxy.no_proxy.as_deref(), Some("*.corp.example.com"));
+
+        let output =
+            "    ProxyEnable    REG_DWORD    0x1\n    ProxyServer    REG_SZ    proxy:8080\n";
+        let proxy = SystemProxy::from_windows_registry(output).unwrap();
+        assert_eq!(proxy.http, proxy.https);
+
+        let disabled = output.replace("0x1", "0x0");
+        assert_eq!(SystemProxy::from_windows_registry(&disabled), None);
+    }
+
+    #[test]
+    fn macos_scutil_settings_are_parsed() {
+        let output = "<dictionary> {
+  ExceptionsList : <array> {
+    0 : *.local
+    1 : 169.254/16
+  }
+  HTTPEnable : 1
+  HTTPPort : 8080
+  HTTPProxy : proxy.example.com
+  HTTPSEnable : 0
+  HTTPSPort : 8443
+  HTTPSProxy : secure.example.com
+}
+";
+        let proxy = SystemProxy::from_scutil(output).unwrap();
+        assert_eq!(proxy.http.as_deref(), Some("proxy.example.com:8080"));
+        assert_eq!(proxy.https, None);
+        assert_eq!(proxy.no_proxy.as_deref(), Some("*.local,169.254/16"));
+
+        assert_eq!(SystemProxy::from_scutil("<dictionary> {\n}\n"), None);
+    }
+}
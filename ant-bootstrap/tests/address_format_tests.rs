@@ -50,6 +50,7 @@ async fn test_multiaddr_format_parsing() -> Result<(), Box<dyn std::error::Error
             disable_mainnet_contacts: true,
             ignore_cache: true,
             bootstrap_cache_dir: None,
+            proxy: Default::default(),
         };
 
         let bootstrap_addresses = args.get_bootstrap_addr(None, None).await?;
@@ -88,6 +89,7 @@ async fn test_network_contacts_format() -> Result<(), Box<dyn std::error::Error>
         disable_mainnet_contacts: true,
         ignore_cache: true,
         bootstrap_cache_dir: None,
+        proxy: Default::default(),
     };
 
     let addrs = args.get_bootstrap_addr(None, None).await?;
@@ -36,6 +36,7 @@ async fn test_first_flag() -> Result<(), Box<dyn std::error::Error>> {
         disable_mainnet_contacts: false,
         ignore_cache: false,
         bootstrap_cache_dir: None,
+        proxy: Default::default(),
     };
 
     let addrs = args.get_addrs(Some(config), None).await?;
@@ -62,6 +63,7 @@ async fn test_peer_argument() -> Result<(), Box<dyn std::error::Error>> {
         disable_mainnet_contacts: true,
         ignore_cache: false,
         bootstrap_cache_dir: None,
+        proxy: Default::default(),
     };
 
     let addrs = args.get_addrs(None, None).await?;
@@ -97,6 +99,7 @@ async fn test_network_contacts_fallback() -> Result<(), Box<dyn std::error::Erro
         disable_mainnet_contacts: true,
         ignore_cache: true,
         bootstrap_cache_dir: None,
+        proxy: Default::default(),
     };
 
     let addrs = args.get_addrs(Some(config), None).await?;
@@ -128,6 +131,7 @@ async fn test_local_mode() -> Result<(), Box<dyn std::error::Error>> {
         disable_mainnet_contacts: false,
         ignore_cache: false,
         bootstrap_cache_dir: None,
+        proxy: Default::default(),
     };
 
     let addrs = args.get_addrs(Some(config), None).await?;
@@ -164,6 +168,7 @@ async fn test_test_network_peers() -> Result<(), Box<dyn std::error::Error>> {
         disable_mainnet_contacts: true,
         ignore_cache: false,
         bootstrap_cache_dir: None,
+        proxy: Default::default(),
     };
 
     let addrs = args.get_addrs(Some(config), None).await?;
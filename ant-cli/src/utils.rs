@@ -29,6 +29,7 @@ pub fn collect_upload_summary(
                             tokens_spent += upload_summary.tokens_spent;
                             record_count += upload_summary.record_count;
                         }
+                        Some(_) => {}
                         None => break,
                     }
                 }
@@ -38,11 +39,9 @@ pub fn collect_upload_summary(
 
         // try to drain the event receiver in case there are any more events
         while let Ok(event) = event_receiver.try_recv() {
-            match event {
-                ClientEvent::UploadComplete(upload_summary) => {
-                    tokens_spent += upload_summary.tokens_spent;
-                    record_count += upload_summary.record_count;
-                }
+            if let ClientEvent::UploadComplete(upload_summary) = event {
+                tokens_spent += upload_summary.tokens_spent;
+                record_count += upload_summary.record_count;
             }
         }
 
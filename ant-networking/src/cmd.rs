@@ -10,6 +10,7 @@ use crate::{
     driver::{PendingGetClosestType, SwarmDriver},
     error::{NetworkError, Result},
     event::TerminateNodeReason,
+    ip_mode::{ip_family_statuses, IpFamilyStatus},
     log_markers::Marker,
     multiaddr_pop_p2p, GetRecordCfg, GetRecordError, MsgResponder, NetworkEvent, CLOSE_GROUP_SIZE,
 };
@@ -365,6 +366,10 @@ pub struct SwarmLocalState {
     pub connected_peers: Vec<PeerId>,
     /// List of addresses the node is currently listening on
     pub listeners: Vec<Multiaddr>,
+    /// List of addresses the node currently advertises to its peers
+    pub external_addresses: Vec<Multiaddr>,
+    /// The listeners and advertised addresses of the node for each IP family
+    pub ip_families: Vec<IpFamilyStatus>,
 }
 
 impl SwarmDriver {
@@ -857,9 +862,15 @@ impl SwarmDriver {
             }
             LocalSwarmCmd::GetSwarmLocalState(sender) => {
                 cmd_string = "GetSwarmLocalState";
+                let listeners: Vec<_> = self.swarm.listeners().cloned().collect();
+                let external_addresses: Vec<_> = self.swarm.external_addresses().cloned().collect();
+                let ip_families =
+                    ip_family_statuses(&self.listen_families, &listeners, &external_addresses);
                 let current_state = SwarmLocalState {
                     connected_peers: self.swarm.connected_peers().cloned().collect(),
-                    listeners: self.swarm.listeners().cloned().collect(),
+                    listeners,
+                    external_addresses,
+                    ip_families,
                 };
 
                 sender
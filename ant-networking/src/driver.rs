@@ -14,6 +14,7 @@ use crate::{
     event::{NetworkEvent, NodeEvent},
     external_address::ExternalAddressManager,
     fifo_register::FifoRegister,
+    ip_mode::{IpFamily, IpMode},
     log_markers::Marker,
     multiaddr_pop_p2p,
     network_discovery::NetworkDiscovery,
@@ -21,6 +22,9 @@ use crate::{
     record_store_api::UnifiedRecordStore,
     relay_manager::RelayManager,
     replication_fetcher::ReplicationFetcher,
+    state_snapshot::{
+        StateSnapshot, MAX_SNAPSHOT_PEERS_TO_DIAL, MAX_STATE_SNAPSHOT_AGE, STATE_SNAPSHOT_INTERVAL,
+    },
     target_arch::Interval,
     target_arch::{interval, spawn, Instant},
     transport, GetRecordError, Network, NodeIssue, CLOSE_GROUP_SIZE,
@@ -71,6 +75,7 @@ use std::{
     net::{IpAddr, SocketAddr},
     num::NonZeroUsize,
     path::PathBuf,
+    time::SystemTime,
 };
 use tokio::sync::{mpsc, oneshot};
 use tokio::time::Duration;
@@ -269,6 +274,7 @@ pub struct NetworkBuilder {
     bootstrap_cache: Option<BootstrapCacheStore>,
     concurrency_limit: Option<usize>,
     is_behind_home_network: bool,
+    ip_mode: IpMode,
     keypair: Keypair,
     listen_addr: Option<SocketAddr>,
     local: bool,
@@ -287,6 +293,7 @@ impl NetworkBuilder {
             bootstrap_cache: None,
             concurrency_limit: None,
             is_behind_home_network: false,
+            ip_mode: IpMode::default(),
             keypair,
             listen_addr: None,
             local,
@@ -312,6 +319,12 @@ impl NetworkBuilder {
         self.listen_addr = Some(listen_addr);
     }
 
+    /// Set the IP families to listen on, and which of the external addresses of the node are
+    /// advertised. Defaults to listening on the listen address only.
+    pub fn ip_mode(&mut self, ip_mode: IpMode) {
+        self.ip_mode = ip_mode;
+    }
+
     pub fn request_timeout(&mut self, request_timeout: Duration) {
         self.request_timeout = Some(request_timeout);
     }
@@ -423,6 +436,7 @@ impl NetworkBuilder {
         };
 
         let listen_addr = self.listen_addr;
+        let ip_mode = self.ip_mode;
         #[cfg(feature = "upnp")]
         let upnp = self.upnp;
 
@@ -435,16 +449,30 @@ impl NetworkBuilder {
             upnp,
         )?;
 
-        // Listen on the provided address
+        // Listen on the provided address, along with the address for the other IP family in
+        // dual-stack mode
         let listen_socket_addr = listen_addr.ok_or(NetworkError::ListenAddressNotProvided)?;
+        let listen_socket_addrs = ip_mode.listen_addrs(listen_socket_addr).ok_or(
+            NetworkError::ListenAddressNotAllowedByIpMode {
+                addr: listen_socket_addr,
+                mode: ip_mode,
+            },
+        )?;
 
         // Listen on QUIC
-        let addr_quic = Multiaddr::from(listen_socket_addr.ip())
-            .with(Protocol::Udp(listen_socket_addr.port()))
-            .with(Protocol::QuicV1);
-        swarm_driver
-            .listen_on(addr_quic)
-            .expect("Multiaddr should be supported by our configured transports");
+        for listen_socket_addr in listen_socket_addrs {
+            let addr_quic = Multiaddr::from(listen_socket_addr.ip())
+                .with(Protocol::Udp(listen_socket_addr.port()))
+                .with(Protocol::QuicV1);
+            swarm_driver.listen_on(addr_quic)?;
+            swarm_driver
+                .listen_families
+                .push(IpFamily::of(&listen_socket_addr.ip()));
+        }
+
+        swarm_driver.restored_state_snapshot =
+            StateSnapshot::load(&root_dir, swarm_driver.self_peer_id, MAX_STATE_SNAPSHOT_AGE);
+        swarm_driver.state_snapshot_dir = Some(root_dir);
 
         Ok((network, events_receiver, swarm_driver))
     }
@@ -712,7 +740,7 @@ impl NetworkBuilder {
         // Enable external address manager for public nodes and not behind nat
         let external_address_manager = if !is_client && !self.local && !self.is_behind_home_network
         {
-            Some(ExternalAddressManager::new(peer_id))
+            Some(ExternalAddressManager::new(peer_id, self.ip_mode))
         } else {
             info!("External address manager is disabled for this node.");
             None
@@ -724,6 +752,7 @@ impl NetworkBuilder {
             local: self.local,
             is_client,
             is_behind_home_network: self.is_behind_home_network,
+            listen_families: Vec::new(),
             #[cfg(feature = "open-metrics")]
             close_group: Vec::with_capacity(CLOSE_GROUP_SIZE),
             peers_in_rt: 0,
@@ -761,6 +790,8 @@ impl NetworkBuilder {
             last_replication: None,
             last_connection_pruning_time: Instant::now(),
             network_density_samples: FifoRegister::new(100),
+            state_snapshot_dir: None,
+            restored_state_snapshot: None,
         };
 
         let network = Network::new(
@@ -820,6 +851,8 @@ pub struct SwarmDriver {
     pub(crate) local: bool,
     pub(crate) is_client: bool,
     pub(crate) is_behind_home_network: bool,
+    /// The IP families the node was asked to listen over.
+    pub(crate) listen_families: Vec<IpFamily>,
     #[cfg(feature = "open-metrics")]
     pub(crate) close_group: Vec<PeerId>,
     pub(crate) peers_in_rt: usize,
@@ -871,6 +904,10 @@ pub struct SwarmDriver {
     pub(crate) last_connection_pruning_time: Instant,
     /// FIFO cache for the network density samples
     pub(crate) network_density_samples: FifoRegister,
+    /// The dir to periodically write the state snapshot into. Only set for nodes.
+    pub(crate) state_snapshot_dir: Option<PathBuf>,
+    /// The state snapshot left by a previous run, used to warm up once the driver runs.
+    pub(crate) restored_state_snapshot: Option<StateSnapshot>,
 }
 
 impl SwarmDriver {
@@ -904,6 +941,18 @@ impl SwarmDriver {
             );
         }
 
+        let mut state_snapshot_interval = self.state_snapshot_dir.as_ref().map(|_| {
+            // add a variance of 10% to the interval, to avoid all nodes writing to disk at the same time.
+            interval(Self::duration_with_variance(STATE_SNAPSHOT_INTERVAL, 10))
+        });
+        if let Some(interval) = state_snapshot_interval.as_mut() {
+            interval.tick().await; // first tick completes immediately
+            info!("State snapshot interval is set to {:?}", interval.period());
+        }
+        if let Some(snapshot) = self.restored_state_snapshot.take() {
+            self.warm_up_from_state_snapshot(snapshot);
+        }
+
         // temporarily skip processing IncomingConnectionError swarm event to avoid log spamming
         let mut previous_incoming_connection_error_event = None;
         loop {
@@ -1083,6 +1132,9 @@ impl SwarmDriver {
                     trace!("Bootstrap cache synced in {:?}", start.elapsed());
 
                 },
+                Some(()) = Self::conditional_interval(&mut state_snapshot_interval) => {
+                    self.take_state_snapshot();
+                },
             }
         }
     }
@@ -1153,6 +1205,94 @@ impl SwarmDriver {
             .collect()
     }
 
+    /// Writes the routing table and the replication bookkeeping to disk, off thread.
+    fn take_state_snapshot(&mut self) {
+        let Some(root_dir) = self.state_snapshot_dir.clone() else {
+            return;
+        };
+        let start = Instant::now();
+
+        let mut routing_table = vec![];
+        for kbucket in self.swarm.behaviour_mut().kademlia.kbuckets() {
+            routing_table.extend(kbucket.iter().map(|peer_entry| {
+                (
+                    peer_entry.node.key.into_preimage(),
+                    peer_entry.node.value.clone().into_vec(),
+                )
+            }));
+        }
+        let snapshot = StateSnapshot {
+            taken_at: SystemTime::now(),
+            routing_table,
+            responsible_distance_range: self
+                .swarm
+                .behaviour_mut()
+                .kademlia
+                .store_mut()
+                .get_farthest_replication_distance(),
+            pending_replication: self.replication_fetcher.pending_entries(),
+        };
+        debug!(
+            "Taking a state snapshot with {} peers and {} pending replication entries",
+            snapshot.routing_table.len(),
+            snapshot.pending_replication.len()
+        );
+
+        let self_peer_id = self.self_peer_id;
+        spawn(async move {
+            if let Err(err) = snapshot.write(&root_dir, self_peer_id) {
+                error!("Failed to write the state snapshot: {err}");
+            }
+        });
+        trace!("State snapshot taken in {:?}", start.elapsed());
+    }
+
+    /// Warms up from the state snapshot of a previous run: restores the responsible distance
+    /// range and the pending replication entries, and dials the peers of the routing table.
+    /// The peers are only added back to the routing table once they respond.
+    fn warm_up_from_state_snapshot(&mut self, snapshot: StateSnapshot) {
+        info!(
+            "Warming up from the state snapshot taken at {:?}, with {} peers and {} pending replication entries",
+            snapshot.taken_at,
+            snapshot.routing_table.len(),
+            snapshot.pending_replication.len()
+        );
+
+        if let Some(distance) = snapshot.responsible_distance_range {
+            self.swarm
+                .behaviour_mut()
+                .kademlia
+                .store_mut()
+                .set_distance_range(distance);
+            self.replication_fetcher
+                .set_replication_distance_range(distance);
+        }
+
+        let locally_stored_keys = self
+            .swarm
+            .behaviour_mut()
+            .kademlia
+            .store_mut()
+            .record_addresses_ref();
+        self.replication_fetcher
+            .restore_pending_entries(snapshot.pending_replication, locally_stored_keys);
+
+        for (peer_id, addrs) in snapshot
+            .routing_table
+            .into_iter()
+            .filter(|(peer_id, _)| *peer_id != self.self_peer_id)
+            .take(MAX_SNAPSHOT_PEERS_TO_DIAL)
+        {
+            let opts = DialOpts::peer_id(peer_id)
+                .condition(PeerCondition::NotDialing)
+                .addresses(addrs)
+                .build();
+            if let Err(err) = self.swarm.dial(opts) {
+                debug!("Failed to dial {peer_id:?} from the state snapshot: {err:?}");
+            }
+        }
+    }
+
     /// Dials the given multiaddress. If address contains a peer ID, simultaneous
     /// dials to that peer are prevented.
     pub(crate) fn dial(&mut self, mut addr: Multiaddr) -> Result<(), DialError> {
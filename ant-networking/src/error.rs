@@ -6,6 +6,7 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
+use crate::IpMode;
 use ant_protocol::storage::TransactionAddress;
 use ant_protocol::{messages::Response, storage::RecordKind, NetworkAddress, PrettyPrintRecordKey};
 use libp2p::{
@@ -18,6 +19,7 @@ use std::{
     collections::{HashMap, HashSet},
     fmt::Debug,
     io,
+    net::SocketAddr,
     path::PathBuf,
 };
 use thiserror::Error;
@@ -154,6 +156,9 @@ pub enum NetworkError {
     #[error("Node Listen Address was not provided during construction")]
     ListenAddressNotProvided,
 
+    #[error("Listen address {addr} can't be used in {mode} mode")]
+    ListenAddressNotAllowedByIpMode { addr: SocketAddr, mode: IpMode },
+
     #[cfg(feature = "open-metrics")]
     #[error("Network Metric error")]
     NetworkMetricError,
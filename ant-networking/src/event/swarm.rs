@@ -602,7 +602,7 @@ impl SwarmDriver {
                 event_string = "ExpiredListenAddr";
                 info!("Listen address has expired. {listener_id:?} on {address:?}");
                 if let Some(external_addr_manager) = self.external_address_manager.as_mut() {
-                    external_addr_manager.on_expired_listen_addr(address, &self.swarm);
+                    external_addr_manager.on_expired_listen_addr(address, &mut self.swarm);
                 }
             }
             SwarmEvent::ListenerError { listener_id, error } => {
@@ -6,7 +6,10 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-use crate::{driver::NodeBehaviour, multiaddr_get_ip, multiaddr_get_port, multiaddr_is_global};
+use crate::{
+    driver::NodeBehaviour, multiaddr_get_ip, multiaddr_get_port, multiaddr_is_global, IpFamily,
+    IpMode,
+};
 use itertools::Itertools;
 use libp2p::{multiaddr::Protocol, Multiaddr, PeerId, Swarm};
 use std::{
@@ -31,8 +34,12 @@ const MAX_CANDIDATES: usize = 50;
 pub struct ExternalAddressManager {
     /// All the external addresses of the node
     address_states: Vec<ExternalAddressState>,
-    /// The current IP address of all the external addresses.
-    current_ip_address: Option<IpAddr>,
+    /// The current IP address of the external addresses of each IP family.
+    current_ip_addresses: HashMap<IpFamily, IpAddr>,
+    /// Which families of addresses are added to the swarm.
+    ip_mode: IpMode,
+    /// Whether the IPv4 addresses are kept out of the swarm, in favour of the IPv6 ones.
+    ipv4_withheld: bool,
     /// The peer id of the node
     peer_id: PeerId,
     // Port -> (ok, error) count
@@ -72,10 +79,12 @@ impl PortStats {
 }
 
 impl ExternalAddressManager {
-    pub fn new(peer_id: PeerId) -> Self {
+    pub fn new(peer_id: PeerId, ip_mode: IpMode) -> Self {
         Self {
             address_states: Vec::new(),
-            current_ip_address: None,
+            current_ip_addresses: HashMap::new(),
+            ip_mode,
+            ipv4_withheld: false,
             peer_id,
             connection_stats: HashMap::new(),
             bad_ports: HashSet::new(),
@@ -115,6 +124,18 @@ impl ExternalAddressManager {
             return;
         };
 
+        let Some(ip_address) = multiaddr_get_ip(&address) else {
+            return;
+        };
+        let family = IpFamily::of(&ip_address);
+        if !self.ip_mode.allows(family) {
+            debug!(
+                "{family} addresses are not used in {} mode, ignoring: {address:?}",
+                self.ip_mode
+            );
+            return;
+        }
+
         let Some(port) = multiaddr_get_port(&address) else {
             return;
         };
@@ -134,20 +155,22 @@ impl ExternalAddressManager {
             if state.is_candidate() {
                 if state.num_reports() >= MAX_REPORTS_BEFORE_CONFIRMATION {
                     // if the IP address of our confirmed address is the same as the new address, then add it
-                    let confirmed = if let Some(current_ip_address) = self.current_ip_address {
-                        current_ip_address == *state.ip_address()
-                    } else {
-                        true
-                    };
+                    let confirmed =
+                        if let Some(current_ip_address) = self.current_ip_addresses.get(&family) {
+                            current_ip_address == state.ip_address()
+                        } else {
+                            true
+                        };
 
                     if confirmed {
                         info!("External address confirmed, adding it to swarm: {address:?}");
-                        swarm.add_external_address(address.clone());
                         *state = ExternalAddressState::Confirmed {
                             address: address.clone(),
                             num_reports: state.num_reports(),
                             ip_address: *state.ip_address(),
                         };
+                        self.advertise(address, swarm);
+                        self.apply_ip_preference(swarm);
 
                         Self::print_swarm_state(swarm);
                         return;
@@ -166,7 +189,7 @@ impl ExternalAddressManager {
         }
         // check if we need to update to new ip.
         // TODO: Need to observe this
-        if let Some(current_ip_address) = self.current_ip_address {
+        if let Some(current_ip_address) = self.current_ip_addresses.get(&family).copied() {
             let mut new_ip_map = HashMap::new();
 
             for state in &self.address_states {
@@ -176,7 +199,8 @@ impl ExternalAddressManager {
                     ..
                 } = state
                 {
-                    if current_ip_address != *ip_address
+                    if IpFamily::of(ip_address) == family
+                        && current_ip_address != *ip_address
                         && *num_reports >= MAX_REPORTS_BEFORE_SWITCHING_IP
                     {
                         *new_ip_map.entry(ip_address).or_insert(0) += 1;
@@ -212,9 +236,6 @@ impl ExternalAddressManager {
             return;
         }
 
-        let Some(ip_address) = multiaddr_get_ip(&address) else {
-            return;
-        };
         debug!("Added external address to manager: {address:?}");
         self.address_states.push(ExternalAddressState::Candidate {
             address,
@@ -241,23 +262,30 @@ impl ExternalAddressManager {
         let Some(ip_address) = multiaddr_get_ip(&address) else {
             return;
         };
-
-        // set the current IP address if it is not set
-        if self.current_ip_address.is_none() {
-            self.current_ip_address = Some(ip_address);
+        let family = IpFamily::of(&ip_address);
+        if !self.ip_mode.allows(family) {
+            debug!(
+                "{family} addresses are not used in {} mode, ignoring listen address: {address:?}",
+                self.ip_mode
+            );
+            return;
         }
 
+        // set the current IP address of the family if it is not set
+        let current_ip_address = *self
+            .current_ip_addresses
+            .entry(family)
+            .or_insert(ip_address);
+
         // Switch to new IP early.
-        if let Some(current_ip_address) = self.current_ip_address {
-            if current_ip_address != ip_address {
-                self.address_states.push(ExternalAddressState::Listener {
-                    address: address.clone(),
-                    ip_address,
-                });
-                // this will add it as external addr
-                self.switch_to_new_ip(ip_address, swarm);
-                return;
-            }
+        if current_ip_address != ip_address {
+            self.address_states.push(ExternalAddressState::Listener {
+                address: address.clone(),
+                ip_address,
+            });
+            // this will add it as external addr
+            self.switch_to_new_ip(ip_address, swarm);
+            return;
         }
 
         if let Some(state) = self
@@ -269,11 +297,12 @@ impl ExternalAddressManager {
                 ExternalAddressState::Candidate { ip_address, .. } => {
                     info!("Listen Addr was found as a candidate. Adding it as external to the swarm {address:?}");
 
-                    swarm.add_external_address(address.clone());
                     *state = ExternalAddressState::Listener {
                         address: address.clone(),
                         ip_address: *ip_address,
                     };
+                    self.advertise(address, swarm);
+                    self.apply_ip_preference(swarm);
 
                     Self::print_swarm_state(swarm);
                     return;
@@ -299,11 +328,16 @@ impl ExternalAddressManager {
             address: address.clone(),
             ip_address,
         });
-        swarm.add_external_address(address);
+        self.advertise(address, swarm);
+        self.apply_ip_preference(swarm);
     }
 
     /// Remove a listen-addr from the manager if expired.
-    pub fn on_expired_listen_addr(&mut self, listen_addr: Multiaddr, swarm: &Swarm<NodeBehaviour>) {
+    pub fn on_expired_listen_addr(
+        &mut self,
+        listen_addr: Multiaddr,
+        swarm: &mut Swarm<NodeBehaviour>,
+    ) {
         let address = if multiaddr_is_global(&listen_addr) {
             let Some(address) = self.craft_external_address(&listen_addr) else {
                 error!("Listen address is ill formed, ignoring {listen_addr:?}");
@@ -325,6 +359,7 @@ impl ExternalAddressManager {
                 true
             }
         });
+        self.apply_ip_preference(swarm);
     }
 
     pub fn on_incoming_connection_error(
@@ -372,6 +407,7 @@ impl ExternalAddressManager {
             if !removed_confirmed.is_empty() {
                 info!("Removed external addresses due to connection errors on port {port}: {removed_confirmed:?}");
             }
+            self.apply_ip_preference(swarm);
             Self::print_swarm_state(swarm);
         }
     }
@@ -386,17 +422,18 @@ impl ExternalAddressManager {
         stats.ok = stats.ok.saturating_add(1);
     }
 
-    /// Switch to a new IP address. The old external addresses are removed and the new ones are added.
-    /// The new IP address is set as the current IP address.
+    /// Switch to a new IP address. The old external addresses of the same IP family are removed and
+    /// the new ones are added. The new IP address is set as the current IP address of its family.
     fn switch_to_new_ip(&mut self, new_ip: IpAddr, swarm: &mut Swarm<NodeBehaviour>) {
         info!("Switching to new IpAddr: {new_ip}");
-        self.current_ip_address = Some(new_ip);
+        let family = IpFamily::of(&new_ip);
+        let _ = self.current_ip_addresses.insert(family, new_ip);
 
-        // remove all the old confirmed addresses with different ip
+        // remove all the old confirmed addresses of the family with different ip
         let mut removed_addresses = Vec::new();
         let mut to_remove_indices = Vec::new();
         for (idx, state) in &mut self.address_states.iter().enumerate() {
-            if state.is_candidate() {
+            if state.is_candidate() || IpFamily::of(state.ip_address()) != family {
                 continue;
             }
 
@@ -413,6 +450,7 @@ impl ExternalAddressManager {
         info!("Removed addresses due to change of IP: {removed_addresses:?}");
 
         // add the new confirmed addresses with new ip
+        let mut added_addresses = Vec::new();
         for state in &mut self.address_states {
             if state.ip_address() == &new_ip {
                 match state {
@@ -423,7 +461,7 @@ impl ExternalAddressManager {
                     } => {
                         if *num_reports >= MAX_REPORTS_BEFORE_SWITCHING_IP {
                             info!("Switching to new IP, adding confirmed address: {address:?}");
-                            swarm.add_external_address(address.clone());
+                            added_addresses.push(address.clone());
                             *state = ExternalAddressState::Confirmed {
                                 address: address.clone(),
                                 num_reports: *num_reports,
@@ -434,25 +472,68 @@ impl ExternalAddressManager {
 
                     ExternalAddressState::Listener { address, .. } => {
                         info!("Switching to new IP, adding listen address as external address {address:?}");
-                        swarm.add_external_address(address.clone());
+                        added_addresses.push(address.clone());
                     }
                     _ => {}
                 }
             }
         }
+        for address in added_addresses {
+            self.advertise(address, swarm);
+        }
+        self.apply_ip_preference(swarm);
         Self::print_swarm_state(swarm);
     }
 
+    /// Add a confirmed or listen address to the swarm, unless it is an IPv4 address that is
+    /// currently withheld in favour of the IPv6 addresses.
+    fn advertise(&self, address: Multiaddr, swarm: &mut Swarm<NodeBehaviour>) {
+        if self.ipv4_withheld && IpFamily::of_multiaddr(&address) == Some(IpFamily::Ipv4) {
+            debug!("Preferring IPv6 addresses, not adding {address:?} to the swarm yet");
+            return;
+        }
+        swarm.add_external_address(address);
+    }
+
+    /// When IPv6 is preferred, keep the IPv4 addresses out of the swarm while there is any IPv6
+    /// address to advertise, and add them back once there is none.
+    fn apply_ip_preference(&mut self, swarm: &mut Swarm<NodeBehaviour>) {
+        let has_ipv6 = self.address_states.iter().any(|state| {
+            !state.is_candidate() && IpFamily::of(state.ip_address()) == IpFamily::Ipv6
+        });
+        let withhold = self.ip_mode.prefers_ipv6() && has_ipv6;
+        if withhold == self.ipv4_withheld {
+            return;
+        }
+        self.ipv4_withheld = withhold;
+
+        let ipv4_addresses = self.address_states.iter().filter(|state| {
+            !state.is_candidate() && IpFamily::of(state.ip_address()) == IpFamily::Ipv4
+        });
+        if withhold {
+            info!("Advertising IPv6 addresses only, as IPv6 is preferred");
+            for state in ipv4_addresses {
+                swarm.remove_external_address(state.multiaddr());
+            }
+        } else {
+            info!("No IPv6 address to advertise, falling back to the IPv4 addresses");
+            for state in ipv4_addresses {
+                swarm.add_external_address(state.multiaddr().clone());
+            }
+        }
+    }
+
     /// Craft a proper address Ws or Quic address to avoid any ill formed addresses
     /// Example:
     /// /ip4/131.131.131.131/tcp/53620/ws/p2p/12D3KooWD2aV1f3qkhggzEFaJ24CEFYkSdZF5RKoMLpU6CwExYV5
     /// /ip4/131.131.131.131/udp/53620/quic-v1/p2p/12D3KooWD2aV1f3qkhggzEFaJ24CEFYkSdZF5RKoMLpU6CwExYV5
+    /// /ip6/2a01:4f8::1/udp/53620/quic-v1/p2p/12D3KooWD2aV1f3qkhggzEFaJ24CEFYkSdZF5RKoMLpU6CwExYV5
     fn craft_external_address(&self, given_address: &Multiaddr) -> Option<Multiaddr> {
         let mut output_address = Multiaddr::empty();
 
         let ip = given_address
             .iter()
-            .find(|protocol| matches!(protocol, Protocol::Ip4(_)))?;
+            .find(|protocol| matches!(protocol, Protocol::Ip4(_) | Protocol::Ip6(_)))?;
         output_address.push(ip);
 
         if let Some(ws_protocol) = given_address
@@ -0,0 +1,264 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::{multiaddr_get_ip, multiaddr_is_global};
+use libp2p::Multiaddr;
+use std::{
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IpFamily {
+    Ipv4,
+    Ipv6,
+}
+
+impl IpFamily {
+    pub const ALL: [IpFamily; 2] = [IpFamily::Ipv4, IpFamily::Ipv6];
+
+    pub fn of(ip: &IpAddr) -> Self {
+        match ip {
+            IpAddr::V4(_) => IpFamily::Ipv4,
+            IpAddr::V6(_) => IpFamily::Ipv6,
+        }
+    }
+
+    pub fn of_multiaddr(addr: &Multiaddr) -> Option<Self> {
+        multiaddr_get_ip(addr).map(|ip| Self::of(&ip))
+    }
+
+    fn unspecified(&self) -> IpAddr {
+        match self {
+            IpFamily::Ipv4 => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            IpFamily::Ipv6 => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+        }
+    }
+}
+
+impl fmt::Display for IpFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IpFamily::Ipv4 => write!(f, "IPv4"),
+            IpFamily::Ipv6 => write!(f, "IPv6"),
+        }
+    }
+}
+
+/// The IP families a node listens on, and which of its addresses it advertises.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IpMode {
+    /// Listen on the listen address only, over its IP family.
+    #[default]
+    SingleStack,
+    /// Listen over IPv6 only.
+    ///
+    /// The unspecified IPv4 address, the default listen address, is replaced by the unspecified
+    /// IPv6 address. Any other IPv4 listen address is an error.
+    Ipv6Only,
+    /// Listen over both IPv4 and IPv6, on the same port: on the listen address for its family,
+    /// and on the unspecified address for the other family.
+    DualStack {
+        /// Only advertise the IPv6 addresses of the node while it has any, and fall back to its
+        /// IPv4 addresses otherwise.
+        prefer_ipv6: bool,
+    },
+}
+
+impl IpMode {
+    /// The addresses to listen on for the given listen address, IPv4 first.
+    ///
+    /// Returns `None` if the listen address can't be used with this mode.
+    pub fn listen_addrs(&self, listen_addr: SocketAddr) -> Option<Vec<SocketAddr>> {
+        let family = IpFamily::of(&listen_addr.ip());
+        match self {
+            IpMode::SingleStack => Some(vec![listen_addr]),
+            IpMode::Ipv6Only => match family {
+                IpFamily::Ipv6 => Some(vec![listen_addr]),
+                IpFamily::Ipv4 if listen_addr.ip().is_unspecified() => Some(vec![SocketAddr::new(
+                    IpFamily::Ipv6.unspecified(),
+                    listen_addr.port(),
+                )]),
+                IpFamily::Ipv4 => None,
+            },
+            IpMode::DualStack { .. } => {
+                let mut addrs = IpFamily::ALL
+                    .iter()
+                    .map(|other| {
+                        if *other == family {
+                            listen_addr
+                        } else {
+                            SocketAddr::new(other.unspecified(), listen_addr.port())
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                addrs.sort_by_key(|addr| IpFamily::of(&addr.ip()));
+                Some(addrs)
+            }
+        }
+    }
+
+    /// Whether addresses of the family can be advertised.
+    pub(crate) fn allows(&self, family: IpFamily) -> bool {
+        match self {
+            IpMode::Ipv6Only => family == IpFamily::Ipv6,
+            IpMode::SingleStack | IpMode::DualStack { .. } => true,
+        }
+    }
+
+    pub(crate) fn prefers_ipv6(&self) -> bool {
+        matches!(self, IpMode::DualStack { prefer_ipv6: true })
+    }
+}
+
+impl fmt::Display for IpMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IpMode::SingleStack => write!(f, "single-stack"),
+            IpMode::Ipv6Only => write!(f, "IPv6-only"),
+            IpMode::DualStack { prefer_ipv6: false } => write!(f, "dual-stack"),
+            IpMode::DualStack { prefer_ipv6: true } => write!(f, "dual-stack, preferring IPv6"),
+        }
+    }
+}
+
+/// The state of the listeners of a node for an IP family.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListenerState {
+    /// The node does not listen over the family.
+    Disabled,
+    /// The node should listen over the family, but has no listen address for it.
+    NotListening,
+    /// The node only listens on addresses that can't be reached from the internet, e.g.
+    /// loopback, private or link-local addresses.
+    LocalOnly,
+    /// The node listens on a global address, but doesn't advertise any address of the family.
+    Listening,
+    /// The node advertises at least one address of the family to its peers.
+    Advertised,
+}
+
+impl fmt::Display for ListenerState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListenerState::Disabled => write!(f, "disabled"),
+            ListenerState::NotListening => write!(f, "not listening"),
+            ListenerState::LocalOnly => write!(f, "local only"),
+            ListenerState::Listening => write!(f, "listening"),
+            ListenerState::Advertised => write!(f, "advertised"),
+        }
+    }
+}
+
+/// The listeners and advertised addresses of a node for an IP family.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IpFamilyStatus {
+    pub family: IpFamily,
+    pub state: ListenerState,
+    pub listeners: Vec<Multiaddr>,
+    pub external_addresses: Vec<Multiaddr>,
+}
+
+/// The status of both IP families, given the families the node should listen over.
+pub fn ip_family_statuses(
+    enabled: &[IpFamily],
+    listeners: &[Multiaddr],
+    external_addresses: &[Multiaddr],
+) -> Vec<IpFamilyStatus> {
+    let of_family = |addrs: &[Multiaddr], family: IpFamily| {
+        addrs
+            .iter()
+            .filter(|addr| IpFamily::of_multiaddr(addr) == Some(family))
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    IpFamily::ALL
+        .iter()
+        .map(|family| {
+            let listeners = of_family(listeners, *family);
+            let external_addresses = of_family(external_addresses, *family);
+            let state = if !external_addresses.is_empty() {
+                ListenerState::Advertised
+            } else if !enabled.contains(family) && listeners.is_empty() {
+                ListenerState::Disabled
+            } else if listeners.is_empty() {
+                ListenerState::NotListening
+            } else if listeners.iter().any(multiaddr_is_global) {
+                ListenerState::Listening
+            } else {
+                ListenerState::LocalOnly
+            };
+            IpFamilyStatus {
+                family: *family,
+                state,
+                listeners,
+                external_addresses,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn socket_addr(addr: &str) -> SocketAddr {
+        addr.parse().unwrap()
+    }
+
+    #[test]
+    fn listen_addrs_follow_the_mode() {
+        let unspecified_v4 = socket_addr("0.0.0.0:12000");
+        let v4 = socket_addr("10.0.0.1:12000");
+        let v6 = socket_addr("[2001:db8::1]:12000");
+
+        assert_eq!(
+            IpMode::SingleStack.listen_addrs(v4),
+            Some(vec![socket_addr("10.0.0.1:12000")])
+        );
+
+        assert_eq!(
+            IpMode::Ipv6Only.listen_addrs(unspecified_v4),
+            Some(vec![socket_addr("[::]:12000")])
+        );
+        assert_eq!(IpMode::Ipv6Only.listen_addrs(v6), Some(vec![v6]));
+        assert_eq!(IpMode::Ipv6Only.listen_addrs(v4), None);
+
+        let dual_stack = IpMode::DualStack { prefer_ipv6: false };
+        assert_eq!(
+            dual_stack.listen_addrs(unspecified_v4),
+            Some(vec![unspecified_v4, socket_addr("[::]:12000")])
+        );
+        assert_eq!(dual_stack.listen_addrs(v6), Some(vec![unspecified_v4, v6]));
+    }
+
+    #[test]
+    fn statuses_report_each_family() {
+        let listeners: Vec<Multiaddr> = vec![
+            "/ip4/127.0.0.1/udp/12000/quic-v1".parse().unwrap(),
+            "/ip4/8.8.8.8/udp/12000/quic-v1".parse().unwrap(),
+            "/ip6/::1/udp/12000/quic-v1".parse().unwrap(),
+        ];
+        let external: Vec<Multiaddr> = vec!["/ip4/8.8.8.8/udp/12000/quic-v1".parse().unwrap()];
+
+        let statuses = ip_family_statuses(&IpFamily::ALL, &listeners, &external);
+        assert_eq!(statuses[0].family, IpFamily::Ipv4);
+        assert_eq!(statuses[0].state, ListenerState::Advertised);
+        assert_eq!(statuses[0].listeners.len(), 2);
+        assert_eq!(statuses[1].family, IpFamily::Ipv6);
+        assert_eq!(statuses[1].state, ListenerState::LocalOnly);
+
+        let statuses = ip_family_statuses(&IpFamily::ALL, &listeners[..2], &[]);
+        assert_eq!(statuses[0].state, ListenerState::Listening);
+        assert_eq!(statuses[1].state, ListenerState::NotListening);
+
+        let statuses = ip_family_statuses(&[IpFamily::Ipv4], &listeners[..2], &[]);
+        assert_eq!(statuses[1].state, ListenerState::Disabled);
+    }
+}
@@ -17,6 +17,7 @@ mod error;
 mod event;
 mod external_address;
 mod fifo_register;
+mod ip_mode;
 mod log_markers;
 #[cfg(feature = "open-metrics")]
 mod metrics;
@@ -25,6 +26,7 @@ mod record_store;
 mod record_store_api;
 mod relay_manager;
 mod replication_fetcher;
+mod state_snapshot;
 pub mod target_arch;
 mod transactions;
 mod transport;
@@ -40,6 +42,7 @@ pub use self::{
     },
     error::{GetRecordError, NetworkError},
     event::{MsgResponder, NetworkEvent},
+    ip_mode::{ip_family_statuses, IpFamily, IpFamilyStatus, IpMode, ListenerState},
     record_store::NodeRecordStore,
     transactions::get_transactions_from_record,
 };
@@ -1171,7 +1174,7 @@ pub fn get_quorum_value(quorum: &Quorum) -> usize {
     }
 }
 
-/// Verifies if `Multiaddr` contains IPv4 address that is not global.
+/// Verifies if `Multiaddr` contains an IPv4 or IPv6 address that is not global.
 /// This is used to filter out unroutable addresses from the Kademlia routing table.
 pub fn multiaddr_is_global(multiaddr: &Multiaddr) -> bool {
     !multiaddr.iter().any(|addr| match addr {
@@ -1185,6 +1188,18 @@ pub fn multiaddr_is_global(multiaddr: &Multiaddr) -> bool {
                 | ip.is_documentation()
                 | ip.is_broadcast()
         }
+        Protocol::Ip6(ip) => {
+            // Based on the nightly `is_global` method (`Ipv6Addr::is_global`), only using what is available in stable.
+            let segments = ip.segments();
+            ip.is_unspecified()
+                | ip.is_loopback()
+                // Unique local addresses, fc00::/7
+                | ((segments[0] & 0xfe00) == 0xfc00)
+                // Link-local unicast addresses, fe80::/10
+                | ((segments[0] & 0xffc0) == 0xfe80)
+                // Documentation addresses, 2001:db8::/32
+                | (segments[0] == 0x2001 && segments[1] == 0xdb8)
+        }
         _ => false,
     })
 }
@@ -243,6 +243,54 @@ impl ReplicationFetcher {
         self.next_keys_to_fetch()
     }
 
+    // Returns the entries that are pending or being fetched, to be included in a state snapshot.
+    pub(crate) fn pending_entries(&self) -> Vec<(NetworkAddress, RecordType, PeerId)> {
+        self.to_be_fetched
+            .keys()
+            .map(|(key, record_type, holder)| (key, record_type, holder))
+            .chain(
+                self.on_going_fetches
+                    .iter()
+                    .map(|((key, record_type), (holder, _))| (key, record_type, holder)),
+            )
+            .map(|(key, record_type, holder)| {
+                (
+                    NetworkAddress::from_record_key(key),
+                    record_type.clone(),
+                    *holder,
+                )
+            })
+            .collect()
+    }
+
+    // Restores the pending entries from a state snapshot taken before a restart.
+    // Entries that got stored meanwhile, or are out of range, are skipped.
+    // They will be fetched alongside the next incoming keys.
+    pub(crate) fn restore_pending_entries(
+        &mut self,
+        entries: Vec<(NetworkAddress, RecordType, PeerId)>,
+        locally_stored_keys: &HashMap<RecordKey, (NetworkAddress, RecordType)>,
+    ) {
+        let self_address = NetworkAddress::from_peer(self.self_peer_id);
+        let mut restored = 0;
+        for (addr, record_type, holder) in entries {
+            let key = addr.to_record_key();
+            if locally_stored_keys.contains_key(&key) {
+                continue;
+            }
+            if let Some(distance_range) = self.distance_range {
+                if convert_distance_to_u256(&self_address.distance(&addr)) > distance_range {
+                    continue;
+                }
+            }
+            let _ = self
+                .to_be_fetched
+                .insert((key, record_type, holder), Instant::now() + PENDING_TIMEOUT);
+            restored += 1;
+        }
+        info!("Restored {restored} pending replication entries from the state snapshot");
+    }
+
     // Returns the set of keys that has to be fetched from the peer/network.
     // Target must not be under-fetching
     // and no more than MAX_PARALLEL_FETCH fetches to be undertaken at the same time.
@@ -512,4 +560,44 @@ mod tests {
             "all keys should be in range and in the fetcher"
         );
     }
+
+    #[test]
+    fn pending_entries_are_restored_unless_stored() {
+        let peer_id = PeerId::random();
+        let (event_sender, _event_receiver) = mpsc::channel(4);
+        let mut replication_fetcher = ReplicationFetcher::new(peer_id, event_sender.clone());
+
+        let incoming_keys: Vec<_> = (0..5)
+            .map(|_| {
+                let random_data: Vec<u8> = (0..50).map(|_| rand::random::<u8>()).collect();
+                let key = NetworkAddress::from_record_key(&RecordKey::from(random_data));
+                (key, RecordType::Chunk)
+            })
+            .collect();
+        let _ = replication_fetcher.add_keys(
+            PeerId::random(),
+            incoming_keys.clone(),
+            &Default::default(),
+        );
+        let pending = replication_fetcher.pending_entries();
+        assert_eq!(pending.len(), incoming_keys.len());
+
+        let (stored_key, stored_type) = incoming_keys[0].clone();
+        let locally_stored_keys = HashMap::from([(
+            stored_key.to_record_key(),
+            (stored_key.clone(), stored_type),
+        )]);
+        let mut restarted_fetcher = ReplicationFetcher::new(peer_id, event_sender);
+        restarted_fetcher.restore_pending_entries(pending, &locally_stored_keys);
+
+        assert_eq!(
+            restarted_fetcher.to_be_fetched.len(),
+            incoming_keys.len() - 1
+        );
+        assert!(restarted_fetcher.on_going_fetches.is_empty());
+        assert_eq!(
+            restarted_fetcher.next_keys_to_fetch().len(),
+            incoming_keys.len() - 1
+        );
+    }
 }
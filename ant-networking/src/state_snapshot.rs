@@ -0,0 +1,256 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use ant_evm::U256;
+use ant_protocol::{storage::RecordType, version::get_network_id, NetworkAddress};
+use libp2p::{Multiaddr, PeerId};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, SystemTime},
+};
+
+const STATE_SNAPSHOT_FILENAME: &str = "state_snapshot";
+
+/// Bumped whenever the layout of the snapshot file changes. Snapshots of other versions are ignored.
+const STATE_SNAPSHOT_VERSION: u8 = 1;
+
+/// Interval to take a snapshot of the routing and replication state.
+pub(crate) const STATE_SNAPSHOT_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Snapshots older than this are not loaded, as too many of the peers in them will have gone.
+pub(crate) const MAX_STATE_SNAPSHOT_AGE: Duration = Duration::from_secs(3600);
+
+/// Max number of the peers from a snapshot to dial at startup.
+pub(crate) const MAX_SNAPSHOT_PEERS_TO_DIAL: usize = 100;
+
+/// The routing and replication bookkeeping of a node, periodically written to disk so that a
+/// restarted node can warm up its caches instead of rebuilding them from scratch.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct StateSnapshot {
+    pub(crate) taken_at: SystemTime,
+    /// The peers in the routing table, closest buckets first, along with their addresses.
+    pub(crate) routing_table: Vec<(PeerId, Vec<Multiaddr>)>,
+    pub(crate) responsible_distance_range: Option<U256>,
+    /// Records that were still to be fetched from their holders.
+    pub(crate) pending_replication: Vec<(NetworkAddress, RecordType, PeerId)>,
+}
+
+/// The on-disk form of the snapshot. Peers and addresses are kept as strings, so the format
+/// doesn't depend on the serde support of libp2p.
+#[derive(Serialize, Deserialize)]
+struct StateSnapshotFile {
+    version: u8,
+    peer_id: String,
+    network_id: String,
+    taken_at: SystemTime,
+    routing_table: Vec<(String, Vec<String>)>,
+    responsible_distance_range: Option<[u8; 32]>,
+    pending_replication: Vec<(NetworkAddress, RecordType, String)>,
+}
+
+impl StateSnapshot {
+    pub(crate) fn path(root_dir: &Path) -> PathBuf {
+        root_dir.join(STATE_SNAPSHOT_FILENAME)
+    }
+
+    /// Write the snapshot of `self_peer_id` to a temporary file first, then move it into place, so
+    /// a crash while writing can't leave a truncated snapshot behind.
+    pub(crate) fn write(&self, root_dir: &Path, self_peer_id: PeerId) -> std::io::Result<()> {
+        let file = StateSnapshotFile {
+            version: STATE_SNAPSHOT_VERSION,
+            peer_id: self_peer_id.to_string(),
+            network_id: get_network_id(),
+            taken_at: self.taken_at,
+            routing_table: self
+                .routing_table
+                .iter()
+                .map(|(peer, addrs)| {
+                    (
+                        peer.to_string(),
+                        addrs.iter().map(|addr| addr.to_string()).collect(),
+                    )
+                })
+                .collect(),
+            responsible_distance_range: self
+                .responsible_distance_range
+                .map(|distance| distance.to_be_bytes()),
+            pending_replication: self
+                .pending_replication
+                .iter()
+                .map(|(addr, record_type, holder)| {
+                    (addr.clone(), record_type.clone(), holder.to_string())
+                })
+                .collect(),
+        };
+        let bytes = rmp_serde::to_vec(&file).map_err(std::io::Error::other)?;
+
+        let path = Self::path(root_dir);
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, bytes)?;
+        fs::rename(tmp_path, path)
+    }
+
+    /// Load the snapshot left by a previous run, if there is a usable one.
+    ///
+    /// Snapshots are ignored if they were taken by another peer or for another network, or if
+    /// they are older than `max_age`.
+    pub(crate) fn load(root_dir: &Path, self_peer_id: PeerId, max_age: Duration) -> Option<Self> {
+        let path = Self::path(root_dir);
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                info!("No state snapshot loaded from {path:?}: {err}");
+                return None;
+            }
+        };
+        let file: StateSnapshotFile = match rmp_serde::from_slice(&bytes) {
+            Ok(file) => file,
+            Err(err) => {
+                warn!("Ignoring the state snapshot at {path:?}, as it could not be read: {err}");
+                return None;
+            }
+        };
+
+        if file.version != STATE_SNAPSHOT_VERSION {
+            info!(
+                "Ignoring the state snapshot, as its version {} is not {STATE_SNAPSHOT_VERSION}",
+                file.version
+            );
+            return None;
+        }
+        if file.peer_id != self_peer_id.to_string() {
+            info!(
+                "Ignoring the state snapshot, as it was taken by peer {}",
+                file.peer_id
+            );
+            return None;
+        }
+        if file.network_id != get_network_id() {
+            info!(
+                "Ignoring the state snapshot, as it was taken on network {}",
+                file.network_id
+            );
+            return None;
+        }
+        match SystemTime::now().duration_since(file.taken_at) {
+            Ok(age) if age <= max_age => {
+                debug!("The state snapshot is {age:?} old");
+            }
+            Ok(age) => {
+                info!("Ignoring the state snapshot, as it is stale ({age:?} old)");
+                return None;
+            }
+            Err(_) => {
+                warn!("Ignoring the state snapshot, as it was taken in the future");
+                return None;
+            }
+        }
+
+        let routing_table = file
+            .routing_table
+            .into_iter()
+            .filter_map(|(peer, addrs)| {
+                let peer = PeerId::from_str(&peer).ok()?;
+                let addrs = addrs
+                    .iter()
+                    .filter_map(|addr| Multiaddr::from_str(addr).ok())
+                    .collect::<Vec<_>>();
+                (!addrs.is_empty()).then_some((peer, addrs))
+            })
+            .collect();
+        let pending_replication = file
+            .pending_replication
+            .into_iter()
+            .filter_map(|(addr, record_type, holder)| {
+                Some((addr, record_type, PeerId::from_str(&holder).ok()?))
+            })
+            .collect();
+
+        Some(Self {
+            taken_at: file.taken_at,
+            routing_table,
+            responsible_distance_range: file.responsible_distance_range.map(U256::from_be_bytes),
+            pending_replication,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ant_protocol::storage::ChunkAddress;
+    use xor_name::XorName;
+
+    fn snapshot(taken_at: SystemTime) -> StateSnapshot {
+        let holder = PeerId::random();
+        StateSnapshot {
+            taken_at,
+            routing_table: vec![
+                (
+                    PeerId::random(),
+                    vec!["/ip4/10.0.0.1/udp/12000/quic-v1".parse().unwrap()],
+                ),
+                (
+                    holder,
+                    vec![
+                        "/ip4/10.0.0.2/udp/12000/quic-v1".parse().unwrap(),
+                        "/ip6/::1/udp/12000/quic-v1".parse().unwrap(),
+                    ],
+                ),
+            ],
+            responsible_distance_range: Some(U256::from(1u64) << 250usize),
+            pending_replication: vec![(
+                NetworkAddress::from_chunk_address(ChunkAddress::new(XorName::random(
+                    &mut rand::thread_rng(),
+                ))),
+                RecordType::Chunk,
+                holder,
+            )],
+        }
+    }
+
+    #[test]
+    fn snapshot_is_restored_by_the_same_peer() {
+        let dir = assert_fs::TempDir::new().unwrap();
+        let peer_id = PeerId::random();
+        let snapshot = snapshot(SystemTime::now());
+        snapshot.write(dir.path(), peer_id).unwrap();
+
+        let loaded = StateSnapshot::load(dir.path(), peer_id, MAX_STATE_SNAPSHOT_AGE);
+        assert_eq!(loaded, Some(snapshot));
+        assert!(!StateSnapshot::path(dir.path())
+            .with_extension("tmp")
+            .exists());
+
+        assert!(
+            StateSnapshot::load(dir.path(), PeerId::random(), MAX_STATE_SNAPSHOT_AGE).is_none()
+        );
+    }
+
+    #[test]
+    fn stale_or_unreadable_snapshots_are_ignored() {
+        let dir = assert_fs::TempDir::new().unwrap();
+        let peer_id = PeerId::random();
+        assert!(StateSnapshot::load(dir.path(), peer_id, MAX_STATE_SNAPSHOT_AGE).is_none());
+
+        let taken_at = SystemTime::now() - Duration::from_secs(600);
+        snapshot(taken_at).write(dir.path(), peer_id).unwrap();
+        assert!(StateSnapshot::load(dir.path(), peer_id, Duration::from_secs(60)).is_none());
+        assert!(StateSnapshot::load(dir.path(), peer_id, MAX_STATE_SNAPSHOT_AGE).is_some());
+
+        let taken_at = SystemTime::now() + Duration::from_secs(600);
+        snapshot(taken_at).write(dir.path(), peer_id).unwrap();
+        assert!(StateSnapshot::load(dir.path(), peer_id, MAX_STATE_SNAPSHOT_AGE).is_none());
+
+        fs::write(StateSnapshot::path(dir.path()), b"not a snapshot").unwrap();
+        assert!(StateSnapshot::load(dir.path(), peer_id, MAX_STATE_SNAPSHOT_AGE).is_none());
+    }
+}
@@ -9,7 +9,9 @@
 use ant_bootstrap::PeersArgs;
 use ant_evm::{EvmNetwork, RewardsAddress};
 use ant_logging::LogFormat;
-use ant_service_management::node::push_arguments_from_peers_args;
+use ant_service_management::{
+    node::push_arguments_from_peers_args, DaemonTextfileOptions, DaemonWatchdogOptions,
+};
 use color_eyre::{eyre::eyre, Result};
 use service_manager::{ServiceInstallCtx, ServiceLabel};
 use std::{
@@ -19,7 +21,7 @@ use std::{
     str::FromStr,
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum PortRange {
     Single(u16),
     Range(u16, u16),
@@ -300,8 +302,10 @@ pub struct AddDaemonServiceOptions {
     pub daemon_install_bin_path: PathBuf,
     pub daemon_src_bin_path: PathBuf,
     pub port: u16,
+    pub textfile: DaemonTextfileOptions,
     pub user: String,
     pub version: String,
+    pub watchdog: DaemonWatchdogOptions,
 }
 
 #[cfg(test)]
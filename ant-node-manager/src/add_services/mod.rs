@@ -427,13 +427,16 @@ pub fn add_daemon(
         options.daemon_install_bin_path.clone(),
     )?;
 
+    let mut args = vec![
+        OsString::from("--port"),
+        OsString::from(options.port.to_string()),
+        OsString::from("--address"),
+        OsString::from(options.address.to_string()),
+    ];
+    options.textfile.push_arguments(&mut args);
+    options.watchdog.push_arguments(&mut args);
     let install_ctx = ServiceInstallCtx {
-        args: vec![
-            OsString::from("--port"),
-            OsString::from(options.port.to_string()),
-            OsString::from("--address"),
-            OsString::from(options.address.to_string()),
-        ],
+        args,
         autostart: true,
         contents: None,
         environment: options.env_variables,
@@ -451,7 +454,9 @@ pub fn add_daemon(
                 pid: None,
                 service_name: DAEMON_SERVICE_NAME.to_string(),
                 status: ServiceStatus::Added,
+                textfile: options.textfile,
                 version: options.version,
+                watchdog: options.watchdog,
             };
             node_registry.daemon = Some(daemon);
             info!("Daemon service has been added successfully");
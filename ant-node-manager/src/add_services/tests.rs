@@ -21,7 +21,8 @@ use ant_evm::{AttoTokens, CustomNetwork, EvmNetwork, RewardsAddress};
 use ant_service_management::{auditor::AuditorServiceData, control::ServiceControl};
 use ant_service_management::{error::Result as ServiceControlResult, NatDetectionStatus};
 use ant_service_management::{
-    DaemonServiceData, FaucetServiceData, NodeRegistry, NodeServiceData, ServiceStatus,
+    DaemonServiceData, DaemonTextfileOptions, DaemonWatchdogOptions, FaucetServiceData,
+    NodeRegistry, NodeServiceData, ServiceStatus,
 };
 use assert_fs::prelude::*;
 use assert_matches::assert_matches;
@@ -117,6 +118,7 @@ async fn add_genesis_node_should_use_latest_version_and_add_one_service() -> Res
         disable_mainnet_contacts: false,
         ignore_cache: false,
         bootstrap_cache_dir: None,
+        proxy: Default::default(),
     };
 
     let install_ctx = InstallNodeServiceCtxBuilder {
@@ -270,6 +272,7 @@ async fn add_genesis_node_should_return_an_error_if_there_is_already_a_genesis_n
         disable_mainnet_contacts: false,
         ignore_cache: false,
         bootstrap_cache_dir: None,
+        proxy: Default::default(),
     };
     let mut node_registry = NodeRegistry {
         auditor: None,
@@ -410,6 +413,7 @@ async fn add_genesis_node_should_return_an_error_if_count_is_greater_than_1() ->
         disable_mainnet_contacts: false,
         ignore_cache: false,
         bootstrap_cache_dir: None,
+        proxy: Default::default(),
     };
 
     let latest_version = "0.96.4";
@@ -1126,6 +1130,7 @@ async fn add_node_should_create_service_file_with_first_arg() -> Result<()> {
         disable_mainnet_contacts: false,
         ignore_cache: false,
         bootstrap_cache_dir: None,
+        proxy: Default::default(),
     };
 
     let mut seq = Sequence::new();
@@ -1281,6 +1286,7 @@ async fn add_node_should_create_service_file_with_peers_args() -> Result<()> {
         disable_mainnet_contacts: false,
         ignore_cache: false,
         bootstrap_cache_dir: None,
+        proxy: Default::default(),
     };
 
     let mut seq = Sequence::new();
@@ -1435,6 +1441,7 @@ async fn add_node_should_create_service_file_with_local_arg() -> Result<()> {
         disable_mainnet_contacts: false,
         ignore_cache: false,
         bootstrap_cache_dir: None,
+        proxy: Default::default(),
     };
 
     let mut seq = Sequence::new();
@@ -1590,6 +1597,7 @@ async fn add_node_should_create_service_file_with_network_contacts_url_arg() ->
         disable_mainnet_contacts: false,
         ignore_cache: false,
         bootstrap_cache_dir: None,
+        proxy: Default::default(),
     };
 
     let mut seq = Sequence::new();
@@ -1746,6 +1754,7 @@ async fn add_node_should_create_service_file_with_testnet_arg() -> Result<()> {
         disable_mainnet_contacts: true,
         ignore_cache: false,
         bootstrap_cache_dir: None,
+        proxy: Default::default(),
     };
 
     let mut seq = Sequence::new();
@@ -1898,6 +1907,7 @@ async fn add_node_should_create_service_file_with_ignore_cache_arg() -> Result<(
         disable_mainnet_contacts: false,
         ignore_cache: true,
         bootstrap_cache_dir: None,
+        proxy: Default::default(),
     };
 
     let mut seq = Sequence::new();
@@ -2050,6 +2060,7 @@ async fn add_node_should_create_service_file_with_custom_bootstrap_cache_path()
         disable_mainnet_contacts: false,
         ignore_cache: false,
         bootstrap_cache_dir: Some(PathBuf::from("/path/to/bootstrap/cache")),
+        proxy: Default::default(),
     };
 
     let mut seq = Sequence::new();
@@ -5568,8 +5579,10 @@ async fn add_daemon_should_add_a_daemon_service() -> Result<()> {
             daemon_src_bin_path: daemon_download_path.to_path_buf(),
             env_variables: Some(vec![("ANT_LOG".to_string(), "ALL".to_string())]),
             port: 8080,
+            textfile: DaemonTextfileOptions::default(),
             user: get_username(),
             version: latest_version.to_string(),
+            watchdog: DaemonWatchdogOptions::default(),
         },
         &mut node_registry,
         &mock_service_control,
@@ -5590,6 +5603,172 @@ async fn add_daemon_should_add_a_daemon_service() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn add_daemon_should_supply_the_watchdog_options() -> Result<()> {
+    let tmp_data_dir = assert_fs::TempDir::new()?;
+    let node_reg_path = tmp_data_dir.child("node_reg.json");
+
+    let latest_version = "0.96.4";
+    let temp_dir = assert_fs::TempDir::new()?;
+    let daemon_install_dir = temp_dir.child("install");
+    daemon_install_dir.create_dir_all()?;
+    let daemon_install_path = daemon_install_dir.child(DAEMON_FILE_NAME);
+    let daemon_download_path = temp_dir.child(DAEMON_FILE_NAME);
+    daemon_download_path.write_binary(b"fake daemon bin")?;
+
+    let mut node_registry = NodeRegistry {
+        daemon: None,
+        auditor: None,
+        faucet: None,
+        environment_variables: None,
+        nat_status: None,
+        nodes: vec![],
+        save_path: node_reg_path.to_path_buf(),
+    };
+
+    let mut mock_service_control = MockServiceControl::new();
+
+    mock_service_control
+        .expect_install()
+        .times(1)
+        .with(
+            eq(ServiceInstallCtx {
+                args: vec![
+                    OsString::from("--port"),
+                    OsString::from("8080"),
+                    OsString::from("--address"),
+                    OsString::from("127.0.0.1"),
+                    OsString::from("--max-cpu-percent"),
+                    OsString::from("150"),
+                    OsString::from("--max-memory-mb"),
+                    OsString::from("512"),
+                    OsString::from("--max-pending-requests"),
+                    OsString::from("20"),
+                    OsString::from("--restart-on-resource-limit"),
+                    OsString::from("--watchdog-interval"),
+                    OsString::from("30"),
+                ],
+                autostart: true,
+                contents: None,
+                environment: None,
+                label: "antctld".parse()?,
+                program: daemon_install_path.to_path_buf(),
+                username: Some(get_username()),
+                working_directory: None,
+            }),
+            eq(false),
+        )
+        .returning(|_, _| Ok(()));
+
+    let watchdog = DaemonWatchdogOptions {
+        max_cpu_percent: Some(150.0),
+        max_memory_mb: Some(512),
+        max_pending_requests: Some(20),
+        restart_on_resource_limit: true,
+        watchdog_interval: Some(30),
+    };
+    add_daemon(
+        AddDaemonServiceOptions {
+            address: Ipv4Addr::new(127, 0, 0, 1),
+            daemon_install_bin_path: daemon_install_path.to_path_buf(),
+            daemon_src_bin_path: daemon_download_path.to_path_buf(),
+            env_variables: None,
+            port: 8080,
+            textfile: DaemonTextfileOptions::default(),
+            user: get_username(),
+            version: latest_version.to_string(),
+            watchdog: watchdog.clone(),
+        },
+        &mut node_registry,
+        &mock_service_control,
+    )?;
+
+    let saved_daemon = node_registry.daemon.unwrap();
+    assert_eq!(saved_daemon.watchdog, watchdog);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn add_daemon_should_supply_the_textfile_options() -> Result<()> {
+    let tmp_data_dir = assert_fs::TempDir::new()?;
+    let node_reg_path = tmp_data_dir.child("node_reg.json");
+
+    let latest_version = "0.96.4";
+    let temp_dir = assert_fs::TempDir::new()?;
+    let daemon_install_dir = temp_dir.child("install");
+    daemon_install_dir.create_dir_all()?;
+    let daemon_install_path = daemon_install_dir.child(DAEMON_FILE_NAME);
+    let daemon_download_path = temp_dir.child(DAEMON_FILE_NAME);
+    daemon_download_path.write_binary(b"fake daemon bin")?;
+
+    let mut node_registry = NodeRegistry {
+        daemon: None,
+        auditor: None,
+        faucet: None,
+        environment_variables: None,
+        nat_status: None,
+        nodes: vec![],
+        save_path: node_reg_path.to_path_buf(),
+    };
+
+    let mut mock_service_control = MockServiceControl::new();
+
+    mock_service_control
+        .expect_install()
+        .times(1)
+        .with(
+            eq(ServiceInstallCtx {
+                args: vec![
+                    OsString::from("--port"),
+                    OsString::from("8080"),
+                    OsString::from("--address"),
+                    OsString::from("127.0.0.1"),
+                    OsString::from("--textfile-interval"),
+                    OsString::from("30"),
+                    OsString::from("--textfile-path"),
+                    OsString::from("/var/lib/node_exporter/textfile_collector/antctl.prom"),
+                ],
+                autostart: true,
+                contents: None,
+                environment: None,
+                label: "antctld".parse()?,
+                program: daemon_install_path.to_path_buf(),
+                username: Some(get_username()),
+                working_directory: None,
+            }),
+            eq(false),
+        )
+        .returning(|_, _| Ok(()));
+
+    let textfile = DaemonTextfileOptions {
+        textfile_interval: Some(30),
+        textfile_path: Some(PathBuf::from(
+            "/var/lib/node_exporter/textfile_collector/antctl.prom",
+        )),
+    };
+    add_daemon(
+        AddDaemonServiceOptions {
+            address: Ipv4Addr::new(127, 0, 0, 1),
+            daemon_install_bin_path: daemon_install_path.to_path_buf(),
+            daemon_src_bin_path: daemon_download_path.to_path_buf(),
+            env_variables: None,
+            port: 8080,
+            textfile: textfile.clone(),
+            user: get_username(),
+            version: latest_version.to_string(),
+            watchdog: DaemonWatchdogOptions::default(),
+        },
+        &mut node_registry,
+        &mock_service_control,
+    )?;
+
+    let saved_daemon = node_registry.daemon.unwrap();
+    assert_eq!(saved_daemon.textfile, textfile);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn add_daemon_should_return_an_error_if_a_daemon_service_was_already_created() -> Result<()> {
     let tmp_data_dir = assert_fs::TempDir::new()?;
@@ -5613,7 +5792,9 @@ async fn add_daemon_should_return_an_error_if_a_daemon_service_was_already_creat
             pid: Some(1234),
             service_name: "antctld".to_string(),
             status: ServiceStatus::Running,
+            textfile: DaemonTextfileOptions::default(),
             version: latest_version.to_string(),
+            watchdog: DaemonWatchdogOptions::default(),
         }),
         auditor: None,
         faucet: None,
@@ -5630,8 +5811,10 @@ async fn add_daemon_should_return_an_error_if_a_daemon_service_was_already_creat
             daemon_src_bin_path: daemon_download_path.to_path_buf(),
             env_variables: Some(Vec::new()),
             port: 8080,
+            textfile: DaemonTextfileOptions::default(),
             user: get_username(),
             version: latest_version.to_string(),
+            watchdog: DaemonWatchdogOptions::default(),
         },
         &mut node_registry,
         &MockServiceControl::new(),
@@ -14,9 +14,13 @@ use ant_evm::RewardsAddress;
 use ant_logging::{LogBuilder, LogFormat};
 use ant_node_manager::{
     add_services::config::PortRange,
-    cmd::{self},
-    VerbosityLevel, DEFAULT_NODE_STARTUP_CONNECTION_TIMEOUT_S,
+    cmd::{self, add_wizard::AddChoices},
+    config,
+    error_catalog::{error_code_for_report, ErrorCode},
+    history::{CommandKind, HistoryRecorder},
+    ConnectivityRequirement, VerbosityLevel, DEFAULT_NODE_STARTUP_CONNECTION_TIMEOUT_S,
 };
+use ant_service_management::{DaemonTextfileOptions, DaemonWatchdogOptions};
 use clap::{Parser, Subcommand};
 use color_eyre::{eyre::eyre, Result};
 use libp2p::Multiaddr;
@@ -40,6 +44,25 @@ pub(crate) struct Cmd {
     #[clap(long, conflicts_with = "trace")]
     debug: bool,
 
+    /// Explain an error code: its common causes, likely fixes and the files worth inspecting.
+    ///
+    /// Use `--explain=<CODE>` to explain a code, e.g., `--explain=PidNotFoundAfterStarting`.
+    ///
+    /// Without a code, the command runs as usual and, if it fails, the explanation for the error
+    /// it failed with is printed.
+    ///
+    /// The exit code of antctl is determined by the same error codes.
+    #[clap(long, value_name = "CODE", num_args = 0..=1, require_equals = true, default_missing_value = "")]
+    explain: Option<String>,
+
+    /// Run the command even if the installed versions of antctl, antctld and antnode are known
+    /// not to work together.
+    ///
+    /// Commands that add or start services check the versions against a compatibility matrix,
+    /// and refuse to run when a combination is known to be broken.
+    #[clap(long)]
+    ignore_compatibility: bool,
+
     /// Print the package version.
     #[cfg(not(feature = "nightly"))]
     #[clap(long)]
@@ -124,13 +147,25 @@ pub enum SubCmd {
         #[clap(name = "env", long, use_value_delimiter = true, value_parser = parse_environment_variables)]
         env_variables: Option<Vec<(String, String)>>,
         /// Specify what EVM network to use for payments.
+        ///
+        /// This is required unless the --interactive option is used.
         #[command(subcommand)]
-        evm_network: EvmNetworkCommand,
+        evm_network: Option<EvmNetworkCommand>,
         /// Set this flag to use the antnode '--home-network' feature.
         ///
         /// This enables the use of antnode services from a home network with a router.
         #[clap(long)]
         home_network: bool,
+        /// Walk through the main options with a guided wizard.
+        ///
+        /// The wizard asks for the number of services, their ports, the rewards address, the EVM
+        /// network and the log file limits, validating each answer as it is given. A summary is
+        /// displayed for confirmation before any services are added.
+        ///
+        /// Any of those options that are provided as arguments are offered as the defaults. The
+        /// other arguments are used as they are.
+        #[clap(long)]
+        interactive: bool,
         /// Provide the path for the log directory for the installed node.
         ///
         /// This path is a prefix. Each installed node will have its own directory underneath it.
@@ -208,8 +243,10 @@ pub enum SubCmd {
         #[command(flatten)]
         peers: PeersArgs,
         /// Specify the wallet address that will receive the node's earnings.
-        #[clap(long)]
-        rewards_address: RewardsAddress,
+        ///
+        /// This is required unless the --interactive option is used.
+        #[clap(long, required_unless_present = "interactive")]
+        rewards_address: Option<RewardsAddress>,
         /// Specify an Ipv4Addr for the node's RPC server to run on.
         ///
         /// Useful if you want to expose the RPC server pubilcly. Ports are assigned automatically.
@@ -275,9 +312,28 @@ pub enum SubCmd {
     Daemon(DaemonSubCmd),
     #[clap(subcommand)]
     Faucet(FaucetSubCmd),
+    /// Show the history of the commands that changed the antnode services.
+    ///
+    /// Each entry records the command, the arguments it was given and the changes it made to the
+    /// services in the node registry.
+    ///
+    /// Provide the ID of an entry to show the changes it made in detail.
+    #[clap(name = "history")]
+    History {
+        /// The ID of the entry to show in detail.
+        id: Option<u64>,
+        /// Output the history as JSON.
+        #[clap(long)]
+        json: bool,
+        /// The number of most recent entries to show.
+        #[clap(long, default_value_t = 20, conflicts_with = "id")]
+        limit: usize,
+    },
     #[clap(subcommand)]
     Local(LocalSubCmd),
     #[clap(subcommand)]
+    Mirror(MirrorSubCmd),
+    #[clap(subcommand)]
     NatDetection(NatDetectionSubCmd),
     /// Remove antnode service(s).
     ///
@@ -289,6 +345,12 @@ pub enum SubCmd {
     /// sudo if you defined system-wide services; otherwise, do not run the command elevated.
     #[clap(name = "remove")]
     Remove {
+        /// Remove all services.
+        ///
+        /// A summary of the node identities and directories that will be lost is displayed, and
+        /// the name of this host must be typed to confirm the removal.
+        #[clap(long, conflicts_with_all = ["peer_id", "service_name"])]
+        all: bool,
         /// The peer ID of the service to remove.
         ///
         /// The argument can be used multiple times to remove many services.
@@ -302,18 +364,24 @@ pub enum SubCmd {
         /// Set this flag to keep the node's data and log directories.
         #[clap(long)]
         keep_directories: bool,
+        /// Set to suppress the confirmation prompt when using --all.
+        #[clap(long = "yes-i-mean-it", requires = "all")]
+        yes_i_mean_it: bool,
     },
     /// Reset back to a clean base state.
     ///
     /// Stop and remove all services and delete the node registry, which will set the service
     /// counter back to zero.
     ///
+    /// A summary of the node identities and directories that will be lost is displayed, and the
+    /// name of this host must be typed to confirm the reset.
+    ///
     /// This command must run as the root/administrative user.
     #[clap(name = "reset")]
     Reset {
         /// Set to suppress the confirmation prompt.
-        #[clap(long, short)]
-        force: bool,
+        #[clap(long = "yes-i-mean-it", short = 'f', alias = "force")]
+        yes_i_mean_it: bool,
     },
     /// Start antnode service(s).
     ///
@@ -342,7 +410,7 @@ pub enum SubCmd {
         /// 'connection-timeout' argument.
         ///
         /// Units are milliseconds.
-        #[clap(long, conflicts_with = "connection-timeout")]
+        #[clap(long, conflicts_with = "connection_timeout")]
         interval: Option<u64>,
         /// The peer ID of the service to start.
         ///
@@ -354,6 +422,17 @@ pub enum SubCmd {
         /// The argument can be used multiple times to start many services.
         #[clap(long, conflicts_with = "peer_id")]
         service_name: Vec<String>,
+        /// Wait for each node to be connected to a minimum number of peers, rather than only for it
+        /// to be running.
+        ///
+        /// Optionally takes the minimum number of peers, which defaults to 5, followed by the max
+        /// time in seconds to wait for each node, which defaults to 300s. A node that does not reach
+        /// the number of peers in time is considered failed, and the command exits with a distinct
+        /// exit code (see `antctl --explain=ConnectivityTimeout`).
+        ///
+        /// Example: --wait-for-connectivity 10 120
+        #[clap(long, num_args = 0..=2, value_names = ["MIN_PEERS", "TIMEOUT"])]
+        wait_for_connectivity: Option<Vec<u64>>,
     },
     /// Get the status of services.
     #[clap(name = "status")]
@@ -379,7 +458,7 @@ pub enum SubCmd {
         /// An interval applied between stopping each service.
         ///
         /// Units are milliseconds.
-        #[clap(long, conflicts_with = "connection-timeout")]
+        #[clap(long)]
         interval: Option<u64>,
         /// The peer ID of the service to stop.
         ///
@@ -392,6 +471,24 @@ pub enum SubCmd {
         #[clap(long, conflicts_with = "peer_id")]
         service_name: Vec<String>,
     },
+    /// Undo the changes made by a command in the history.
+    ///
+    /// These commands can be undone:
+    ///  - `add`: the added services are stopped and removed, along with their directories.
+    ///  - `remove --keep-directories`: the removed services are installed again.
+    ///  - `start`: the started services are stopped.
+    ///  - `stop`: the stopped services are started.
+    ///
+    /// Without an ID, the most recent command that can be undone is undone. Use the `history`
+    /// command to find the ID of a command.
+    ///
+    /// On Windows, this command must run as the administrative user. On Linux/macOS, run using
+    /// sudo if you defined system-wide services; otherwise, do not run the command elevated.
+    #[clap(name = "undo")]
+    Undo {
+        /// The ID of the history entry to undo.
+        id: Option<u64>,
+    },
     /// Upgrade antnode services.
     ///
     /// By default, each node service is started after the previous node has successfully connected to the network or
@@ -440,7 +537,7 @@ pub enum SubCmd {
         /// 'connection-timeout' argument.
         ///
         /// Units are milliseconds.
-        #[clap(long, conflicts_with = "connection-timeout")]
+        #[clap(long, conflicts_with = "connection_timeout")]
         interval: Option<u64>,
         /// Provide a path for the antnode binary to be used by the service.
         ///
@@ -465,9 +562,45 @@ pub enum SubCmd {
         /// The version number should be in the form X.Y.Z, with no 'v' prefix.
         #[clap(long)]
         version: Option<String>,
+        /// Wait for each node to be connected to a minimum number of peers, rather than only for it
+        /// to be running.
+        ///
+        /// Optionally takes the minimum number of peers, which defaults to 5, followed by the max
+        /// time in seconds to wait for each node, which defaults to 300s. A node that does not reach
+        /// the number of peers in time is considered failed, and the command exits with a distinct
+        /// exit code (see `antctl --explain=ConnectivityTimeout`).
+        ///
+        /// Example: --wait-for-connectivity 10 120
+        #[clap(
+            long,
+            num_args = 0..=2,
+            value_names = ["MIN_PEERS", "TIMEOUT"],
+            conflicts_with = "do_not_start"
+        )]
+        wait_for_connectivity: Option<Vec<u64>>,
     },
 }
 
+impl SubCmd {
+    /// The kind of the command, if it changes the antnode services and is recorded in the history.
+    fn history_kind(&self) -> Option<CommandKind> {
+        match self {
+            SubCmd::Add { .. } => Some(CommandKind::Add),
+            SubCmd::Remove {
+                keep_directories, ..
+            } => Some(CommandKind::Remove {
+                keep_directories: *keep_directories,
+            }),
+            SubCmd::Reset { .. } => Some(CommandKind::Reset),
+            SubCmd::Start { .. } => Some(CommandKind::Start),
+            SubCmd::Stop { .. } => Some(CommandKind::Stop),
+            SubCmd::Undo { .. } => Some(CommandKind::Undo),
+            SubCmd::Upgrade { .. } => Some(CommandKind::Upgrade),
+            _ => None,
+        }
+    }
+}
+
 /// Manage the Auditor service.
 #[derive(Subcommand, Debug)]
 pub enum AuditorSubCmd {
@@ -597,6 +730,22 @@ pub enum DaemonSubCmd {
         /// Example: --env ANT_LOG=all,RUST_LOG=libp2p=debug
         #[clap(name = "env", long, use_value_delimiter = true, value_parser = parse_environment_variables)]
         env_variables: Option<Vec<(String, String)>>,
+        /// The maximum CPU usage of the daemon, as a percentage where 100 is a single core fully in
+        /// use.
+        ///
+        /// A warning is logged whenever the limit is exceeded.
+        #[clap(long)]
+        max_cpu_percent: Option<f32>,
+        /// The maximum memory usage of the daemon, in MB.
+        ///
+        /// A warning is logged whenever the limit is exceeded.
+        #[clap(long)]
+        max_memory_mb: Option<u64>,
+        /// The maximum number of RPC requests the daemon can be handling at once.
+        ///
+        /// A warning is logged whenever the limit is exceeded.
+        #[clap(long)]
+        max_pending_requests: Option<usize>,
         /// Specify a port for the daemon to listen on.
         #[clap(long, default_value_t = 12500)]
         port: u16,
@@ -605,6 +754,21 @@ pub enum DaemonSubCmd {
         /// Useful for creating the daemon service using a custom built binary.
         #[clap(long)]
         path: Option<PathBuf>,
+        /// Have the daemon shut down cleanly when any of the resource limits is exceeded on
+        /// consecutive checks, for the service manager to start it again.
+        #[clap(long)]
+        restart_on_resource_limit: bool,
+        /// The interval, in seconds, at which the textfile is written.
+        ///
+        /// If not set, the daemon writes it every 60 seconds.
+        #[clap(long, requires = "textfile_path", value_parser = clap::value_parser!(u64).range(1..))]
+        textfile_interval: Option<u64>,
+        /// Have the daemon periodically write the state of the node services to this file, for the
+        /// textfile collector of the Prometheus node exporter.
+        ///
+        /// The file should be in the collector directory and have the .prom extension.
+        #[clap(long)]
+        textfile_path: Option<PathBuf>,
         /// Provide a faucet binary using a URL.
         ///
         /// The binary must be inside a zip or gzipped tar archive.
@@ -621,6 +785,11 @@ pub enum DaemonSubCmd {
         /// The binary will be downloaded.
         #[clap(long)]
         version: Option<String>,
+        /// The interval, in seconds, at which the daemon checks its own resource usage.
+        ///
+        /// If not set, the daemon checks every 60 seconds.
+        #[clap(long, value_parser = clap::value_parser!(u64).range(1..))]
+        watchdog_interval: Option<u64>,
     },
     /// Start the daemon service.
     ///
@@ -736,6 +905,39 @@ pub enum FaucetSubCmd {
     },
 }
 
+/// Manage the artifact mirror binaries are downloaded from.
+///
+/// When a mirror is configured, the binaries for the add and upgrade commands are downloaded from
+/// the mirror rather than the public releases. Only the archives listed in the signed manifest of
+/// the mirror are downloaded, and their checksums are verified against it. The latest version of a
+/// binary is the latest version approved in the manifest.
+#[derive(Subcommand, Debug, Clone)]
+pub enum MirrorSubCmd {
+    /// Remove the artifact mirror, to download binaries from the public releases again.
+    #[clap(name = "remove")]
+    Remove {},
+    /// Configure the artifact mirror to download binaries from.
+    ///
+    /// The mirror must serve the release archives at its base URL, along with a `manifest.json`
+    /// listing the approved archives and a `manifest.json.sig` containing the hex-encoded ed25519
+    /// signature of the manifest. The manifest is fetched and verified before the mirror is
+    /// configured.
+    ///
+    /// On Windows, this command must run as the administrative user. On Linux/macOS, run using
+    /// sudo if you defined system-wide services; otherwise, do not run the command elevated.
+    #[clap(name = "set")]
+    Set {
+        /// The base URL of the mirror.
+        url: String,
+        /// The hex-encoded ed25519 public key the manifest is signed with.
+        #[clap(long)]
+        public_key: String,
+    },
+    /// Show the configured artifact mirror and the versions it approves.
+    #[clap(name = "show")]
+    Show {},
+}
+
 /// Manage NAT detection.
 #[derive(Subcommand, Debug, Clone)]
 pub enum NatDetectionSubCmd {
@@ -1025,6 +1227,37 @@ async fn main() -> Result<()> {
     color_eyre::install()?;
     let args = Cmd::parse();
 
+    let explain = match args.explain.as_deref() {
+        Some("") => true,
+        Some(code) => {
+            let error_code = ErrorCode::from_name(code).ok_or_else(|| {
+                eyre!("Unknown error code '{code}'. Known codes: {}", {
+                    let codes: Vec<_> = ErrorCode::ALL.iter().map(|c| c.to_string()).collect();
+                    codes.join(", ")
+                })
+            })?;
+            print!("{}", error_code.entry());
+            return Ok(());
+        }
+        None => false,
+    };
+
+    if let Err(err) = run(args).await {
+        let error_code = error_code_for_report(&err);
+        eprintln!("Error: {err:?}");
+        if explain {
+            eprintln!();
+            eprint!("{}", error_code.entry());
+        } else if error_code != ErrorCode::Unknown {
+            eprintln!();
+            eprintln!("Run `antctl --explain={error_code}` for the likely causes and fixes.");
+        }
+        std::process::exit(error_code.exit_code());
+    }
+    Ok(())
+}
+
+async fn run(args: Cmd) -> Result<()> {
     if args.version {
         println!(
             "{}",
@@ -1065,7 +1298,16 @@ async fn main() -> Result<()> {
 
     tracing::info!("Executing cmd: {:?}", args.cmd);
 
-    match args.cmd {
+    // Only the commands that change the services are checked, and only adding or starting services
+    // is refused, so the installed versions can still be upgraded or removed.
+    if let Some(subcmd) = args.cmd.as_ref().filter(|c| c.history_kind().is_some()) {
+        let refuse_incompatible = !args.ignore_compatibility
+            && matches!(subcmd, SubCmd::Add { .. } | SubCmd::Start { .. });
+        cmd::check_compatibility(refuse_incompatible, verbosity)?;
+    }
+
+    let history_recorder = start_history_recording(args.cmd.as_ref());
+    let result = match args.cmd {
         Some(SubCmd::Add {
             auto_restart,
             auto_set_nat_flags,
@@ -1075,6 +1317,7 @@ async fn main() -> Result<()> {
             env_variables,
             evm_network,
             home_network,
+            interactive,
             log_dir_path,
             log_format,
             max_archived_log_files,
@@ -1094,37 +1337,55 @@ async fn main() -> Result<()> {
             user,
             version,
         }) => {
-            cmd::node::add(
-                auto_restart,
-                auto_set_nat_flags,
+            let choices = AddChoices {
                 count,
-                data_dir_path,
-                enable_metrics_server,
-                env_variables,
-                Some(evm_network.try_into()?),
-                home_network,
-                log_dir_path,
-                log_format,
+                evm_network: evm_network.map(|network| network.try_into()).transpose()?,
                 max_archived_log_files,
                 max_log_files,
                 metrics_port,
-                network_id,
-                node_ip,
                 node_port,
-                owner,
-                peers,
                 rewards_address,
-                rpc_address,
                 rpc_port,
-                path,
-                upnp,
-                url,
-                user,
-                version,
-                verbosity,
-            )
-            .await?;
-            Ok(())
+            };
+            let settings = if interactive {
+                cmd::add_wizard::run(choices, peers.first, verbosity)?
+            } else {
+                Some(choices.resolve()?)
+            };
+            match settings {
+                Some(settings) => cmd::node::add(
+                    auto_restart,
+                    auto_set_nat_flags,
+                    settings.count,
+                    data_dir_path,
+                    enable_metrics_server,
+                    env_variables,
+                    Some(settings.evm_network),
+                    home_network,
+                    log_dir_path,
+                    log_format,
+                    settings.max_archived_log_files,
+                    settings.max_log_files,
+                    settings.metrics_port,
+                    network_id,
+                    node_ip,
+                    settings.node_port,
+                    owner,
+                    peers,
+                    settings.rewards_address,
+                    rpc_address,
+                    settings.rpc_port,
+                    path,
+                    upnp,
+                    url,
+                    user,
+                    version,
+                    verbosity,
+                )
+                .await
+                .map(|_| ()),
+                None => Ok(()),
+            }
         }
         Some(SubCmd::Auditor(AuditorSubCmd::Add {
             beta_encryption_key,
@@ -1165,11 +1426,40 @@ async fn main() -> Result<()> {
         Some(SubCmd::Daemon(DaemonSubCmd::Add {
             address,
             env_variables,
+            max_cpu_percent,
+            max_memory_mb,
+            max_pending_requests,
             port,
             path,
+            restart_on_resource_limit,
+            textfile_interval,
+            textfile_path,
             url,
             version,
-        })) => cmd::daemon::add(address, env_variables, port, path, url, version, verbosity).await,
+            watchdog_interval,
+        })) => {
+            cmd::daemon::add(
+                address,
+                env_variables,
+                port,
+                path,
+                url,
+                version,
+                DaemonTextfileOptions {
+                    textfile_interval,
+                    textfile_path,
+                },
+                DaemonWatchdogOptions {
+                    max_cpu_percent,
+                    max_memory_mb,
+                    max_pending_requests,
+                    restart_on_resource_limit,
+                    watchdog_interval,
+                },
+                verbosity,
+            )
+            .await
+        }
         Some(SubCmd::Daemon(DaemonSubCmd::Start {})) => cmd::daemon::start(verbosity).await,
         Some(SubCmd::Daemon(DaemonSubCmd::Stop {})) => cmd::daemon::stop(verbosity).await,
         Some(SubCmd::Faucet(faucet_command)) => match faucet_command {
@@ -1212,6 +1502,13 @@ async fn main() -> Result<()> {
                 .await
             }
         },
+        Some(SubCmd::Mirror(mirror_command)) => match mirror_command {
+            MirrorSubCmd::Remove {} => cmd::mirror::remove(verbosity),
+            MirrorSubCmd::Set { url, public_key } => {
+                cmd::mirror::set(url, public_key, verbosity).await
+            }
+            MirrorSubCmd::Show {} => cmd::mirror::show().await,
+        },
         Some(SubCmd::Local(local_command)) => match local_command {
             LocalSubCmd::Join {
                 build,
@@ -1318,19 +1615,35 @@ async fn main() -> Result<()> {
                 .await
         }
         Some(SubCmd::Remove {
+            all,
             keep_directories,
             peer_id: peer_ids,
             service_name: service_names,
-        }) => cmd::node::remove(keep_directories, peer_ids, service_names, verbosity).await,
-        Some(SubCmd::Reset { force }) => cmd::node::reset(force, verbosity).await,
+            yes_i_mean_it,
+        }) => {
+            cmd::node::remove(
+                all,
+                keep_directories,
+                peer_ids,
+                service_names,
+                yes_i_mean_it,
+                verbosity,
+            )
+            .await
+        }
+        Some(SubCmd::Reset { yes_i_mean_it }) => cmd::node::reset(yes_i_mean_it, verbosity).await,
         Some(SubCmd::Start {
             connection_timeout,
             interval,
             peer_id: peer_ids,
             service_name: service_names,
+            wait_for_connectivity,
         }) => {
             cmd::node::start(
                 connection_timeout,
+                wait_for_connectivity
+                    .as_deref()
+                    .map(ConnectivityRequirement::from_args),
                 interval,
                 peer_ids,
                 service_names,
@@ -1359,9 +1672,13 @@ async fn main() -> Result<()> {
             env_variables: provided_env_variable,
             url,
             version,
+            wait_for_connectivity,
         }) => {
             cmd::node::upgrade(
                 connection_timeout,
+                wait_for_connectivity
+                    .as_deref()
+                    .map(ConnectivityRequirement::from_args),
                 do_not_start,
                 path,
                 force,
@@ -1375,8 +1692,34 @@ async fn main() -> Result<()> {
             )
             .await
         }
+        Some(SubCmd::History { id, json, limit }) => cmd::history::list(id, limit, json),
+        Some(SubCmd::Undo { id }) => cmd::history::undo(id, verbosity).await,
         None => Ok(()),
+    };
+
+    if let Some(history_recorder) = history_recorder {
+        if let Err(err) = history_recorder.finish(result.is_ok()) {
+            tracing::warn!("Could not record the command in the history: {err}");
+        }
     }
+    result
+}
+
+/// Begin recording the command in the history, if it's one that changes the services.
+///
+/// The history is an aid, so failing to record a command does not prevent it from running.
+fn start_history_recording(cmd: Option<&SubCmd>) -> Option<HistoryRecorder> {
+    let kind = cmd?.history_kind()?;
+    let registry_path = config::get_node_registry_path().ok()?;
+    let history_path = config::get_command_history_path().ok()?;
+    HistoryRecorder::start(
+        kind,
+        std::env::args().skip(1).collect(),
+        registry_path,
+        history_path,
+    )
+    .inspect_err(|err| tracing::warn!("Could not record the command in the history: {err}"))
+    .ok()
 }
 
 fn get_log_builder(level: Level) -> Result<LogBuilder> {
@@ -9,8 +9,15 @@
 #[macro_use]
 extern crate tracing;
 
+mod textfile;
+mod watchdog;
+
 use ant_logging::LogBuilder;
-use ant_node_manager::{config::get_node_registry_path, rpc, DAEMON_DEFAULT_PORT};
+use ant_node_manager::{
+    compatibility::{self, Component, COMPATIBILITY_MATRIX},
+    config::get_node_registry_path,
+    rpc, DAEMON_DEFAULT_PORT,
+};
 use ant_service_management::{
     antctl_proto::{
         ant_ctl_server::{AntCtl, AntCtlServer},
@@ -22,9 +29,16 @@ use ant_service_management::{
 use clap::Parser;
 use color_eyre::eyre::{eyre, Result};
 use libp2p_identity::PeerId;
-use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use semver::Version;
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    path::PathBuf,
+    time::Duration,
+};
+use textfile::TextfileConfig;
 use tonic::{transport::Server, Code, Request, Response, Status};
 use tracing::Level;
+use watchdog::{PendingRequests, ResourceLimits, WatchdogConfig};
 
 #[derive(Parser, Debug)]
 #[command(disable_version_flag = true)]
@@ -37,6 +51,21 @@ struct Args {
     /// Print the crate version.
     #[clap(long)]
     pub crate_version: bool,
+    /// The maximum CPU usage of the daemon, as a percentage where 100 is a single core fully in use.
+    ///
+    /// A warning is logged whenever the limit is exceeded.
+    #[clap(long)]
+    max_cpu_percent: Option<f32>,
+    /// The maximum memory usage of the daemon, in MB.
+    ///
+    /// A warning is logged whenever the limit is exceeded.
+    #[clap(long)]
+    max_memory_mb: Option<u64>,
+    /// The maximum number of RPC requests the daemon can be handling at once.
+    ///
+    /// A warning is logged whenever the limit is exceeded.
+    #[clap(long)]
+    max_pending_requests: Option<usize>,
     /// Print the package version.
     #[cfg(not(feature = "nightly"))]
     #[clap(long)]
@@ -44,12 +73,33 @@ struct Args {
     /// Specify a port for the daemon to listen for RPCs. It defaults to 12500 if not set.
     #[clap(long, default_value_t = DAEMON_DEFAULT_PORT)]
     port: u16,
+    /// Shut the daemon down cleanly when any of the resource limits is exceeded on consecutive
+    /// checks.
+    ///
+    /// The daemon exits with an error, for the service manager to start it again.
+    #[clap(long)]
+    restart_on_resource_limit: bool,
+    /// The interval, in seconds, at which the textfile is written.
+    #[clap(long, default_value_t = 60, value_parser = clap::value_parser!(u64).range(1..))]
+    textfile_interval: u64,
+    /// Periodically write the state of the node services to this file, for the textfile collector
+    /// of the Prometheus node exporter.
+    ///
+    /// The file reports whether each service is up, along with its version, status and restart
+    /// count. It should be in the collector directory and have the .prom extension.
+    #[clap(long)]
+    textfile_path: Option<PathBuf>,
     /// Print version information.
     #[clap(long)]
     version: bool,
+    /// The interval, in seconds, at which the daemon checks its own resource usage.
+    #[clap(long, default_value_t = 60, value_parser = clap::value_parser!(u64).range(1..))]
+    watchdog_interval: u64,
 }
 
-struct AntCtlDaemon {}
+struct AntCtlDaemon {
+    pending_requests: PendingRequests,
+}
 
 // Implementing RPC interface for service defined in .proto
 #[tonic::async_trait]
@@ -58,6 +108,7 @@ impl AntCtl for AntCtlDaemon {
         &self,
         request: Request<NodeServiceRestartRequest>,
     ) -> Result<Response<NodeServiceRestartResponse>, Status> {
+        let _pending = self.pending_requests.track();
         println!("RPC request received {:?}", request.get_ref());
         info!("RPC request received {:?}", request.get_ref());
         let node_registry = Self::load_node_registry().map_err(|err| {
@@ -86,6 +137,7 @@ impl AntCtl for AntCtlDaemon {
         &self,
         request: Request<GetStatusRequest>,
     ) -> Result<Response<GetStatusResponse>, Status> {
+        let _pending = self.pending_requests.track();
         println!("RPC request received {:?}", request.get_ref());
         info!("RPC request received {:?}", request.get_ref());
         let node_registry = Self::load_node_registry().map_err(|err| {
@@ -136,6 +188,30 @@ impl AntCtlDaemon {
 // into Status inside the trait fns.
 impl AntCtlDaemon {}
 
+/// Log the nodes in the registry whose versions are known not to work with this daemon.
+///
+/// The daemon runs unattended, so it continues regardless.
+fn log_incompatibilities() {
+    let node_registry = match AntCtlDaemon::load_node_registry() {
+        Ok(node_registry) => node_registry,
+        Err(err) => {
+            warn!("Not checking the compatibility of the nodes: {err}");
+            return;
+        }
+    };
+    let Ok(version) = Version::parse(env!("CARGO_PKG_VERSION")) else {
+        return;
+    };
+    let installed =
+        compatibility::installed_components(Component::AntCtlDaemon, version, &node_registry);
+    for incompatibility in compatibility::check(COMPATIBILITY_MATRIX, &installed) {
+        warn!(
+            severity = ?incompatibility.severity,
+            "Version compatibility: {incompatibility}"
+        );
+    }
+}
+
 #[tokio::main(flavor = "current_thread")]
 async fn main() -> Result<()> {
     let args = Args::parse();
@@ -165,11 +241,47 @@ async fn main() -> Result<()> {
 
     let _log_handles = get_log_builder()?.initialize()?;
     println!("Starting antctld");
-    let service = AntCtlDaemon {};
+    log_incompatibilities();
+    let pending_requests = PendingRequests::default();
+    let service = AntCtlDaemon {
+        pending_requests: pending_requests.clone(),
+    };
+
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel();
+    let watchdog_config = WatchdogConfig {
+        interval: Duration::from_secs(args.watchdog_interval),
+        limits: ResourceLimits {
+            max_memory_bytes: args.max_memory_mb.map(|mb| mb * 1024 * 1024),
+            max_cpu_percent: args.max_cpu_percent,
+            max_pending_requests: args.max_pending_requests,
+        },
+        restart_on_breach: args.restart_on_resource_limit,
+    };
+    let _watchdog = tokio::spawn(watchdog::run(
+        watchdog_config,
+        pending_requests,
+        shutdown_tx,
+    ));
+    let _textfile = args.textfile_path.map(|path| {
+        tokio::spawn(textfile::run(TextfileConfig {
+            path,
+            interval: Duration::from_secs(args.textfile_interval),
+        }))
+    });
 
+    // If the watchdog is dropped without a reason, it stopped without asking for a shutdown.
+    let mut shutdown_reason = None;
     if let Err(err) = Server::builder()
         .add_service(AntCtlServer::new(service))
-        .serve(SocketAddr::new(IpAddr::V4(args.address), args.port))
+        .serve_with_shutdown(
+            SocketAddr::new(IpAddr::V4(args.address), args.port),
+            async {
+                match shutdown_rx.await {
+                    Ok(reason) => shutdown_reason = Some(reason),
+                    Err(_) => std::future::pending().await,
+                }
+            },
+        )
         .await
     {
         error!("Antctl Daemon failed to start: {err:?}");
@@ -177,6 +289,14 @@ async fn main() -> Result<()> {
         return Err(err.into());
     }
 
+    if let Some(reason) = shutdown_reason {
+        error!("Antctl Daemon shut down by the resource watchdog: {reason}");
+        println!("Antctl Daemon shut down by the resource watchdog: {reason}");
+        return Err(eyre!(
+            "The daemon was shut down to be restarted because resource limits were exceeded: {reason}"
+        ));
+    }
+
     Ok(())
 }
 
@@ -0,0 +1,292 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Export of the state of the node services to a file for the textfile collector of the
+//! Prometheus node exporter, so the fleet can be monitored without scraping anything new.
+
+use crate::AntCtlDaemon;
+use ant_service_management::{NodeRegistry, ServiceStatus};
+use color_eyre::eyre::Result;
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use sysinfo::{ProcessRefreshKind, System, UpdateKind};
+
+#[derive(Clone, Debug)]
+pub struct TextfileConfig {
+    /// The file to write, which the node exporter only reads if it has the `.prom` extension.
+    pub path: PathBuf,
+    pub interval: Duration,
+}
+
+/// The state of a node service at the time of an export.
+#[derive(Clone, Debug, PartialEq)]
+struct ServiceSample {
+    service_name: String,
+    version: String,
+    peer_id: Option<String>,
+    status: ServiceStatus,
+    /// The PID of the running process of the service, if there is one.
+    pid: Option<u32>,
+}
+
+/// Counts the restarts of each service, as the times its process was seen with a new PID.
+///
+/// The registry doesn't record restarts, so only those since the daemon started are counted,
+/// which Prometheus handles as a counter reset.
+#[derive(Debug, Default)]
+struct RestartTracker {
+    services: HashMap<String, TrackedService>,
+}
+
+#[derive(Debug, Default)]
+struct TrackedService {
+    last_pid: Option<u32>,
+    restarts: u64,
+}
+
+impl RestartTracker {
+    /// Returns the number of restarts of the service so far.
+    fn observe(&mut self, service_name: &str, pid: Option<u32>) -> u64 {
+        let tracked = self.services.entry(service_name.to_string()).or_default();
+        if let Some(pid) = pid {
+            if tracked.last_pid.is_some_and(|last_pid| last_pid != pid) {
+                tracked.restarts += 1;
+            }
+            tracked.last_pid = Some(pid);
+        }
+        tracked.restarts
+    }
+}
+
+/// Periodically write the state of the node services in the registry to the textfile.
+pub async fn run(config: TextfileConfig) {
+    info!(
+        "Writing the state of the node services to {:?} every {:?}",
+        config.path, config.interval
+    );
+    if config.path.extension().is_none_or(|ext| ext != "prom") {
+        warn!(
+            "The textfile {:?} does not have the .prom extension, so the node exporter will ignore it",
+            config.path
+        );
+    }
+
+    let mut system = System::new();
+    let mut restart_tracker = RestartTracker::default();
+    let mut interval = tokio::time::interval(config.interval);
+    loop {
+        interval.tick().await;
+        let node_registry = match AntCtlDaemon::load_node_registry() {
+            Ok(node_registry) => node_registry,
+            Err(err) => {
+                warn!("Not exporting the state of the node services: {err}");
+                continue;
+            }
+        };
+        system.refresh_processes_specifics(
+            ProcessRefreshKind::new().with_exe(UpdateKind::OnlyIfNotSet),
+        );
+        let samples = sample_services(&node_registry, &system);
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let contents = render(&samples, &mut restart_tracker, timestamp);
+        match write_atomically(&config.path, &contents) {
+            Ok(()) => debug!(
+                "Exported the state of {} node services to {:?}",
+                samples.len(),
+                config.path
+            ),
+            Err(err) => error!("Failed to write the textfile {:?}: {err}", config.path),
+        }
+    }
+}
+
+/// The services in the registry that were not removed, with the PID of their running process.
+///
+/// Each service runs its own binary, so its process is found by the path of the binary.
+fn sample_services(node_registry: &NodeRegistry, system: &System) -> Vec<ServiceSample> {
+    let pids_by_exe = system
+        .processes()
+        .iter()
+        .filter_map(|(pid, process)| Some((process.exe()?, pid.as_u32())))
+        .collect::<HashMap<_, _>>();
+
+    node_registry
+        .nodes
+        .iter()
+        .filter(|node| node.status != ServiceStatus::Removed)
+        .map(|node| ServiceSample {
+            service_name: node.service_name.clone(),
+            version: node.version.clone(),
+            peer_id: node.peer_id.map(|peer_id| peer_id.to_string()),
+            status: node.status.clone(),
+            pid: pids_by_exe.get(node.antnode_path.as_path()).copied(),
+        })
+        .collect()
+}
+
+/// Render the samples in the Prometheus text exposition format.
+fn render(
+    samples: &[ServiceSample],
+    restart_tracker: &mut RestartTracker,
+    timestamp: u64,
+) -> String {
+    let mut up = String::new();
+    let mut info = String::new();
+    let mut restarts = String::new();
+    for sample in samples {
+        let service_name = escape_label_value(&sample.service_name);
+        let _ = writeln!(
+            up,
+            "antnode_service_up{{service_name=\"{service_name}\"}} {}",
+            u8::from(sample.pid.is_some())
+        );
+        let _ = writeln!(
+            info,
+            "antnode_service_info{{service_name=\"{service_name}\",version=\"{}\",status=\"{}\",peer_id=\"{}\"}} 1",
+            escape_label_value(&sample.version),
+            status_label(&sample.status),
+            sample.peer_id.as_deref().unwrap_or_default(),
+        );
+        let _ = writeln!(
+            restarts,
+            "antnode_service_restarts_total{{service_name=\"{service_name}\"}} {}",
+            restart_tracker.observe(&sample.service_name, sample.pid)
+        );
+    }
+
+    let mut contents = String::new();
+    for (name, kind, help, series) in [
+        (
+            "antnode_service_up",
+            "gauge",
+            "Whether the process of the node service is running.",
+            up,
+        ),
+        (
+            "antnode_service_info",
+            "gauge",
+            "The version, registry status and peer ID of the node service.",
+            info,
+        ),
+        (
+            "antnode_service_restarts_total",
+            "counter",
+            "The restarts of the node service seen by antctld since it started.",
+            restarts,
+        ),
+    ] {
+        let _ = writeln!(contents, "# HELP {name} {help}");
+        let _ = writeln!(contents, "# TYPE {name} {kind}");
+        contents.push_str(&series);
+    }
+    let _ = writeln!(
+        contents,
+        "# HELP antctld_textfile_timestamp_seconds When antctld last wrote this file.\n\
+         # TYPE antctld_textfile_timestamp_seconds gauge\n\
+         antctld_textfile_timestamp_seconds {timestamp}"
+    );
+    contents
+}
+
+fn status_label(status: &ServiceStatus) -> &'static str {
+    match status {
+        ServiceStatus::Added => "added",
+        ServiceStatus::Running => "running",
+        ServiceStatus::Stopped => "stopped",
+        ServiceStatus::Removed => "removed",
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Write to a temporary file in the same directory first, so the node exporter never reads a
+/// partially written file.
+fn write_atomically(path: &Path, contents: &str) -> Result<()> {
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(format!(".{}.tmp", std::process::id()));
+    std::fs::write(&temp_path, contents)?;
+    std::fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(service_name: &str, status: ServiceStatus, pid: Option<u32>) -> ServiceSample {
+        ServiceSample {
+            service_name: service_name.to_string(),
+            version: "0.3.0".to_string(),
+            peer_id: None,
+            status,
+            pid,
+        }
+    }
+
+    #[test]
+    fn restarts_are_counted_when_the_pid_changes() {
+        let mut tracker = RestartTracker::default();
+        assert_eq!(tracker.observe("antnode1", None), 0);
+        assert_eq!(tracker.observe("antnode1", Some(100)), 0);
+        assert_eq!(tracker.observe("antnode1", Some(100)), 0);
+        assert_eq!(tracker.observe("antnode1", Some(200)), 1);
+        // A process that is down and comes back with a new PID was restarted too.
+        assert_eq!(tracker.observe("antnode1", None), 1);
+        assert_eq!(tracker.observe("antnode1", Some(300)), 2);
+        assert_eq!(tracker.observe("antnode2", Some(300)), 0);
+    }
+
+    #[test]
+    fn services_are_rendered_in_the_exposition_format() {
+        let mut tracker = RestartTracker::default();
+        let mut running = sample("antnode1", ServiceStatus::Running, Some(100));
+        running.peer_id = Some("12D3KooWRBhwfeP2Y4TCx1SM6s9rUoHhR5STiGwxBhgFRcw3UERE".to_string());
+        let samples = vec![running, sample("antnode2", ServiceStatus::Stopped, None)];
+        let _ = render(&samples, &mut tracker, 1);
+
+        let mut restarted = samples.clone();
+        restarted[0].pid = Some(200);
+        assert_eq!(
+            render(&restarted, &mut tracker, 1700000000),
+            "# HELP antnode_service_up Whether the process of the node service is running.
+# TYPE antnode_service_up gauge
+antnode_service_up{service_name=\"antnode1\"} 1
+antnode_service_up{service_name=\"antnode2\"} 0
+# HELP antnode_service_info The version, registry status and peer ID of the node service.
+# TYPE antnode_service_info gauge
+antnode_service_info{service_name=\"antnode1\",version=\"0.3.0\",status=\"running\",peer_id=\"12D3KooWRBhwfeP2Y4TCx1SM6s9rUoHhR5STiGwxBhgFRcw3UERE\"} 1
+antnode_service_info{service_name=\"antnode2\",version=\"0.3.0\",status=\"stopped\",peer_id=\"\"} 1
+# HELP antnode_service_restarts_total The restarts of the node service seen by antctld since it started.
+# TYPE antnode_service_restarts_total counter
+antnode_service_restarts_total{service_name=\"antnode1\"} 1
+antnode_service_restarts_total{service_name=\"antnode2\"} 0
+# HELP antctld_textfile_timestamp_seconds When antctld last wrote this file.
+# TYPE antctld_textfile_timestamp_seconds gauge
+antctld_textfile_timestamp_seconds 1700000000
+"
+        );
+    }
+
+    #[test]
+    fn label_values_are_escaped() {
+        assert_eq!(escape_label_value(r#"a"b\c"#), r#"a\"b\\c"#);
+        assert_eq!(escape_label_value("a\nb"), r"a\nb");
+    }
+}
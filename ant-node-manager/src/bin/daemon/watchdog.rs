@@ -0,0 +1,286 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use sysinfo::{Pid, ProcessRefreshKind, System};
+use tokio::sync::oneshot;
+
+/// Number of consecutive checks a limit must be exceeded on before the daemon restarts, so a
+/// short spike, e.g. of CPU while restarting a node, doesn't restart it.
+const CONSECUTIVE_BREACHES_BEFORE_RESTART: usize = 3;
+
+/// The limits on the resources used by the daemon. Unset limits are not checked.
+#[derive(Clone, Debug, Default)]
+pub struct ResourceLimits {
+    pub max_memory_bytes: Option<u64>,
+    /// As reported by the OS, where 100% is a single core fully in use.
+    pub max_cpu_percent: Option<f32>,
+    pub max_pending_requests: Option<usize>,
+}
+
+#[derive(Clone, Debug)]
+pub struct WatchdogConfig {
+    pub interval: Duration,
+    pub limits: ResourceLimits,
+    /// Shut the daemon down once a limit was exceeded on consecutive checks, for the service
+    /// manager to start it again. Otherwise, only warnings are logged.
+    pub restart_on_breach: bool,
+}
+
+/// The resources used by the daemon at the time of a check.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ResourceUsage {
+    pub memory_bytes: u64,
+    pub cpu_percent: f32,
+    pub pending_requests: usize,
+}
+
+/// A limit that was exceeded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Breach {
+    Memory { usage: u64, limit: u64 },
+    Cpu { usage: f32, limit: f32 },
+    PendingRequests { usage: usize, limit: usize },
+}
+
+impl fmt::Display for Breach {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Breach::Memory { usage, limit } => write!(
+                f,
+                "memory usage of {} MB exceeds the limit of {} MB",
+                usage / (1024 * 1024),
+                limit / (1024 * 1024)
+            ),
+            Breach::Cpu { usage, limit } => {
+                write!(
+                    f,
+                    "CPU usage of {usage:.1}% exceeds the limit of {limit:.1}%"
+                )
+            }
+            Breach::PendingRequests { usage, limit } => write!(
+                f,
+                "{usage} pending RPC requests exceed the limit of {limit}"
+            ),
+        }
+    }
+}
+
+impl ResourceLimits {
+    pub fn breaches(&self, usage: &ResourceUsage) -> Vec<Breach> {
+        let mut breaches = Vec::new();
+        if let Some(limit) = self.max_memory_bytes {
+            if usage.memory_bytes > limit {
+                breaches.push(Breach::Memory {
+                    usage: usage.memory_bytes,
+                    limit,
+                });
+            }
+        }
+        if let Some(limit) = self.max_cpu_percent {
+            if usage.cpu_percent > limit {
+                breaches.push(Breach::Cpu {
+                    usage: usage.cpu_percent,
+                    limit,
+                });
+            }
+        }
+        if let Some(limit) = self.max_pending_requests {
+            if usage.pending_requests > limit {
+                breaches.push(Breach::PendingRequests {
+                    usage: usage.pending_requests,
+                    limit,
+                });
+            }
+        }
+        breaches
+    }
+}
+
+/// The number of RPC requests the daemon is handling.
+///
+/// Clones share the same count.
+#[derive(Clone, Debug, Default)]
+pub struct PendingRequests(Arc<AtomicUsize>);
+
+impl PendingRequests {
+    /// Count a request as pending until the returned guard is dropped.
+    pub fn track(&self) -> PendingRequestGuard {
+        let _ = self.0.fetch_add(1, Ordering::SeqCst);
+        PendingRequestGuard(self.0.clone())
+    }
+
+    pub fn count(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+pub struct PendingRequestGuard(Arc<AtomicUsize>);
+
+impl Drop for PendingRequestGuard {
+    fn drop(&mut self) {
+        let _ = self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Tracks how many consecutive checks exceeded a limit.
+#[derive(Debug, Default)]
+struct BreachCounter {
+    consecutive: usize,
+}
+
+impl BreachCounter {
+    /// Returns true once limits were exceeded on enough consecutive checks to restart.
+    fn observe(&mut self, breaches: &[Breach]) -> bool {
+        if breaches.is_empty() {
+            self.consecutive = 0;
+        } else {
+            self.consecutive += 1;
+        }
+        self.consecutive >= CONSECUTIVE_BREACHES_BEFORE_RESTART
+    }
+}
+
+/// Periodically check the resources used by the daemon, logging a warning for each limit that is
+/// exceeded.
+///
+/// If the config allows it, a reason is sent on `shutdown` once the limits were exceeded on
+/// consecutive checks, and the watchdog stops.
+pub async fn run(
+    config: WatchdogConfig,
+    pending_requests: PendingRequests,
+    shutdown: oneshot::Sender<String>,
+) {
+    let pid = match sysinfo::get_current_pid() {
+        Ok(pid) => pid,
+        Err(err) => {
+            error!("The resource watchdog could not obtain the daemon PID: {err}");
+            return;
+        }
+    };
+    info!(
+        "Starting the resource watchdog with an interval of {:?} and limits {:?}",
+        config.interval, config.limits
+    );
+
+    let mut system = System::new();
+    // CPU usage is measured between two refreshes, so the first refresh only sets the baseline.
+    refresh(&mut system, pid);
+    let mut breach_counter = BreachCounter::default();
+    let mut interval = tokio::time::interval(config.interval);
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        let Some(usage) =
+            refresh(&mut system, pid).map(|(memory_bytes, cpu_percent)| ResourceUsage {
+                memory_bytes,
+                cpu_percent,
+                pending_requests: pending_requests.count(),
+            })
+        else {
+            warn!("The resource watchdog could not obtain the usage of process {pid}");
+            continue;
+        };
+        debug!("Daemon resource usage: {usage:?}");
+
+        let breaches = config.limits.breaches(&usage);
+        for breach in &breaches {
+            warn!(
+                memory_bytes = usage.memory_bytes,
+                cpu_percent = usage.cpu_percent,
+                pending_requests = usage.pending_requests,
+                "Daemon {breach}"
+            );
+        }
+
+        if breach_counter.observe(&breaches) && config.restart_on_breach {
+            let reason = breaches
+                .iter()
+                .map(|breach| breach.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            error!(
+                "Daemon resource limits exceeded on {CONSECUTIVE_BREACHES_BEFORE_RESTART} consecutive checks: {reason}"
+            );
+            let _ = shutdown.send(reason);
+            return;
+        }
+    }
+}
+
+/// Refresh the process, returning its memory in bytes and CPU usage.
+fn refresh(system: &mut System, pid: Pid) -> Option<(u64, f32)> {
+    if !system.refresh_process_specifics(pid, ProcessRefreshKind::new().with_memory().with_cpu()) {
+        return None;
+    }
+    system
+        .process(pid)
+        .map(|process| (process.memory(), process.cpu_usage()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_set_limits_are_checked() {
+        let usage = ResourceUsage {
+            memory_bytes: 600 * 1024 * 1024,
+            cpu_percent: 150.0,
+            pending_requests: 3,
+        };
+        assert!(ResourceLimits::default().breaches(&usage).is_empty());
+
+        let limits = ResourceLimits {
+            max_memory_bytes: Some(512 * 1024 * 1024),
+            max_cpu_percent: None,
+            max_pending_requests: Some(3),
+        };
+        assert_eq!(
+            limits.breaches(&usage),
+            vec![Breach::Memory {
+                usage: 600 * 1024 * 1024,
+                limit: 512 * 1024 * 1024
+            }]
+        );
+    }
+
+    #[test]
+    fn restart_requires_consecutive_breaches() {
+        let breach = [Breach::PendingRequests { usage: 2, limit: 1 }];
+        let mut counter = BreachCounter::default();
+
+        assert!(!counter.observe(&breach));
+        assert!(!counter.observe(&breach));
+        // A check within the limits resets the count.
+        assert!(!counter.observe(&[]));
+        assert!(!counter.observe(&breach));
+        assert!(!counter.observe(&breach));
+        assert!(counter.observe(&breach));
+    }
+
+    #[test]
+    fn pending_requests_are_counted_until_dropped() {
+        let pending = PendingRequests::default();
+        let first = pending.track();
+        let second = pending.clone().track();
+        assert_eq!(pending.count(), 2);
+
+        drop(first);
+        assert_eq!(pending.count(), 1);
+        drop(second);
+        assert_eq!(pending.count(), 0);
+    }
+}
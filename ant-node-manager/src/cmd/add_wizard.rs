@@ -0,0 +1,590 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::{
+    add_services::config::PortRange, config, helpers::check_port_availability, print_banner,
+    VerbosityLevel,
+};
+use ant_evm::{EvmNetwork, RewardsAddress};
+use ant_service_management::{NodeRegistry, NodeServiceData};
+use color_eyre::{eyre::eyre, Help, Result};
+use std::io::{BufRead, Write};
+
+/// The `add` options that the wizard asks about, as given by the arguments.
+///
+/// When the wizard is used, any options that were given are offered as the defaults.
+#[derive(Clone, Debug, Default)]
+pub struct AddChoices {
+    pub count: Option<u16>,
+    pub evm_network: Option<EvmNetwork>,
+    pub max_archived_log_files: Option<usize>,
+    pub max_log_files: Option<usize>,
+    pub metrics_port: Option<PortRange>,
+    pub node_port: Option<PortRange>,
+    pub rewards_address: Option<RewardsAddress>,
+    pub rpc_port: Option<PortRange>,
+}
+
+/// The `add` options after either the arguments or the wizard have supplied all the required ones.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddSettings {
+    pub count: Option<u16>,
+    pub evm_network: EvmNetwork,
+    pub max_archived_log_files: Option<usize>,
+    pub max_log_files: Option<usize>,
+    pub metrics_port: Option<PortRange>,
+    pub node_port: Option<PortRange>,
+    pub rewards_address: RewardsAddress,
+    pub rpc_port: Option<PortRange>,
+}
+
+impl AddChoices {
+    /// Use the arguments as they are, for when the wizard isn't used.
+    pub fn resolve(self) -> Result<AddSettings> {
+        let rewards_address = self.rewards_address.ok_or_else(|| {
+            eyre!("The rewards address was not provided")
+                .suggestion("Use the --rewards-address argument, or run with --interactive")
+        })?;
+        let evm_network = self.evm_network.ok_or_else(|| {
+            eyre!("The EVM network was not provided").suggestion(
+                "Use one of the evm-arbitrum-one, evm-arbitrum-sepolia or evm-custom \
+                subcommands, or run with --interactive",
+            )
+        })?;
+        Ok(AddSettings {
+            count: self.count,
+            evm_network,
+            max_archived_log_files: self.max_archived_log_files,
+            max_log_files: self.max_log_files,
+            metrics_port: self.metrics_port,
+            node_port: self.node_port,
+            rewards_address,
+            rpc_port: self.rpc_port,
+        })
+    }
+}
+
+/// Walk through the `add` options on the terminal.
+///
+/// Returns `None` if the settings were not confirmed at the end.
+pub fn run(
+    choices: AddChoices,
+    first: bool,
+    verbosity: VerbosityLevel,
+) -> Result<Option<AddSettings>> {
+    if verbosity != VerbosityLevel::Minimal {
+        print_banner("Add Antnode Services Wizard");
+    }
+    let node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
+
+    let stdin = std::io::stdin();
+    let mut prompt = Prompt {
+        input: stdin.lock(),
+        output: std::io::stdout(),
+    };
+    let settings = ask_settings(&mut prompt, choices, first, &node_registry.nodes)?;
+    if settings.is_none() {
+        println!("No services were added");
+    }
+    Ok(settings)
+}
+
+struct Prompt<R, W> {
+    input: R,
+    output: W,
+}
+
+impl<R: BufRead, W: Write> Prompt<R, W> {
+    /// Ask until the answer is valid. An empty answer selects the default, if there is one.
+    fn ask<T>(
+        &mut self,
+        question: &str,
+        default: Option<String>,
+        parse: impl Fn(&str) -> Result<T>,
+    ) -> Result<T> {
+        loop {
+            match &default {
+                Some(default) => write!(self.output, "{question} [{default}]: ")?,
+                None => write!(self.output, "{question}: ")?,
+            }
+            self.output.flush()?;
+
+            let mut line = String::new();
+            if self.input.read_line(&mut line)? == 0 {
+                return Err(eyre!(
+                    "The input ended before all the questions were answered"
+                ));
+            }
+            let answer = match (line.trim(), &default) {
+                ("", Some(default)) => default.clone(),
+                (answer, _) => answer.to_string(),
+            };
+            match parse(&answer) {
+                Ok(value) => return Ok(value),
+                Err(err) => writeln!(self.output, "  {err}")?,
+            }
+        }
+    }
+
+    /// Ask for a value that can be left unset, by giving an empty answer or 'none'.
+    fn ask_optional<T>(
+        &mut self,
+        question: &str,
+        default: Option<String>,
+        parse: impl Fn(&str) -> Result<T>,
+    ) -> Result<Option<T>> {
+        let question = if default.is_some() {
+            format!("{question} (enter 'none' for none)")
+        } else {
+            format!("{question} (leave empty for none)")
+        };
+        self.ask(&question, default, |answer| {
+            if answer.is_empty() || answer.eq_ignore_ascii_case("none") {
+                Ok(None)
+            } else {
+                parse(answer).map(Some)
+            }
+        })
+    }
+
+    fn confirm(&mut self, question: &str) -> Result<bool> {
+        self.ask(&format!("{question} [y/n]"), None, |answer| {
+            match answer.to_lowercase().as_str() {
+                "y" | "yes" => Ok(true),
+                "n" | "no" => Ok(false),
+                _ => Err(eyre!("Please answer 'y' or 'n'")),
+            }
+        })
+    }
+}
+
+fn ask_settings<R: BufRead, W: Write>(
+    prompt: &mut Prompt<R, W>,
+    choices: AddChoices,
+    first: bool,
+    nodes: &[NodeServiceData],
+) -> Result<Option<AddSettings>> {
+    let count = if first {
+        writeln!(
+            prompt.output,
+            "A single service will be added, since it will be the first node of a network"
+        )?;
+        1
+    } else {
+        prompt.ask(
+            "How many services should be added?",
+            Some(choices.count.unwrap_or(1).to_string()),
+            parse_count,
+        )?
+    };
+
+    let ports = |port: &Option<PortRange>| port.as_ref().map(port_range_to_string);
+    let node_port = prompt.ask_optional(
+        "Which port, or range of ports, should the nodes listen on? Ports are random if none",
+        ports(&choices.node_port),
+        |answer| parse_port_range(answer, count, nodes),
+    )?;
+    let rpc_port = prompt.ask_optional(
+        "Which port, or range of ports, should the RPC services use? Ports are random if none",
+        ports(&choices.rpc_port),
+        |answer| {
+            let range = parse_port_range(answer, count, nodes)?;
+            check_ports_overlap(&range, node_port.as_ref())?;
+            Ok(range)
+        },
+    )?;
+    let metrics_port = prompt.ask_optional(
+        "Which port, or range of ports, should the metrics servers use? The metrics servers are not started if none",
+        ports(&choices.metrics_port),
+        |answer| {
+            let range = parse_port_range(answer, count, nodes)?;
+            check_ports_overlap(&range, node_port.as_ref())?;
+            check_ports_overlap(&range, rpc_port.as_ref())?;
+            Ok(range)
+        },
+    )?;
+
+    let (rewards_address, checksummed) = prompt.ask(
+        "Which wallet address should receive the rewards?",
+        choices
+            .rewards_address
+            .map(|address| address.to_checksum(None)),
+        |answer| Ok((parse_rewards_address(answer)?, has_checksum(answer))),
+    )?;
+    if !checksummed {
+        writeln!(
+            prompt.output,
+            "  The address has no checksum, so it could not be checked for typos. Please make sure \
+            {} is the address of your wallet.",
+            rewards_address.to_checksum(None)
+        )?;
+    }
+
+    let evm_network = ask_evm_network(prompt, choices.evm_network)?;
+
+    let max_log_files = prompt.ask_optional(
+        "How many uncompressed log files should each node keep? The node's default is used if none",
+        choices.max_log_files.map(|n| n.to_string()),
+        parse_limit,
+    )?;
+    let max_archived_log_files = prompt.ask_optional(
+        "How many archived log files should each node keep? The node's default is used if none",
+        choices.max_archived_log_files.map(|n| n.to_string()),
+        parse_limit,
+    )?;
+
+    let settings = AddSettings {
+        count: Some(count),
+        evm_network,
+        max_archived_log_files,
+        max_log_files,
+        metrics_port,
+        node_port,
+        rewards_address,
+        rpc_port,
+    };
+    print_summary(&mut prompt.output, &settings)?;
+    if prompt.confirm("Add the services with these settings?")? {
+        Ok(Some(settings))
+    } else {
+        Ok(None)
+    }
+}
+
+fn ask_evm_network<R: BufRead, W: Write>(
+    prompt: &mut Prompt<R, W>,
+    default: Option<EvmNetwork>,
+) -> Result<EvmNetwork> {
+    let mut options = vec![
+        ("Arbitrum One", EvmNetwork::ArbitrumOne),
+        ("Arbitrum Sepolia (testnet)", EvmNetwork::ArbitrumSepolia),
+    ];
+    if cfg!(feature = "local") {
+        if let Ok(network) = ant_evm::utils::get_evm_network_from_env() {
+            options.push(("Local testnet", network));
+        }
+    }
+
+    writeln!(
+        prompt.output,
+        "Which EVM network should be used for payments?"
+    )?;
+    for (i, (name, _)) in options.iter().enumerate() {
+        writeln!(prompt.output, "  {}) {name}", i + 1)?;
+    }
+    let custom_choice = options.len() + 1;
+    writeln!(prompt.output, "  {custom_choice}) A custom network")?;
+
+    let default_choice = match &default {
+        Some(EvmNetwork::Custom(_)) => custom_choice,
+        Some(network) => options
+            .iter()
+            .position(|(_, option)| option == network)
+            .map_or(1, |i| i + 1),
+        None => 1,
+    };
+    let choice = prompt.ask(
+        "Network",
+        Some(default_choice.to_string()),
+        |answer| match answer.parse::<usize>() {
+            Ok(choice) if (1..=custom_choice).contains(&choice) => Ok(choice),
+            _ => Err(eyre!("Please choose a number from 1 to {custom_choice}")),
+        },
+    )?;
+    if choice < custom_choice {
+        return Ok(options.swap_remove(choice - 1).1);
+    }
+
+    let custom_default = match default {
+        Some(EvmNetwork::Custom(custom)) => Some(custom),
+        _ => None,
+    };
+    let rpc_url = prompt.ask(
+        "RPC URL of the network",
+        custom_default
+            .as_ref()
+            .map(|custom| custom.rpc_url_http.to_string()),
+        parse_rpc_url,
+    )?;
+    let payment_token_address = prompt.ask(
+        "Payment token contract address",
+        custom_default
+            .as_ref()
+            .map(|custom| custom.payment_token_address.to_checksum(None)),
+        parse_contract_address,
+    )?;
+    let data_payments_address = prompt.ask(
+        "Data payments contract address",
+        custom_default
+            .as_ref()
+            .map(|custom| custom.data_payments_address.to_checksum(None)),
+        parse_contract_address,
+    )?;
+    Ok(EvmNetwork::new_custom(
+        &rpc_url,
+        &payment_token_address.to_string(),
+        &data_payments_address.to_string(),
+    ))
+}
+
+fn print_summary(output: &mut impl Write, settings: &AddSettings) -> Result<()> {
+    let ports = |port: &Option<PortRange>, none: &str| {
+        port.as_ref().map_or(none.to_string(), port_range_to_string)
+    };
+    let limit = |limit: Option<usize>| limit.map_or("node default".to_string(), |n| n.to_string());
+
+    writeln!(output)?;
+    writeln!(output, "Summary:")?;
+    writeln!(output, "  Services: {}", settings.count.unwrap_or(1))?;
+    writeln!(
+        output,
+        "  Node ports: {}",
+        ports(&settings.node_port, "random")
+    )?;
+    writeln!(
+        output,
+        "  RPC ports: {}",
+        ports(&settings.rpc_port, "random")
+    )?;
+    writeln!(
+        output,
+        "  Metrics ports: {}",
+        ports(&settings.metrics_port, "metrics server disabled")
+    )?;
+    writeln!(
+        output,
+        "  Rewards address: {}",
+        settings.rewards_address.to_checksum(None)
+    )?;
+    writeln!(output, "  EVM network: {}", settings.evm_network)?;
+    if let EvmNetwork::Custom(custom) = &settings.evm_network {
+        writeln!(output, "    RPC URL: {}", custom.rpc_url_http)?;
+        writeln!(
+            output,
+            "    Payment token address: {}",
+            custom.payment_token_address.to_checksum(None)
+        )?;
+        writeln!(
+            output,
+            "    Data payments address: {}",
+            custom.data_payments_address.to_checksum(None)
+        )?;
+    }
+    writeln!(output, "  Max log files: {}", limit(settings.max_log_files))?;
+    writeln!(
+        output,
+        "  Max archived log files: {}",
+        limit(settings.max_archived_log_files)
+    )?;
+    writeln!(output)?;
+    Ok(())
+}
+
+fn port_range_to_string(range: &PortRange) -> String {
+    match range {
+        PortRange::Single(port) => port.to_string(),
+        PortRange::Range(start, end) => format!("{start}-{end}"),
+    }
+}
+
+fn parse_count(answer: &str) -> Result<u16> {
+    match answer.parse::<u16>() {
+        Ok(count) if count > 0 => Ok(count),
+        _ => Err(eyre!("The count must be a number greater than 0")),
+    }
+}
+
+fn parse_limit(answer: &str) -> Result<usize> {
+    match answer.parse::<usize>() {
+        Ok(limit) if limit > 0 => Ok(limit),
+        _ => Err(eyre!("The limit must be a number greater than 0")),
+    }
+}
+
+/// Parse a port range, checking it covers `count` services and isn't used by another service.
+fn parse_port_range(answer: &str, count: u16, nodes: &[NodeServiceData]) -> Result<PortRange> {
+    let range = PortRange::parse(answer)?;
+    range.validate(count)?;
+    check_port_availability(&range, nodes)?;
+    Ok(range)
+}
+
+fn check_ports_overlap(range: &PortRange, other: Option<&PortRange>) -> Result<()> {
+    let bounds = |range: &PortRange| match range {
+        PortRange::Single(port) => (*port, *port),
+        PortRange::Range(start, end) => (*start, *end),
+    };
+    if let Some(other) = other {
+        let (start, end) = bounds(range);
+        let (other_start, other_end) = bounds(other);
+        if start <= other_end && other_start <= end {
+            return Err(eyre!(
+                "The ports overlap with the ports already chosen ({})",
+                port_range_to_string(other)
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Whether the address uses mixed case, and so carries an EIP-55 checksum.
+fn has_checksum(address: &str) -> bool {
+    let hex = address.trim_start_matches("0x");
+    hex.chars().any(|c| c.is_ascii_lowercase()) && hex.chars().any(|c| c.is_ascii_uppercase())
+}
+
+/// Parse an address, verifying its checksum when it has one.
+fn parse_address(answer: &str) -> Result<RewardsAddress> {
+    let hex = answer
+        .strip_prefix("0x")
+        .ok_or_else(|| eyre!("The address must start with '0x'"))?;
+    if hex.len() != 40 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(eyre!(
+            "The address must be '0x' followed by 40 hexadecimal characters"
+        ));
+    }
+    if has_checksum(answer) {
+        RewardsAddress::parse_checksummed(answer, None)
+            .map_err(|_| eyre!("The address checksum is invalid, so it probably contains a typo"))
+    } else {
+        answer
+            .parse::<RewardsAddress>()
+            .map_err(|err| eyre!("The address is invalid: {err}"))
+    }
+}
+
+fn parse_rewards_address(answer: &str) -> Result<RewardsAddress> {
+    let address = parse_address(answer)?;
+    if address.is_zero() {
+        return Err(eyre!("The zero address can't receive rewards"));
+    }
+    Ok(address)
+}
+
+fn parse_contract_address(answer: &str) -> Result<RewardsAddress> {
+    parse_address(answer)
+}
+
+fn parse_rpc_url(answer: &str) -> Result<String> {
+    let url = reqwest::Url::parse(answer).map_err(|err| eyre!("The URL is invalid: {err}"))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(eyre!("The URL must use http or https"));
+    }
+    Ok(answer.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const ADDRESS: &str = "0x03B770D9cD32077cC0bF330c13C114a87643B124";
+
+    fn run_with_input(
+        input: &str,
+        choices: AddChoices,
+        first: bool,
+    ) -> (Result<Option<AddSettings>>, String) {
+        let mut prompt = Prompt {
+            input: Cursor::new(input.as_bytes().to_vec()),
+            output: Vec::new(),
+        };
+        let result = ask_settings(&mut prompt, choices, first, &[]);
+        (result, String::from_utf8(prompt.output).unwrap())
+    }
+
+    #[test]
+    fn rewards_address_checksum_is_verified() {
+        assert!(parse_rewards_address(ADDRESS).is_ok());
+        assert!(parse_rewards_address(&ADDRESS.to_lowercase()).is_ok());
+
+        let typo = ADDRESS.replace("cD32", "Cd32");
+        let err = parse_rewards_address(&typo).unwrap_err();
+        assert!(err.to_string().contains("checksum"));
+
+        assert!(parse_rewards_address("03B770D9cD32077cC0bF330c13C114a87643B124").is_err());
+        assert!(parse_rewards_address("0x03B770D9").is_err());
+        assert!(parse_rewards_address("0x0000000000000000000000000000000000000000").is_err());
+    }
+
+    #[test]
+    fn invalid_answers_are_asked_again() {
+        let input = format!(
+            "0\n3\n12000-12001\n12000-12002\n\n\n{bad}\n{ADDRESS}\n\n\n\ny\n",
+            bad = ADDRESS.replace("cD32", "Cd32"),
+        );
+        let (result, output) = run_with_input(&input, AddChoices::default(), false);
+
+        let settings = result.unwrap().expect("the settings to be confirmed");
+        assert_eq!(settings.count, Some(3));
+        assert_eq!(settings.node_port, Some(PortRange::Range(12000, 12002)));
+        assert_eq!(settings.rpc_port, None);
+        assert_eq!(settings.rewards_address.to_checksum(None), ADDRESS);
+        assert_eq!(settings.evm_network, EvmNetwork::ArbitrumOne);
+        assert_eq!(settings.max_log_files, None);
+
+        assert!(output.contains("The count must be a number greater than 0"));
+        assert!(output.contains("The count (3) does not match the number of ports (2)"));
+        assert!(output.contains("checksum is invalid"));
+        assert!(output.contains("Rewards address: 0x03B770D9cD32077cC0bF330c13C114a87643B124"));
+    }
+
+    #[test]
+    fn arguments_are_offered_as_defaults() {
+        let choices = AddChoices {
+            count: Some(2),
+            evm_network: Some(EvmNetwork::ArbitrumSepolia),
+            max_log_files: Some(5),
+            node_port: Some(PortRange::Range(13000, 13001)),
+            rewards_address: Some(ADDRESS.parse().unwrap()),
+            ..Default::default()
+        };
+        let (result, _) = run_with_input("\n\n\nnone\n\n\n\n\ny\n", choices, false);
+
+        let settings = result.unwrap().unwrap();
+        assert_eq!(settings.count, Some(2));
+        assert_eq!(settings.node_port, Some(PortRange::Range(13000, 13001)));
+        assert_eq!(settings.evm_network, EvmNetwork::ArbitrumSepolia);
+        assert_eq!(settings.max_log_files, Some(5));
+        assert_eq!(settings.rpc_port, None);
+    }
+
+    #[test]
+    fn overlapping_ports_are_rejected_and_custom_networks_are_supported() {
+        let input = format!(
+            "12000\n12000\n12001\n\n{lowercase}\n3\nnot a url\nhttp://127.0.0.1:8545\n\
+             {ADDRESS}\n{ADDRESS}\n\n\nn\n",
+            lowercase = ADDRESS.to_lowercase(),
+        );
+        let (result, output) = run_with_input(&input, AddChoices::default(), true);
+
+        assert!(result.unwrap().is_none(), "the settings were declined");
+        assert!(output.contains("A single service will be added"));
+        assert!(output.contains("The ports overlap with the ports already chosen (12000)"));
+        assert!(output.contains("The address has no checksum"));
+        assert!(output.contains("The URL is invalid"));
+        assert!(output.contains("RPC URL: http://127.0.0.1:8545/"));
+    }
+
+    #[test]
+    fn resolving_arguments_requires_the_rewards_address_and_network() {
+        let choices = AddChoices {
+            rewards_address: Some(ADDRESS.parse().unwrap()),
+            ..Default::default()
+        };
+        assert!(choices.clone().resolve().is_err());
+
+        let settings = AddChoices {
+            evm_network: Some(EvmNetwork::ArbitrumOne),
+            ..choices
+        }
+        .resolve()
+        .unwrap();
+        assert_eq!(settings.count, None);
+    }
+}
@@ -0,0 +1,294 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::VerbosityLevel;
+use ant_service_management::NodeServiceData;
+use color_eyre::Result;
+use colored::Colorize;
+use std::{
+    io::{BufRead, Write},
+    path::{Path, PathBuf},
+};
+use sysinfo::System;
+
+/// What a destructive command will lose, shown before asking for confirmation.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeletionSummary {
+    pub services: Vec<ServiceLoss>,
+}
+
+/// The identity and directories of a single service that will be removed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServiceLoss {
+    pub service_name: String,
+    /// `None` if the service was never started, so it has no identity yet.
+    pub peer_id: Option<String>,
+    /// The directories that will be deleted, with their size in bytes.
+    ///
+    /// This is empty when the directories are being kept.
+    pub directories: Vec<(PathBuf, u64)>,
+}
+
+impl DeletionSummary {
+    pub fn new<'a>(
+        nodes: impl IntoIterator<Item = &'a NodeServiceData>,
+        keep_directories: bool,
+    ) -> Self {
+        let services = nodes
+            .into_iter()
+            .map(|node| ServiceLoss {
+                service_name: node.service_name.clone(),
+                peer_id: node.peer_id.map(|peer_id| peer_id.to_string()),
+                directories: if keep_directories {
+                    Vec::new()
+                } else {
+                    [&node.data_dir_path, &node.log_dir_path]
+                        .into_iter()
+                        .filter(|path| path.exists())
+                        .map(|path| (path.clone(), dir_size(path)))
+                        .collect()
+                },
+            })
+            .collect();
+        Self { services }
+    }
+
+    pub fn total_size(&self) -> u64 {
+        self.services
+            .iter()
+            .flat_map(|service| service.directories.iter().map(|(_, size)| size))
+            .sum()
+    }
+
+    fn write(&self, output: &mut impl Write) -> Result<()> {
+        if self.services.is_empty() {
+            writeln!(output, "No services are registered")?;
+            return Ok(());
+        }
+
+        writeln!(output, "The following services will be removed:")?;
+        for service in &self.services {
+            match &service.peer_id {
+                Some(peer_id) => writeln!(output, "  {} ({peer_id})", service.service_name)?,
+                None => writeln!(output, "  {} (never started)", service.service_name)?,
+            }
+            for (path, size) in &service.directories {
+                writeln!(output, "    {} ({})", path.display(), format_size(*size))?;
+            }
+        }
+
+        let identities = self
+            .services
+            .iter()
+            .filter(|service| service.peer_id.is_some() && !service.directories.is_empty())
+            .count();
+        if identities > 0 {
+            writeln!(
+                output,
+                "{identities} node identities and {} of data and logs will be permanently lost",
+                format_size(self.total_size())
+            )?;
+        } else if self.total_size() > 0 {
+            writeln!(
+                output,
+                "{} of data and logs will be permanently lost",
+                format_size(self.total_size())
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Show what removing `nodes` will lose and ask for the host name to be typed to confirm it.
+///
+/// With `yes_i_mean_it` and minimal verbosity, e.g. when driven by the launchpad, nothing is shown
+/// and the directories aren't measured.
+pub fn confirm_removal<'a>(
+    action: &str,
+    nodes: impl IntoIterator<Item = &'a NodeServiceData>,
+    keep_directories: bool,
+    yes_i_mean_it: bool,
+    verbosity: VerbosityLevel,
+) -> Result<bool> {
+    if yes_i_mean_it && verbosity == VerbosityLevel::Minimal {
+        return Ok(true);
+    }
+    let summary = DeletionSummary::new(nodes, keep_directories);
+    confirm_destructive(action, &summary, yes_i_mean_it)
+}
+
+/// Show what `action` will lose and ask for the host name to be typed to confirm it.
+///
+/// The prompt is skipped if `yes_i_mean_it` is set, but the summary is still shown. Returns
+/// whether the action should go ahead.
+pub fn confirm_destructive(
+    action: &str,
+    summary: &DeletionSummary,
+    yes_i_mean_it: bool,
+) -> Result<bool> {
+    let stdin = std::io::stdin();
+    confirm_with_input(
+        &mut stdin.lock(),
+        &mut std::io::stdout(),
+        &get_host_name(),
+        action,
+        summary,
+        yes_i_mean_it,
+    )
+}
+
+fn confirm_with_input(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    host_name: &str,
+    action: &str,
+    summary: &DeletionSummary,
+    yes_i_mean_it: bool,
+) -> Result<bool> {
+    writeln!(output, "{} {action}", "WARNING:".yellow())?;
+    summary.write(output)?;
+    if yes_i_mean_it {
+        return Ok(true);
+    }
+
+    write!(
+        output,
+        "This can't be undone. To confirm, type the name of this host ({}): ",
+        host_name.bold()
+    )?;
+    output.flush()?;
+
+    let mut line = String::new();
+    input.read_line(&mut line)?;
+    if line.trim() == host_name {
+        return Ok(true);
+    }
+    writeln!(
+        output,
+        "The name did not match, so nothing was changed. Use --yes-i-mean-it to skip this prompt."
+    )?;
+    Ok(false)
+}
+
+fn get_host_name() -> String {
+    System::host_name().unwrap_or_else(|| "localhost".to_string())
+}
+
+/// The total size of the files under `path`, without following symbolic links.
+///
+/// Anything that can't be read is not counted.
+fn dir_size(path: &Path) -> u64 {
+    let Ok(metadata) = std::fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| dir_size(&entry.path()))
+        .sum()
+}
+
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_fs::prelude::*;
+    use std::io::Cursor;
+
+    fn summary() -> DeletionSummary {
+        DeletionSummary {
+            services: vec![
+                ServiceLoss {
+                    service_name: "antnode1".to_string(),
+                    peer_id: Some(
+                        "12D3KooWS2tpXGGTmg2AHFiDh57yPQnat49YHnyqoggzXZWpqkCR".to_string(),
+                    ),
+                    directories: vec![(PathBuf::from("/var/antctl/services/antnode1"), 2048)],
+                },
+                ServiceLoss {
+                    service_name: "antnode2".to_string(),
+                    peer_id: None,
+                    directories: vec![],
+                },
+            ],
+        }
+    }
+
+    fn confirm(input: &str, yes_i_mean_it: bool) -> (bool, String) {
+        let mut output = Vec::new();
+        let confirmed = confirm_with_input(
+            &mut Cursor::new(input.as_bytes().to_vec()),
+            &mut output,
+            "node-host",
+            "all services will be removed",
+            &summary(),
+            yes_i_mean_it,
+        )
+        .unwrap();
+        (confirmed, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn typing_the_host_name_confirms() {
+        let (confirmed, output) = confirm("node-host\n", false);
+        assert!(confirmed);
+        assert!(output.contains("antnode1 (12D3KooWS2tpXGGTmg2AHFiDh57yPQnat49YHnyqoggzXZWpqkCR)"));
+        assert!(output.contains("/var/antctl/services/antnode1 (2.0 KB)"));
+        assert!(output.contains("antnode2 (never started)"));
+        assert!(output.contains("1 node identities and 2.0 KB"));
+    }
+
+    #[test]
+    fn anything_other_than_the_host_name_aborts() {
+        for input in ["y\n", "yes\n", "node\n", "\n", ""] {
+            let (confirmed, output) = confirm(input, false);
+            assert!(!confirmed, "'{input}' should not confirm");
+            assert!(output.contains("nothing was changed"));
+        }
+    }
+
+    #[test]
+    fn yes_i_mean_it_skips_the_prompt_but_shows_the_summary() {
+        let (confirmed, output) = confirm("", true);
+        assert!(confirmed);
+        assert!(output.contains("antnode1"));
+        assert!(!output.contains("type the name of this host"));
+    }
+
+    #[test]
+    fn dir_size_counts_nested_files() {
+        let tmp = assert_fs::TempDir::new().unwrap();
+        tmp.child("record_store/a").write_binary(&[0; 100]).unwrap();
+        tmp.child("record_store/b").write_binary(&[0; 50]).unwrap();
+        tmp.child("secret-key").write_binary(&[0; 32]).unwrap();
+
+        assert_eq!(dir_size(tmp.path()), 182);
+        assert_eq!(dir_size(&tmp.path().join("missing")), 0);
+        assert_eq!(format_size(182), "182 B");
+        assert_eq!(format_size(3 * 1024 * 1024 + 512 * 1024), "3.5 MB");
+    }
+}
@@ -10,16 +10,18 @@ use crate::{
     add_services::{add_daemon, config::AddDaemonServiceOptions},
     config::{self, is_running_as_root},
     helpers::{download_and_extract_release, get_bin_version},
+    mirror::get_release_repo,
     print_banner, ServiceManager, VerbosityLevel,
 };
-use ant_releases::{AntReleaseRepoActions, ReleaseType};
+use ant_releases::ReleaseType;
 use ant_service_management::{
     control::{ServiceControl, ServiceController},
-    DaemonService, NodeRegistry,
+    DaemonService, DaemonTextfileOptions, DaemonWatchdogOptions, NodeRegistry,
 };
 use color_eyre::{eyre::eyre, Result};
 use std::{net::Ipv4Addr, path::PathBuf};
 
+#[expect(clippy::too_many_arguments)]
 pub async fn add(
     address: Ipv4Addr,
     env_variables: Option<Vec<(String, String)>>,
@@ -27,6 +29,8 @@ pub async fn add(
     src_path: Option<PathBuf>,
     url: Option<String>,
     version: Option<String>,
+    textfile: DaemonTextfileOptions,
+    watchdog: DaemonWatchdogOptions,
     verbosity: VerbosityLevel,
 ) -> Result<()> {
     if !is_running_as_root() {
@@ -44,12 +48,11 @@ pub async fn add(
     service_manager.create_service_user(service_user)?;
 
     let mut node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
-    let release_repo = <dyn AntReleaseRepoActions>::default_config();
-
     let (daemon_src_bin_path, version) = if let Some(path) = src_path {
         let version = get_bin_version(&path)?;
         (path, version)
     } else {
+        let release_repo = get_release_repo().await?;
         download_and_extract_release(
             ReleaseType::AntCtlDaemon,
             url.clone(),
@@ -74,8 +77,10 @@ pub async fn add(
             daemon_install_bin_path: config::get_daemon_install_path(),
             daemon_src_bin_path,
             port,
+            textfile,
             user: "root".to_string(),
             version,
+            watchdog,
         },
         &mut node_registry,
         &ServiceController {},
@@ -0,0 +1,283 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use super::node::{self, summarise_any_failed_ops};
+use crate::{
+    config,
+    error::Error,
+    history::{reinstall_removed_service, CommandHistory, HistoryEntry, UndoAction},
+    print_banner, VerbosityLevel, DEFAULT_NODE_STARTUP_CONNECTION_TIMEOUT_S,
+};
+use ant_service_management::{control::ServiceController, NodeRegistry, ServiceStatus};
+use color_eyre::{eyre::eyre, Help, Result};
+use colored::Colorize;
+
+pub fn list(id: Option<u64>, limit: usize, json: bool) -> Result<()> {
+    let history = CommandHistory::load(&config::get_command_history_path()?)?;
+
+    if let Some(id) = id {
+        let entry = history
+            .get(id)
+            .ok_or_else(|| eyre!("There is no history entry with ID {id}"))?;
+        if json {
+            println!("{}", serde_json::to_string_pretty(entry)?);
+        } else {
+            print_entry_details(entry);
+        }
+        return Ok(());
+    }
+
+    let skip = history.entries.len().saturating_sub(limit);
+    let entries = &history.entries[skip..];
+    if json {
+        println!("{}", serde_json::to_string_pretty(entries)?);
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        println!("No commands have been recorded");
+        return Ok(());
+    }
+    println!(
+        "{:<5} {:<20} {:<7} {:<30} Command",
+        "ID", "Time", "Result", "Undo"
+    );
+    for entry in entries {
+        println!(
+            "{:<5} {:<20} {:<7} {:<30} antctl {}",
+            entry.id,
+            entry.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            if entry.succeeded { "ok" } else { "failed" },
+            undo_summary(entry),
+            entry.args.join(" ")
+        );
+    }
+    Ok(())
+}
+
+fn undo_summary(entry: &HistoryEntry) -> String {
+    match (&entry.undo, entry.undone_by) {
+        (_, Some(undone_by)) => format!("undone by {undone_by}"),
+        (Some(action), None) => action.description(),
+        (None, None) => "-".to_string(),
+    }
+}
+
+fn print_entry_details(entry: &HistoryEntry) {
+    print_banner(&format!("History entry {}", entry.id));
+    println!("Command: antctl {}", entry.args.join(" "));
+    println!("Time: {}", entry.timestamp.format("%Y-%m-%d %H:%M:%S UTC"));
+    println!(
+        "Result: {}",
+        if entry.succeeded {
+            "succeeded"
+        } else {
+            "failed"
+        }
+    );
+    match (&entry.undo, entry.undone_by) {
+        (_, Some(undone_by)) => println!("Undo: undone by entry {undone_by}"),
+        (Some(action), None) => println!(
+            "Undo: `antctl undo {}` will {}",
+            entry.id,
+            action.description()
+        ),
+        (None, None) => println!("Undo: not possible"),
+    }
+
+    if entry.changes.is_empty() {
+        println!("No changes were made to the services");
+        return;
+    }
+    println!("Changes:");
+    for change in &entry.changes {
+        match (&change.before, &change.after) {
+            (None, Some(_)) => println!("  {} {} (added)", "+".green(), change.service_name),
+            (Some(_), None) => println!(
+                "  {} {} (deleted from the registry)",
+                "-".red(),
+                change.service_name
+            ),
+            _ => {
+                println!("  {} {}", "~".yellow(), change.service_name);
+                for (field, before, after) in change.changed_fields() {
+                    println!("      {field}: {before} -> {after}");
+                }
+            }
+        }
+    }
+}
+
+/// Revert the changes of a history entry; the most recent one that can be undone by default.
+///
+/// The `undo` command is itself recorded in the history by the caller, so the undone entry is
+/// marked with the ID the `undo` entry will get.
+pub async fn undo(id: Option<u64>, verbosity: VerbosityLevel) -> Result<()> {
+    let history_path = config::get_command_history_path()?;
+    let history = CommandHistory::load(&history_path)?;
+    let entry = match id {
+        Some(id) => history
+            .get(id)
+            .ok_or_else(|| eyre!("There is no history entry with ID {id}"))?,
+        None => history.last_undoable().ok_or_else(|| {
+            eyre!("There are no commands in the history that can be undone")
+                .suggestion("Run `antctl history` to see the recorded commands")
+        })?,
+    }
+    .clone();
+    if let Some(undone_by) = entry.undone_by {
+        return Err(eyre!(
+            "History entry {} was already undone by entry {undone_by}",
+            entry.id
+        ));
+    }
+    let Some(action) = &entry.undo else {
+        return Err(eyre!(
+            "The `{}` command of history entry {} cannot be undone",
+            entry.command,
+            entry.id
+        )
+        .suggestion(
+            "Only `add`, `remove --keep-directories`, `start` and `stop` can be undone, and only \
+            when they changed any services",
+        ));
+    };
+
+    if verbosity != VerbosityLevel::Minimal {
+        print_banner(&format!("Undo History Entry {}", entry.id));
+        println!("Command: antctl {}", entry.args.join(" "));
+        println!("Undo: {}", action.description());
+    }
+    info!("Undoing history entry {}: {action:?}", entry.id);
+
+    match action {
+        UndoAction::RemoveAddedServices { service_names } => {
+            let service_names = services_not_removed(service_names)?;
+            if !service_names.is_empty() {
+                node::stop(None, vec![], service_names.clone(), verbosity).await?;
+                node::remove(
+                    false,
+                    false,
+                    vec![],
+                    service_names.clone(),
+                    false,
+                    verbosity,
+                )
+                .await?;
+            }
+
+            // The services did not exist before they were added, so they are deleted from the
+            // registry rather than being left with the `Removed` status.
+            let mut node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
+            node_registry.nodes.retain(|node| {
+                node.status != ServiceStatus::Removed
+                    || !action.service_names().contains(&node.service_name)
+            });
+            node_registry.save()?;
+        }
+        UndoAction::ReinstallRemovedServices { service_names } => {
+            reinstall_removed_services(&entry, service_names, verbosity)?;
+        }
+        UndoAction::StopStartedServices { service_names } => {
+            let service_names = services_not_removed(service_names)?;
+            node::stop(None, vec![], service_names, verbosity).await?;
+        }
+        UndoAction::StartStoppedServices { service_names } => {
+            let service_names = services_not_removed(service_names)?;
+            node::start(
+                DEFAULT_NODE_STARTUP_CONNECTION_TIMEOUT_S,
+                None,
+                None,
+                vec![],
+                service_names,
+                verbosity,
+            )
+            .await?;
+        }
+    }
+
+    let mut history = CommandHistory::load(&history_path)?;
+    let undo_id = history.next_id();
+    if let Some(undone) = history.get_mut(entry.id) {
+        undone.undone_by = Some(undo_id);
+    }
+    history.save()?;
+    Ok(())
+}
+
+/// The services that are still in the registry, without the `Removed` status.
+fn services_not_removed(service_names: &[String]) -> Result<Vec<String>> {
+    let node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
+    let (present, missing): (Vec<_>, Vec<_>) = service_names.iter().cloned().partition(|name| {
+        node_registry
+            .nodes
+            .iter()
+            .any(|node| node.service_name == *name && node.status != ServiceStatus::Removed)
+    });
+    if !missing.is_empty() {
+        println!(
+            "Skipping service(s) that have been removed since: {}",
+            missing.join(", ")
+        );
+    }
+    Ok(present)
+}
+
+fn reinstall_removed_services(
+    entry: &HistoryEntry,
+    service_names: &[String],
+    verbosity: VerbosityLevel,
+) -> Result<()> {
+    let mut node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
+    let env_variables = node_registry.environment_variables.clone();
+    let service_control = ServiceController {};
+
+    let mut failed_services = Vec::new();
+    for service_name in service_names {
+        let previous = entry
+            .changes
+            .iter()
+            .find(|change| change.service_name == *service_name)
+            .and_then(|change| change.before.clone());
+        let service_data = node_registry
+            .nodes
+            .iter_mut()
+            .find(|node| node.service_name == *service_name);
+        let (Some(previous), Some(service_data)) = (previous, service_data) else {
+            failed_services.push((
+                service_name.clone(),
+                Error::UndoNotPossible {
+                    service_name: service_name.clone(),
+                    reason: "it is no longer in the node registry".to_string(),
+                },
+            ));
+            continue;
+        };
+
+        match reinstall_removed_service(
+            service_data,
+            &previous,
+            env_variables.clone(),
+            &service_control,
+        ) {
+            Ok(()) => {
+                debug!("Reinstalled service {service_name}");
+                node_registry.save()?;
+                if verbosity != VerbosityLevel::Minimal {
+                    println!("{} Service {service_name} was re-added", "✓".green());
+                }
+            }
+            Err(err) => {
+                error!("Failed to reinstall service {service_name}: {err}");
+                failed_services.push((service_name.clone(), err));
+            }
+        }
+    }
+
+    summarise_any_failed_ops(failed_services, "re-add", verbosity)
+}
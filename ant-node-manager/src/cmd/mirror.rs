@@ -0,0 +1,96 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::{
+    config,
+    mirror::{MirrorConfig, MirrorReleaseRepository},
+    print_banner, VerbosityLevel,
+};
+use color_eyre::{Help, Result};
+use colored::Colorize;
+
+/// Configure the mirror, after checking its manifest can be fetched and verified.
+pub async fn set(url: String, public_key: String, verbosity: VerbosityLevel) -> Result<()> {
+    let config_path = config::get_artifact_mirror_config_path()?;
+    let mut mirror_config = MirrorConfig::new(&url, &public_key)?;
+    // Keep the rollback protection when the same mirror is configured again
+    if let Some(existing) = MirrorConfig::load(&config_path)? {
+        if existing.base_url == mirror_config.base_url
+            && existing.public_key == mirror_config.public_key
+        {
+            mirror_config.last_sequence = existing.last_sequence;
+        }
+    }
+    let repo = MirrorReleaseRepository::connect(mirror_config.clone())
+        .await
+        .suggestion(
+            "The mirror must serve `manifest.json` and `manifest.json.sig` at its base URL",
+        )?;
+
+    mirror_config.last_sequence = repo.manifest().sequence;
+    mirror_config.save(&config_path)?;
+    info!(
+        "Configured the artifact mirror at {}",
+        mirror_config.base_url
+    );
+    if verbosity != VerbosityLevel::Minimal {
+        println!(
+            "{} Binaries will be downloaded from the artifact mirror at {}",
+            "✓".green(),
+            mirror_config.base_url
+        );
+        print_latest_versions(&repo);
+    }
+    Ok(())
+}
+
+pub async fn show() -> Result<()> {
+    let Some(mirror_config) = MirrorConfig::load(&config::get_artifact_mirror_config_path()?)?
+    else {
+        println!(
+            "No artifact mirror is configured, so binaries are downloaded from the public releases"
+        );
+        return Ok(());
+    };
+
+    print_banner("Artifact Mirror");
+    println!("Base URL: {}", mirror_config.base_url);
+    println!("Public key: {}", mirror_config.public_key);
+    match MirrorReleaseRepository::connect(mirror_config).await {
+        Ok(repo) => {
+            println!("Manifest: {}", "verified".green());
+            print_latest_versions(&repo);
+        }
+        Err(err) => println!("Manifest: {} ({err})", "unusable".red()),
+    }
+    Ok(())
+}
+
+pub fn remove(verbosity: VerbosityLevel) -> Result<()> {
+    let path = config::get_artifact_mirror_config_path()?;
+    if path.exists() {
+        std::fs::remove_file(&path)?;
+        info!("Removed the artifact mirror configuration at {path:?}");
+    }
+    if verbosity != VerbosityLevel::Minimal {
+        println!(
+            "{} Binaries will be downloaded from the public releases",
+            "✓".green()
+        );
+    }
+    Ok(())
+}
+
+fn print_latest_versions(repo: &MirrorReleaseRepository) {
+    let manifest = repo.manifest();
+    println!("Manifest sequence: {}", manifest.sequence);
+    println!("Approved archives: {}", manifest.artifacts.len());
+    for (binary, version) in &manifest.latest {
+        println!("Latest approved {binary}: {version}");
+    }
+}
@@ -6,20 +6,28 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
+pub mod add_wizard;
 pub mod auditor;
+pub mod confirm;
 pub mod daemon;
 pub mod faucet;
+pub mod history;
 pub mod local;
+pub mod mirror;
 pub mod nat_detection;
 pub mod node;
 
 use crate::{
+    compatibility::{self, Component, Severity, COMPATIBILITY_MATRIX},
+    config,
+    error::Error,
     helpers::{download_and_extract_release, get_bin_version},
+    mirror::get_release_repo,
     print_banner, VerbosityLevel,
 };
 use ant_releases::{AntReleaseRepoActions, ReleaseType};
-use ant_service_management::UpgradeResult;
-use color_eyre::{eyre::eyre, Result};
+use ant_service_management::{NodeRegistry, UpgradeResult};
+use color_eyre::{eyre::eyre, Help, Result};
 use colored::Colorize;
 use semver::Version;
 use std::{
@@ -47,7 +55,7 @@ pub async fn download_and_get_upgrade_bin_path(
         return Ok((path, bin_version.parse()?));
     }
 
-    let release_repo = <dyn AntReleaseRepoActions>::default_config();
+    let release_repo = get_release_repo().await?;
     if let Some(version) = version {
         debug!("Downloading provided version {version} of {release_type}");
         let (upgrade_bin_path, version) = download_and_extract_release(
@@ -131,6 +139,53 @@ pub fn print_upgrade_summary(upgrade_summary: Vec<(String, UpgradeResult)>) {
     }
 }
 
+/// Check antctl, the daemon and the nodes in the registry against the compatibility matrix.
+///
+/// Incompatible versions are an error if `refuse_incompatible` is set; otherwise they are only
+/// reported, like the combinations that only warrant a warning.
+pub fn check_compatibility(refuse_incompatible: bool, verbosity: VerbosityLevel) -> Result<()> {
+    // The commands themselves report a registry that can't be loaded, so it's not reported here.
+    let node_registry = match config::get_node_registry_path()
+        .map_err(|err| eyre!(err))
+        .and_then(|path| NodeRegistry::load(&path).map_err(|err| eyre!(err)))
+    {
+        Ok(node_registry) => node_registry,
+        Err(err) => {
+            debug!("Not checking the compatibility of the installed components: {err}");
+            return Ok(());
+        }
+    };
+    let installed = compatibility::installed_components(
+        Component::AntCtl,
+        Version::parse(env!("CARGO_PKG_VERSION"))?,
+        &node_registry,
+    );
+
+    let mut refused = Vec::new();
+    for incompatibility in compatibility::check(COMPATIBILITY_MATRIX, &installed) {
+        if incompatibility.severity == Severity::Incompatible && refuse_incompatible {
+            error!("Incompatible versions: {incompatibility}");
+            refused.push(incompatibility.to_string());
+            continue;
+        }
+        warn!("Version compatibility: {incompatibility}");
+        if verbosity != VerbosityLevel::Minimal {
+            let label = match incompatibility.severity {
+                Severity::Warning => "Warning:".yellow(),
+                Severity::Incompatible => "Incompatible:".red(),
+            };
+            println!("{label} {incompatibility}");
+        }
+    }
+
+    if !refused.is_empty() {
+        return Err(Error::IncompatibleVersions(refused.join("; "))).suggestion(
+            "Upgrade the nodes with `antctl upgrade`, or use `antctl --ignore-compatibility` to run the command anyway",
+        );
+    }
+    Ok(())
+}
+
 pub async fn get_bin_path(
     build: bool,
     path: Option<PathBuf>,
@@ -7,10 +7,11 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::{
-    config::get_node_registry_path, helpers::download_and_extract_release, VerbosityLevel,
+    config::get_node_registry_path, helpers::download_and_extract_release,
+    mirror::get_release_repo, VerbosityLevel,
 };
 use ant_bootstrap::ContactsFetcher;
-use ant_releases::{AntReleaseRepoActions, ReleaseType};
+use ant_releases::ReleaseType;
 use ant_service_management::{NatDetectionStatus, NodeRegistry};
 use color_eyre::eyre::{bail, OptionExt, Result};
 use libp2p::Multiaddr;
@@ -63,7 +64,7 @@ pub async fn run_nat_detection(
     let nat_detection_path = if let Some(path) = path {
         path
     } else {
-        let release_repo = <dyn AntReleaseRepoActions>::default_config();
+        let release_repo = get_release_repo().await?;
 
         let (nat_detection_path, _) = download_and_extract_release(
             ReleaseType::NatDetection,
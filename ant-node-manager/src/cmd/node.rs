@@ -8,20 +8,24 @@
 
 #![allow(clippy::too_many_arguments)]
 
-use super::{download_and_get_upgrade_bin_path, print_upgrade_summary};
+use super::{confirm::confirm_removal, download_and_get_upgrade_bin_path, print_upgrade_summary};
 use crate::{
     add_services::{
         add_node,
         config::{AddNodeServiceOptions, PortRange},
     },
     config::{self, is_running_as_root},
+    error::Error,
+    error_catalog::ErrorCode,
     helpers::{download_and_extract_release, get_bin_version},
-    print_banner, refresh_node_registry, status_report, ServiceManager, VerbosityLevel,
+    mirror::get_release_repo,
+    print_banner, refresh_node_registry, status_report, wait_for_connectivity,
+    ConnectivityRequirement, ServiceManager, VerbosityLevel,
 };
 use ant_bootstrap::PeersArgs;
 use ant_evm::{EvmNetwork, RewardsAddress};
 use ant_logging::LogFormat;
-use ant_releases::{AntReleaseRepoActions, ReleaseType};
+use ant_releases::ReleaseType;
 use ant_service_management::{
     control::{ServiceControl, ServiceController},
     rpc::RpcClient,
@@ -31,7 +35,13 @@ use color_eyre::{eyre::eyre, Help, Result};
 use colored::Colorize;
 use libp2p_identity::PeerId;
 use semver::Version;
-use std::{cmp::Ordering, io::Write, net::Ipv4Addr, path::PathBuf, str::FromStr, time::Duration};
+use std::{
+    cmp::Ordering,
+    net::{Ipv4Addr, SocketAddr},
+    path::PathBuf,
+    str::FromStr,
+    time::Duration,
+};
 use tracing::debug;
 
 /// Returns the added service names
@@ -91,12 +101,11 @@ pub async fn add(
     };
 
     let mut node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
-    let release_repo = <dyn AntReleaseRepoActions>::default_config();
-
     let (antnode_src_path, version) = if let Some(path) = src_path.clone() {
         let version = get_bin_version(&path)?;
         (path, version)
     } else {
+        let release_repo = get_release_repo().await?;
         download_and_extract_release(
             ReleaseType::AntNode,
             url.clone(),
@@ -192,15 +201,45 @@ pub async fn balance(
 }
 
 pub async fn remove(
+    all: bool,
     keep_directories: bool,
     peer_ids: Vec<String>,
     service_names: Vec<String>,
+    yes_i_mean_it: bool,
     verbosity: VerbosityLevel,
 ) -> Result<()> {
+    remove_services(
+        all,
+        keep_directories,
+        peer_ids,
+        service_names,
+        yes_i_mean_it,
+        false,
+        verbosity,
+    )
+    .await
+}
+
+/// Remove the services, asking to confirm the removal of all of them unless `already_confirmed`.
+async fn remove_services(
+    all: bool,
+    keep_directories: bool,
+    peer_ids: Vec<String>,
+    service_names: Vec<String>,
+    yes_i_mean_it: bool,
+    already_confirmed: bool,
+    verbosity: VerbosityLevel,
+) -> Result<()> {
+    if !all && peer_ids.is_empty() && service_names.is_empty() {
+        return Err(eyre!("No services were specified for removal").suggestion(
+            "Use --service-name or --peer-id to choose services, or --all to remove every service",
+        ));
+    }
+
     if verbosity != VerbosityLevel::Minimal {
         print_banner("Remove Antnode Services");
     }
-    info!("Removing antnode services with keep_dirs=({keep_directories}) for: {peer_ids:?}, {service_names:?}");
+    info!("Removing antnode services with all=({all}), keep_dirs=({keep_directories}) for: {peer_ids:?}, {service_names:?}");
 
     let mut node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
     refresh_node_registry(
@@ -222,6 +261,23 @@ pub async fn remove(
         return Ok(());
     }
 
+    if all
+        && !already_confirmed
+        && !confirm_removal(
+            "all antnode services will be removed.",
+            service_indices
+                .iter()
+                .map(|&index| &node_registry.nodes[index]),
+            keep_directories,
+            yes_i_mean_it,
+            verbosity,
+        )?
+    {
+        info!("Removal of all services was not confirmed");
+        println!("Removal aborted");
+        return Ok(());
+    }
+
     let mut failed_services = Vec::new();
     for &index in &service_indices {
         let node = &mut node_registry.nodes[index];
@@ -236,7 +292,7 @@ pub async fn remove(
             }
             Err(err) => {
                 error!("Failed to remove service {}: {err}", node.service_name);
-                failed_services.push((node.service_name.clone(), err.to_string()))
+                failed_services.push((node.service_name.clone(), err))
             }
         }
     }
@@ -244,26 +300,29 @@ pub async fn remove(
     summarise_any_failed_ops(failed_services, "remove", verbosity)
 }
 
-pub async fn reset(force: bool, verbosity: VerbosityLevel) -> Result<()> {
+pub async fn reset(yes_i_mean_it: bool, verbosity: VerbosityLevel) -> Result<()> {
     if verbosity != VerbosityLevel::Minimal {
         print_banner("Reset Antnode Services");
     }
-    info!("Resetting all antnode services, with force={force}");
-
-    if !force {
-        println!("WARNING: all antnode services, data, and logs will be removed.");
-        println!("Do you wish to proceed? [y/n]");
-        std::io::stdout().flush()?;
-        let mut input = String::new();
-        std::io::stdin().read_line(&mut input)?;
-        if input.trim().to_lowercase() != "y" {
-            println!("Reset aborted");
-            return Ok(());
-        }
+    info!("Resetting all antnode services, with yes_i_mean_it={yes_i_mean_it}");
+
+    let node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
+    if !confirm_removal(
+        "all antnode services, data, and logs will be removed.",
+        node_registry
+            .nodes
+            .iter()
+            .filter(|node| node.status != ServiceStatus::Removed),
+        false,
+        yes_i_mean_it,
+        verbosity,
+    )? {
+        println!("Reset aborted");
+        return Ok(());
     }
 
     stop(None, vec![], vec![], verbosity).await?;
-    remove(false, vec![], vec![], verbosity).await?;
+    remove_services(true, false, vec![], vec![], true, true, verbosity).await?;
 
     // Due the possibility of repeated runs of the `reset` command, we need to check for the
     // existence of this file before attempting to delete it, since `remove_file` will return an
@@ -279,6 +338,7 @@ pub async fn reset(force: bool, verbosity: VerbosityLevel) -> Result<()> {
 
 pub async fn start(
     connection_timeout_s: u64,
+    connectivity: Option<ConnectivityRequirement>,
     fixed_interval: Option<u64>,
     peer_ids: Vec<String>,
     service_names: Vec<String>,
@@ -343,10 +403,24 @@ pub async fn start(
                 );
 
                 node_registry.save()?;
+
+                if let Some(requirement) = connectivity {
+                    let node = &node_registry.nodes[index];
+                    if let Err(err) = wait_for_node_connectivity(
+                        &node.service_name,
+                        node.rpc_socket_addr,
+                        requirement,
+                        verbosity,
+                    )
+                    .await
+                    {
+                        failed_services.push((node.service_name.clone(), err));
+                    }
+                }
             }
             Err(err) => {
                 error!("Failed to start service {}: {err}", node.service_name);
-                failed_services.push((node.service_name.clone(), err.to_string()))
+                failed_services.push((node.service_name.clone(), err))
             }
         }
     }
@@ -426,7 +500,7 @@ pub async fn stop(
             }
             Err(err) => {
                 error!("Failed to stop service {}: {err}", node.service_name);
-                failed_services.push((node.service_name.clone(), err.to_string()))
+                failed_services.push((node.service_name.clone(), err))
             }
         }
     }
@@ -436,6 +510,7 @@ pub async fn stop(
 
 pub async fn upgrade(
     connection_timeout_s: u64,
+    connectivity: Option<ConnectivityRequirement>,
     do_not_start: bool,
     custom_bin_path: Option<PathBuf>,
     force: bool,
@@ -503,6 +578,7 @@ pub async fn upgrade(
     let service_indices = get_services_for_ops(&node_registry, peer_ids, service_names)?;
     trace!("service_indices len: {}", service_indices.len());
     let mut upgrade_summary = Vec::new();
+    let mut connectivity_failures = Vec::new();
 
     for &index in &service_indices {
         let node = &mut node_registry.nodes[index];
@@ -536,6 +612,24 @@ pub async fn upgrade(
         match service_manager.upgrade(options).await {
             Ok(upgrade_result) => {
                 info!("Service: {service_name} has been upgraded, result: {upgrade_result:?}",);
+                // The upgrade result doesn't record whether the service was started again.
+                let started = !do_not_start
+                    && matches!(
+                        upgrade_result,
+                        UpgradeResult::Forced(_, _) | UpgradeResult::Upgraded(_, _)
+                    );
+                if let (true, Some(requirement)) = (started, connectivity) {
+                    if let Err(err) = wait_for_node_connectivity(
+                        &service_name,
+                        service_manager.service.service_data.rpc_socket_addr,
+                        requirement,
+                        verbosity,
+                    )
+                    .await
+                    {
+                        connectivity_failures.push((service_name.clone(), err));
+                    }
+                }
                 if upgrade_result != UpgradeResult::NotRequired {
                     // It doesn't seem useful to apply the interval if there was no upgrade
                     // required for the previous service.
@@ -574,7 +668,7 @@ pub async fn upgrade(
                 again using the 'start' command."));
     }
 
-    Ok(())
+    summarise_any_failed_ops(connectivity_failures, "connect", verbosity)
 }
 
 /// Ensure n nodes are running by stopping nodes or by adding and starting nodes if required.
@@ -663,6 +757,7 @@ pub async fn maintain_n_running_nodes(
                 );
                 start(
                     connection_timeout_s,
+                    None,
                     start_node_interval,
                     vec![],
                     nodes_to_start,
@@ -720,6 +815,7 @@ pub async fn maintain_n_running_nodes(
                     if i == 0 {
                         start(
                             connection_timeout_s,
+                            None,
                             start_node_interval,
                             vec![],
                             added_service,
@@ -732,6 +828,7 @@ pub async fn maintain_n_running_nodes(
                 if !inactive_nodes.is_empty() {
                     start(
                         connection_timeout_s,
+                        None,
                         start_node_interval,
                         vec![],
                         inactive_nodes,
@@ -818,21 +915,53 @@ fn get_services_for_ops(
     Ok(service_indices)
 }
 
-fn summarise_any_failed_ops(
-    failed_services: Vec<(String, String)>,
+/// Wait for a node to be connected to the required number of peers.
+async fn wait_for_node_connectivity(
+    service_name: &str,
+    rpc_socket_addr: SocketAddr,
+    requirement: ConnectivityRequirement,
+    verbosity: VerbosityLevel,
+) -> Result<(), Error> {
+    if verbosity != VerbosityLevel::Minimal {
+        println!(
+            "Waiting for {service_name} to connect to {} peers...",
+            requirement.min_peers
+        );
+    }
+    let rpc_client = RpcClient::from_socket_addr(rpc_socket_addr);
+    let connected_peers = wait_for_connectivity(service_name, &rpc_client, requirement).await?;
+    if verbosity != VerbosityLevel::Minimal {
+        println!(
+            "{} {service_name} is connected to {connected_peers} peers",
+            "✓".green()
+        );
+    }
+    Ok(())
+}
+
+pub(super) fn summarise_any_failed_ops(
+    failed_services: Vec<(String, Error)>,
     verb: &str,
     verbosity: VerbosityLevel,
 ) -> Result<()> {
     if !failed_services.is_empty() {
         if verbosity != VerbosityLevel::Minimal {
             println!("Failed to {verb} {} service(s):", failed_services.len());
-            for failed in failed_services.iter() {
-                println!("{} {}: {}", "✕".red(), failed.0, failed.1);
+            for (service_name, err) in failed_services.iter() {
+                println!(
+                    "{} {service_name}: {err} [{}]",
+                    "✕".red(),
+                    ErrorCode::from(err)
+                );
             }
         }
 
         error!("Failed to {verb} one or more services");
-        return Err(eyre!("Failed to {verb} one or more services"));
+        return Err(Error::ServiceOperationsFailed {
+            verb: verb.to_string(),
+            failures: failed_services,
+        }
+        .into());
     }
     Ok(())
 }
@@ -0,0 +1,359 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use ant_service_management::{NodeRegistry, ServiceStatus};
+use semver::{Prerelease, Version, VersionReq};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Component {
+    AntCtl,
+    AntCtlDaemon,
+    AntNode,
+}
+
+impl fmt::Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Component::AntCtl => write!(f, "antctl"),
+            Component::AntCtlDaemon => write!(f, "antctld"),
+            Component::AntNode => write!(f, "antnode"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The combination may not work as expected, so a warning is given.
+    Warning,
+    /// The combination is known to break; commands refuse to run unless forced.
+    Incompatible,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Constraint {
+    /// When the component matches `version`, the peer must match `peer_version`.
+    Requires {
+        version: &'static str,
+        peer_version: &'static str,
+    },
+    /// The component and the peer must be from the same release series: the same minor version
+    /// while the major version is `0`, otherwise the same major version.
+    SameRelease,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct CompatibilityRule {
+    pub component: Component,
+    pub peer: Component,
+    pub constraint: Constraint,
+    pub severity: Severity,
+    pub reason: &'static str,
+}
+
+const SAFENODE_REASON: &str =
+    "antnode binaries with 0.1xx versions are safenode releases, which use \
+    the `safe` data directories and `sn_` metric names rather than those used by the node manager";
+
+/// The combinations of component versions that are known to be problematic.
+///
+/// Add a rule here when a change to one component breaks another, e.g. a change to the node
+/// registry format or to the arguments supplied to `antnode`.
+pub const COMPATIBILITY_MATRIX: &[CompatibilityRule] = &[
+    CompatibilityRule {
+        component: Component::AntCtl,
+        peer: Component::AntNode,
+        constraint: Constraint::Requires {
+            version: "*",
+            peer_version: "<0.100.0",
+        },
+        severity: Severity::Incompatible,
+        reason: SAFENODE_REASON,
+    },
+    CompatibilityRule {
+        component: Component::AntCtlDaemon,
+        peer: Component::AntNode,
+        constraint: Constraint::Requires {
+            version: "*",
+            peer_version: "<0.100.0",
+        },
+        severity: Severity::Incompatible,
+        reason: SAFENODE_REASON,
+    },
+    CompatibilityRule {
+        component: Component::AntCtl,
+        peer: Component::AntCtlDaemon,
+        constraint: Constraint::SameRelease,
+        severity: Severity::Warning,
+        reason: "antctl and antctld share the node registry and the daemon RPC protocol, which \
+                 are only kept compatible within a release",
+    },
+];
+
+/// A component installed on the machine, or the running binary itself.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstalledComponent {
+    pub component: Component,
+    /// The service name, for components that run as services.
+    pub name: Option<String>,
+    pub version: Version,
+}
+
+impl fmt::Display for InstalledComponent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{name} ({} {})", self.component, self.version),
+            None => write!(f, "{} {}", self.component, self.version),
+        }
+    }
+}
+
+/// A pair of installed components that matches a rule in the matrix.
+#[derive(Clone, Debug)]
+pub struct Incompatibility {
+    pub component: InstalledComponent,
+    pub peer: InstalledComponent,
+    pub severity: Severity,
+    pub reason: &'static str,
+}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} with {}: {}", self.component, self.peer, self.reason)
+    }
+}
+
+impl Constraint {
+    fn is_satisfied(&self, version: &Version, peer_version: &Version) -> bool {
+        match self {
+            Constraint::Requires {
+                version: req,
+                peer_version: peer_req,
+            } => {
+                // The requirements are constants, so they are covered by the tests.
+                let req = VersionReq::parse(req).expect("Invalid version requirement in matrix");
+                let peer_req =
+                    VersionReq::parse(peer_req).expect("Invalid version requirement in matrix");
+                !req.matches(&release(version)) || peer_req.matches(&release(peer_version))
+            }
+            Constraint::SameRelease => {
+                if version.major == 0 {
+                    peer_version.major == 0 && version.minor == peer_version.minor
+                } else {
+                    version.major == peer_version.major
+                }
+            }
+        }
+    }
+}
+
+/// The release a version is a prerelease of.
+///
+/// A `VersionReq` only matches a prerelease if one of its comparators names the same release, so
+/// e.g. `<0.100.0` would not match `0.3.2-rc.1`. The rules are about releases, so prereleases are
+/// matched as the release they precede.
+fn release(version: &Version) -> Version {
+    let mut release = version.clone();
+    release.pre = Prerelease::EMPTY;
+    release
+}
+
+/// Check each pair of installed components against the rules.
+pub fn check(
+    rules: &[CompatibilityRule],
+    installed: &[InstalledComponent],
+) -> Vec<Incompatibility> {
+    let mut incompatibilities = Vec::new();
+    for rule in rules {
+        for component in installed.iter().filter(|c| c.component == rule.component) {
+            for peer in installed.iter().filter(|c| c.component == rule.peer) {
+                if !rule
+                    .constraint
+                    .is_satisfied(&component.version, &peer.version)
+                {
+                    incompatibilities.push(Incompatibility {
+                        component: component.clone(),
+                        peer: peer.clone(),
+                        severity: rule.severity,
+                        reason: rule.reason,
+                    });
+                }
+            }
+        }
+    }
+    incompatibilities
+}
+
+/// The running binary, along with the daemon and the nodes recorded in the registry.
+///
+/// Removed services are not included, and nor are versions that cannot be parsed, such as those
+/// of custom binaries.
+pub fn installed_components(
+    running: Component,
+    running_version: Version,
+    node_registry: &NodeRegistry,
+) -> Vec<InstalledComponent> {
+    let mut installed = vec![InstalledComponent {
+        component: running,
+        name: None,
+        version: running_version,
+    }];
+
+    let services = node_registry
+        .daemon
+        .iter()
+        .filter(|_| running != Component::AntCtlDaemon)
+        .map(|daemon| {
+            (
+                Component::AntCtlDaemon,
+                &daemon.service_name,
+                &daemon.version,
+                &daemon.status,
+            )
+        })
+        .chain(node_registry.nodes.iter().map(|node| {
+            (
+                Component::AntNode,
+                &node.service_name,
+                &node.version,
+                &node.status,
+            )
+        }));
+    for (component, service_name, version, status) in services {
+        if *status == ServiceStatus::Removed {
+            continue;
+        }
+        match Version::parse(version) {
+            Ok(version) => installed.push(InstalledComponent {
+                component,
+                name: Some(service_name.clone()),
+                version,
+            }),
+            Err(err) => {
+                debug!("Not checking the compatibility of {service_name}, with version '{version}': {err}");
+            }
+        }
+    }
+    installed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn installed(component: Component, name: Option<&str>, version: &str) -> InstalledComponent {
+        InstalledComponent {
+            component,
+            name: name.map(String::from),
+            version: Version::parse(version).unwrap(),
+        }
+    }
+
+    #[test]
+    fn matrix_requirements_are_valid() {
+        for rule in COMPATIBILITY_MATRIX {
+            if let Constraint::Requires {
+                version,
+                peer_version,
+            } = rule.constraint
+            {
+                assert!(VersionReq::parse(version).is_ok(), "{version}");
+                assert!(VersionReq::parse(peer_version).is_ok(), "{peer_version}");
+            }
+            assert_ne!(rule.component, rule.peer);
+        }
+    }
+
+    #[test]
+    fn current_releases_are_compatible() {
+        let components = [
+            installed(Component::AntCtl, None, "0.11.5"),
+            installed(Component::AntCtlDaemon, Some("antctld"), "0.11.3"),
+            installed(Component::AntNode, Some("antnode1"), "0.3.1"),
+            installed(Component::AntNode, Some("antnode2"), "0.3.0"),
+        ];
+        assert!(check(COMPATIBILITY_MATRIX, &components).is_empty());
+    }
+
+    #[test]
+    fn release_candidates_are_checked_as_their_release() {
+        let components = [
+            installed(Component::AntCtl, None, "0.11.6-rc.1"),
+            installed(Component::AntCtlDaemon, Some("antctld"), "0.11.4-rc.2"),
+            installed(Component::AntNode, Some("antnode1"), "0.3.2-rc.1"),
+            installed(Component::AntNode, Some("antnode2"), "0.112.7-rc.1"),
+        ];
+        let pairs: Vec<_> = check(COMPATIBILITY_MATRIX, &components)
+            .iter()
+            .map(|i| (i.component.to_string(), i.peer.to_string(), i.severity))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![
+                (
+                    "antctl 0.11.6-rc.1".to_string(),
+                    "antnode2 (antnode 0.112.7-rc.1)".to_string(),
+                    Severity::Incompatible
+                ),
+                (
+                    "antctld (antctld 0.11.4-rc.2)".to_string(),
+                    "antnode2 (antnode 0.112.7-rc.1)".to_string(),
+                    Severity::Incompatible
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn each_incompatible_pair_is_reported() {
+        let components = [
+            installed(Component::AntCtl, None, "0.11.5"),
+            installed(Component::AntCtlDaemon, Some("antctld"), "0.10.0"),
+            installed(Component::AntNode, Some("antnode1"), "0.3.1"),
+            installed(Component::AntNode, Some("antnode2"), "0.112.6"),
+        ];
+        let incompatibilities = check(COMPATIBILITY_MATRIX, &components);
+
+        let pairs: Vec<_> = incompatibilities
+            .iter()
+            .map(|i| (i.component.to_string(), i.peer.to_string(), i.severity))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![
+                (
+                    "antctl 0.11.5".to_string(),
+                    "antnode2 (antnode 0.112.6)".to_string(),
+                    Severity::Incompatible
+                ),
+                (
+                    "antctld (antctld 0.10.0)".to_string(),
+                    "antnode2 (antnode 0.112.6)".to_string(),
+                    Severity::Incompatible
+                ),
+                (
+                    "antctl 0.11.5".to_string(),
+                    "antctld (antctld 0.10.0)".to_string(),
+                    Severity::Warning
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn same_release_compares_minor_versions_before_1_0() {
+        let constraint = Constraint::SameRelease;
+        let version = |v| Version::parse(v).unwrap();
+
+        assert!(constraint.is_satisfied(&version("0.11.5"), &version("0.11.0")));
+        assert!(!constraint.is_satisfied(&version("0.11.5"), &version("0.12.0")));
+        assert!(!constraint.is_satisfied(&version("0.11.5"), &version("1.11.5")));
+        assert!(constraint.is_satisfied(&version("1.2.0"), &version("1.0.3")));
+        assert!(!constraint.is_satisfied(&version("1.2.0"), &version("2.2.0")));
+    }
+}
@@ -106,6 +106,16 @@ pub fn get_node_registry_path() -> Result<PathBuf> {
     Ok(path.join("node_registry.json"))
 }
 
+/// The command history is kept alongside the node registry.
+pub fn get_command_history_path() -> Result<PathBuf> {
+    Ok(get_node_registry_path()?.with_file_name("command_history.json"))
+}
+
+/// The artifact mirror configuration is kept alongside the node registry.
+pub fn get_artifact_mirror_config_path() -> Result<PathBuf> {
+    Ok(get_node_registry_path()?.with_file_name("artifact_mirror.json"))
+}
+
 /// Get the data directory for the service.
 ///
 /// It's a little counter-intuitive, but the owner will be `None` in the case of a user-mode
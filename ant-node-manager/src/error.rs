@@ -12,8 +12,21 @@ pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 #[derive(Debug, Error)]
 pub enum Error {
+    #[error("The {service_name} service was connected to {connected_peers} peer(s) but {min_peers} were required within {timeout_s}s")]
+    ConnectivityTimeout {
+        service_name: String,
+        min_peers: usize,
+        connected_peers: usize,
+        timeout_s: u64,
+    },
+    #[error("Incompatible versions of the node manager and the nodes are installed: {0}")]
+    IncompatibleVersions(String),
+    #[error("The artifact mirror public key is not a hex-encoded ed25519 public key: {0}")]
+    InvalidMirrorPublicKey(String),
     #[error(transparent)]
     Json(#[from] serde_json::Error),
+    #[error("The artifact mirror manifest at {url} cannot be used because {reason}")]
+    MirrorManifestUnusable { url: String, reason: String },
     #[error(transparent)]
     Io(#[from] std::io::Error),
     #[error("The PID of the process was not found after starting it.")]
@@ -28,8 +41,18 @@ pub enum Error {
     ServiceNotRunning(Vec<String>),
     #[error(transparent)]
     ServiceManagementError(#[from] ant_service_management::Error),
+    #[error("Failed to {verb} one or more services")]
+    ServiceOperationsFailed {
+        verb: String,
+        failures: Vec<(String, Error)>,
+    },
     #[error("The service status is not as expected. Expected: {expected:?}")]
     ServiceStatusMismatch {
         expected: ant_service_management::ServiceStatus,
     },
+    #[error("The changes to the {service_name} service cannot be undone because {reason}")]
+    UndoNotPossible {
+        service_name: String,
+        reason: String,
+    },
 }
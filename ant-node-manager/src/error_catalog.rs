@@ -0,0 +1,636 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! A catalog of the errors `antctl` can fail with.
+//!
+//! Each error code has an entry describing the likely causes, the fixes worth trying and the
+//! files worth inspecting. The same entry provides the process exit code, so the `--explain`
+//! output and the exit status of `antctl` can never disagree.
+
+use crate::error::Error;
+use ant_service_management::Error as ServiceError;
+use colored::Colorize;
+use std::fmt;
+
+/// The exit code used when the error could not be matched to an entry in the catalog.
+pub const UNKNOWN_EXIT_CODE: i32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    ConnectivityTimeout,
+    DaemonEndpointNotSet,
+    IncompatibleVersions,
+    InvalidMirrorPublicKey,
+    Io,
+    Json,
+    MirrorManifestUnusable,
+    NodeNotFound,
+    PidNotFoundAfterStarting,
+    PidNotSet,
+    RpcConnection,
+    Semver,
+    ServiceAlreadyRunning,
+    ServiceManagement,
+    ServiceNotRunning,
+    ServiceOperationsFailed,
+    ServiceProcessNotFound,
+    ServiceRemovedManually,
+    ServiceStatusMismatch,
+    ServiceUserAccountCreationFailed,
+    UndoNotPossible,
+    Unknown,
+    UserDataDirectoryNotObtainable,
+}
+
+/// A location that is useful to inspect when diagnosing an error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelevantPath {
+    NodeRegistry,
+    NodeLogs,
+    NodeData,
+    ServiceDefinitions,
+}
+
+#[derive(Debug)]
+pub struct CatalogEntry {
+    pub code: ErrorCode,
+    pub exit_code: i32,
+    pub summary: &'static str,
+    pub causes: &'static [&'static str],
+    pub fixes: &'static [&'static str],
+    pub paths: &'static [RelevantPath],
+}
+
+impl ErrorCode {
+    pub const ALL: &'static [ErrorCode] = &[
+        ErrorCode::ConnectivityTimeout,
+        ErrorCode::DaemonEndpointNotSet,
+        ErrorCode::IncompatibleVersions,
+        ErrorCode::InvalidMirrorPublicKey,
+        ErrorCode::Io,
+        ErrorCode::Json,
+        ErrorCode::MirrorManifestUnusable,
+        ErrorCode::NodeNotFound,
+        ErrorCode::PidNotFoundAfterStarting,
+        ErrorCode::PidNotSet,
+        ErrorCode::RpcConnection,
+        ErrorCode::Semver,
+        ErrorCode::ServiceAlreadyRunning,
+        ErrorCode::ServiceManagement,
+        ErrorCode::ServiceNotRunning,
+        ErrorCode::ServiceOperationsFailed,
+        ErrorCode::ServiceProcessNotFound,
+        ErrorCode::ServiceRemovedManually,
+        ErrorCode::ServiceStatusMismatch,
+        ErrorCode::ServiceUserAccountCreationFailed,
+        ErrorCode::UndoNotPossible,
+        ErrorCode::Unknown,
+        ErrorCode::UserDataDirectoryNotObtainable,
+    ];
+
+    /// Find an error code by its name, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|code| code.to_string().eq_ignore_ascii_case(name.trim()))
+            .copied()
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        self.entry().exit_code
+    }
+
+    pub fn entry(&self) -> &'static CatalogEntry {
+        match self {
+            ErrorCode::ConnectivityTimeout => &CatalogEntry {
+                code: ErrorCode::ConnectivityTimeout,
+                exit_code: 24,
+                summary: "The node is running, but it did not connect to enough peers in time.",
+                causes: &[
+                    "The node cannot reach the network, e.g. because it is behind a NAT or a firewall.",
+                    "The bootstrap peers are unreachable, or the network is still forming.",
+                    "The minimum number of peers is higher than the network can provide.",
+                ],
+                fixes: &[
+                    "Check the node's log file for connection errors.",
+                    "Increase the timeout, or lower the minimum number of peers, given to `--wait-for-connectivity`.",
+                    "Try another connection mode, e.g. `--home-network` or `--upnp` when the node was added.",
+                ],
+                paths: &[RelevantPath::NodeLogs],
+            },
+            ErrorCode::DaemonEndpointNotSet => &CatalogEntry {
+                code: ErrorCode::DaemonEndpointNotSet,
+                exit_code: 22,
+                summary: "The endpoint for the daemon has not been set.",
+                causes: &["The daemon service was added without an address or port."],
+                fixes: &["Remove the daemon and add it again with `antctl daemon add`."],
+                paths: &[RelevantPath::NodeRegistry],
+            },
+            ErrorCode::IncompatibleVersions => &CatalogEntry {
+                code: ErrorCode::IncompatibleVersions,
+                exit_code: 28,
+                summary: "The versions of antctl, antctld or antnode are known not to work together.",
+                causes: &[
+                    "Nodes were added with an old antnode binary, e.g. one released as safenode.",
+                    "antctl was upgraded without upgrading the daemon or the nodes.",
+                ],
+                fixes: &[
+                    "Upgrade the nodes with `antctl upgrade`, and replace an old daemon by removing it and adding it again with `antctl daemon add`.",
+                    "Use `antctl --ignore-compatibility` to run the command anyway.",
+                ],
+                paths: &[RelevantPath::NodeRegistry],
+            },
+            ErrorCode::InvalidMirrorPublicKey => &CatalogEntry {
+                code: ErrorCode::InvalidMirrorPublicKey,
+                exit_code: 26,
+                summary: "The public key configured for the artifact mirror is not valid.",
+                causes: &["The key is not the hex encoding of a 32-byte ed25519 public key."],
+                fixes: &["Configure the mirror again with `antctl mirror set`, supplying the hex-encoded public key the manifest is signed with."],
+                paths: &[],
+            },
+            ErrorCode::Io => &CatalogEntry {
+                code: ErrorCode::Io,
+                exit_code: 30,
+                summary: "A file or directory could not be read or written.",
+                causes: &[
+                    "The command was run without the privileges needed for system-wide services.",
+                    "The disk is full, or a path was removed while the node manager was using it.",
+                ],
+                fixes: &[
+                    "Run the command with `sudo`, or as administrator on Windows, if the services are system-wide.",
+                    "Check that there is free space on the drive holding the node data.",
+                ],
+                paths: &[RelevantPath::NodeRegistry, RelevantPath::NodeData],
+            },
+            ErrorCode::Json => &CatalogEntry {
+                code: ErrorCode::Json,
+                exit_code: 31,
+                summary: "A JSON document could not be parsed.",
+                causes: &[
+                    "The node registry was edited by hand or was only partially written.",
+                    "The registry was written by an incompatible version of the node manager.",
+                ],
+                fixes: &[
+                    "Restore the node registry from a backup, or fix the invalid JSON it contains.",
+                    "Upgrade the node manager to the latest version.",
+                ],
+                paths: &[RelevantPath::NodeRegistry],
+            },
+            ErrorCode::MirrorManifestUnusable => &CatalogEntry {
+                code: ErrorCode::MirrorManifestUnusable,
+                exit_code: 27,
+                summary: "The manifest of the artifact mirror could not be fetched or verified.",
+                causes: &[
+                    "The mirror is unreachable, or it does not serve `manifest.json` and `manifest.json.sig` at its base URL.",
+                    "The manifest was changed after it was signed, or it was signed with a different key.",
+                    "The manifest has expired, or it is older than a manifest that was already accepted from the mirror.",
+                ],
+                fixes: &[
+                    "Check the mirror with `antctl mirror show`, and that the manifest can be downloaded from this machine.",
+                    "Sign the manifest again with the key of the mirror, or remove the mirror with `antctl mirror remove` to use the public releases.",
+                ],
+                paths: &[],
+            },
+            ErrorCode::NodeNotFound => &CatalogEntry {
+                code: ErrorCode::NodeNotFound,
+                exit_code: 16,
+                summary: "The node registry does not contain the requested service.",
+                causes: &[
+                    "The service name or peer ID was mistyped.",
+                    "The service was removed, or it belongs to another registry (user-mode versus system-wide).",
+                ],
+                fixes: &[
+                    "Run `antctl status` to list the services the registry knows about.",
+                    "Use `sudo` if the services were added as system-wide services, or omit it if they are user-mode services.",
+                ],
+                paths: &[RelevantPath::NodeRegistry],
+            },
+            ErrorCode::PidNotFoundAfterStarting => &CatalogEntry {
+                code: ErrorCode::PidNotFoundAfterStarting,
+                exit_code: 11,
+                summary: "The service was started, but its antnode process could not be found afterwards.",
+                causes: &[
+                    "antnode exited straight after starting, e.g. because its port is already in use.",
+                    "The antnode binary is missing, is not executable or was built for another platform.",
+                    "The rewards address or another argument in the service definition is invalid.",
+                ],
+                fixes: &[
+                    "Check the end of the node's log file for the reason it exited.",
+                    "Inspect the service definition to confirm the binary path and arguments.",
+                    "Run `antctl status --details` to see the port and paths for each service.",
+                ],
+                paths: &[
+                    RelevantPath::NodeLogs,
+                    RelevantPath::ServiceDefinitions,
+                    RelevantPath::NodeRegistry,
+                ],
+            },
+            ErrorCode::PidNotSet => &CatalogEntry {
+                code: ErrorCode::PidNotSet,
+                exit_code: 12,
+                summary: "The service is recorded as running but the registry has no PID for it.",
+                causes: &["The node registry is out of date with the state of the service."],
+                fixes: &["Run `antctl status` to refresh the registry, then retry the command."],
+                paths: &[RelevantPath::NodeRegistry],
+            },
+            ErrorCode::RpcConnection => &CatalogEntry {
+                code: ErrorCode::RpcConnection,
+                exit_code: 17,
+                summary: "The RPC endpoint of a node or of the daemon could not be reached.",
+                causes: &[
+                    "The node is still starting up, or it has stopped.",
+                    "The RPC port is blocked or used by another process.",
+                ],
+                fixes: &[
+                    "Wait a little and retry, or increase `--connection-timeout`.",
+                    "Check the node's log file to confirm it is running and which RPC address it bound to.",
+                ],
+                paths: &[RelevantPath::NodeLogs, RelevantPath::NodeRegistry],
+            },
+            ErrorCode::Semver => &CatalogEntry {
+                code: ErrorCode::Semver,
+                exit_code: 32,
+                summary: "A version number could not be parsed.",
+                causes: &[
+                    "The `--version` argument is not a valid semantic version.",
+                    "The antnode binary reported an unexpected version string.",
+                ],
+                fixes: &["Provide the version in the form `MAJOR.MINOR.PATCH`, e.g. `0.112.6`."],
+                paths: &[],
+            },
+            ErrorCode::ServiceAlreadyRunning => &CatalogEntry {
+                code: ErrorCode::ServiceAlreadyRunning,
+                exit_code: 13,
+                summary: "The operation requires the service(s) to be stopped, but they are running.",
+                causes: &["The services were started before running this command."],
+                fixes: &["Stop the services with `antctl stop`, then retry the command."],
+                paths: &[],
+            },
+            ErrorCode::ServiceManagement => &CatalogEntry {
+                code: ErrorCode::ServiceManagement,
+                exit_code: 23,
+                summary: "The service manager of the operating system reported an error.",
+                causes: &[
+                    "The command was run without the privileges needed for system-wide services.",
+                    "The service definition is invalid or was changed outside the node manager.",
+                ],
+                fixes: &[
+                    "Run the command with `sudo`, or as administrator on Windows.",
+                    "Inspect the service definition and the output of the platform service manager.",
+                ],
+                paths: &[RelevantPath::ServiceDefinitions, RelevantPath::NodeRegistry],
+            },
+            ErrorCode::ServiceNotRunning => &CatalogEntry {
+                code: ErrorCode::ServiceNotRunning,
+                exit_code: 14,
+                summary: "The operation requires the service(s) to be running, but they are not.",
+                causes: &[
+                    "The services were never started, or they have crashed.",
+                    "`antctl status --fail` was used and at least one service is not running.",
+                ],
+                fixes: &[
+                    "Start the services with `antctl start`.",
+                    "Check the log files of the services that stopped unexpectedly.",
+                ],
+                paths: &[RelevantPath::NodeLogs],
+            },
+            ErrorCode::ServiceOperationsFailed => &CatalogEntry {
+                code: ErrorCode::ServiceOperationsFailed,
+                exit_code: 10,
+                summary: "The operation failed for more than one service, for different reasons.",
+                causes: &["See the error reported for each service above."],
+                fixes: &["Run `antctl --explain=<CODE>` for each of the error codes listed."],
+                paths: &[RelevantPath::NodeLogs, RelevantPath::NodeRegistry],
+            },
+            ErrorCode::ServiceProcessNotFound => &CatalogEntry {
+                code: ErrorCode::ServiceProcessNotFound,
+                exit_code: 18,
+                summary: "No process is running from the service's antnode binary.",
+                causes: &[
+                    "The node crashed or was killed.",
+                    "The binary was replaced or moved since the service was started.",
+                ],
+                fixes: &[
+                    "Check the node's log file for the reason it stopped.",
+                    "Start the service again with `antctl start`.",
+                ],
+                paths: &[RelevantPath::NodeLogs, RelevantPath::NodeRegistry],
+            },
+            ErrorCode::ServiceRemovedManually => &CatalogEntry {
+                code: ErrorCode::ServiceRemovedManually,
+                exit_code: 19,
+                summary: "The service definition no longer exists, but the registry still has the service.",
+                causes: &["The service was removed with the platform service manager rather than `antctl`."],
+                fixes: &["Run `antctl remove` for the service to remove it from the registry."],
+                paths: &[RelevantPath::ServiceDefinitions, RelevantPath::NodeRegistry],
+            },
+            ErrorCode::ServiceStatusMismatch => &CatalogEntry {
+                code: ErrorCode::ServiceStatusMismatch,
+                exit_code: 15,
+                summary: "The service is not in the status the operation expected.",
+                causes: &["The service changed state while the command was running."],
+                fixes: &["Run `antctl status` to refresh the registry, then retry the command."],
+                paths: &[RelevantPath::NodeRegistry],
+            },
+            ErrorCode::ServiceUserAccountCreationFailed => &CatalogEntry {
+                code: ErrorCode::ServiceUserAccountCreationFailed,
+                exit_code: 20,
+                summary: "The user account for running system-wide services could not be created.",
+                causes: &[
+                    "The command was run without `sudo`.",
+                    "The `useradd`/`adduser` tools are not available on this system.",
+                ],
+                fixes: &[
+                    "Run the command with `sudo`.",
+                    "Create the user yourself and pass it with `--user`.",
+                ],
+                paths: &[],
+            },
+            ErrorCode::UndoNotPossible => &CatalogEntry {
+                code: ErrorCode::UndoNotPossible,
+                exit_code: 25,
+                summary: "The changes recorded in the command history could not be reverted.",
+                causes: &[
+                    "The data directory or the antnode binary of a removed service was deleted.",
+                    "The service was changed by another command after the one being undone.",
+                ],
+                fixes: &[
+                    "Run `antctl history` to check the changes the command made.",
+                    "Add the service again with `antctl add`.",
+                ],
+                paths: &[RelevantPath::NodeRegistry, RelevantPath::NodeData],
+            },
+            ErrorCode::Unknown => &CatalogEntry {
+                code: ErrorCode::Unknown,
+                exit_code: UNKNOWN_EXIT_CODE,
+                summary: "The error does not have an entry in the catalog.",
+                causes: &[],
+                fixes: &["Run the command again with `--debug` to get more details."],
+                paths: &[RelevantPath::NodeRegistry],
+            },
+            ErrorCode::UserDataDirectoryNotObtainable => &CatalogEntry {
+                code: ErrorCode::UserDataDirectoryNotObtainable,
+                exit_code: 21,
+                summary: "The data directory of the current user could not be determined.",
+                causes: &["The `HOME` environment variable is not set, or it points to a missing directory."],
+                fixes: &[
+                    "Set `HOME` to the home directory of the user running the command.",
+                    "Provide explicit paths with `--data-dir-path` and `--log-dir-path`.",
+                ],
+                paths: &[],
+            },
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl From<&Error> for ErrorCode {
+    fn from(err: &Error) -> Self {
+        match err {
+            Error::ConnectivityTimeout { .. } => ErrorCode::ConnectivityTimeout,
+            Error::IncompatibleVersions(_) => ErrorCode::IncompatibleVersions,
+            Error::InvalidMirrorPublicKey(_) => ErrorCode::InvalidMirrorPublicKey,
+            Error::Io(_) => ErrorCode::Io,
+            Error::Json(_) => ErrorCode::Json,
+            Error::MirrorManifestUnusable { .. } => ErrorCode::MirrorManifestUnusable,
+            Error::PidNotFoundAfterStarting => ErrorCode::PidNotFoundAfterStarting,
+            Error::PidNotSet => ErrorCode::PidNotSet,
+            Error::SemverError(_) => ErrorCode::Semver,
+            Error::ServiceAlreadyRunning(_) => ErrorCode::ServiceAlreadyRunning,
+            Error::ServiceNotRunning(_) => ErrorCode::ServiceNotRunning,
+            Error::ServiceManagementError(err) => err.into(),
+            Error::ServiceStatusMismatch { .. } => ErrorCode::ServiceStatusMismatch,
+            Error::UndoNotPossible { .. } => ErrorCode::UndoNotPossible,
+            Error::ServiceOperationsFailed { failures, .. } => {
+                let mut codes = failures.iter().map(|(_, err)| ErrorCode::from(err));
+                match codes.next() {
+                    Some(first) if codes.all(|code| code == first) => first,
+                    _ => ErrorCode::ServiceOperationsFailed,
+                }
+            }
+        }
+    }
+}
+
+impl From<&ServiceError> for ErrorCode {
+    fn from(err: &ServiceError) -> Self {
+        match err {
+            ServiceError::DaemonEndpointNotSet => ErrorCode::DaemonEndpointNotSet,
+            ServiceError::Io(_) => ErrorCode::Io,
+            ServiceError::Json(_) => ErrorCode::Json,
+            ServiceError::NodeNotFound(_) => ErrorCode::NodeNotFound,
+            ServiceError::RpcConnectionError(_) => ErrorCode::RpcConnection,
+            ServiceError::ServiceProcessNotFound(_) => ErrorCode::ServiceProcessNotFound,
+            ServiceError::ServiceRemovedManually(_) => ErrorCode::ServiceRemovedManually,
+            ServiceError::ServiceUserAccountCreationFailed => {
+                ErrorCode::ServiceUserAccountCreationFailed
+            }
+            ServiceError::UserDataDirectoryNotObtainable => {
+                ErrorCode::UserDataDirectoryNotObtainable
+            }
+            _ => ErrorCode::ServiceManagement,
+        }
+    }
+}
+
+/// Determine the error code for a report returned by one of the commands.
+///
+/// The first error in the chain that belongs to the catalog decides the code.
+pub fn error_code_for_report(report: &color_eyre::Report) -> ErrorCode {
+    report
+        .chain()
+        .find_map(|err| {
+            if let Some(err) = err.downcast_ref::<Error>() {
+                Some(ErrorCode::from(err))
+            } else {
+                err.downcast_ref::<ServiceError>().map(ErrorCode::from)
+            }
+        })
+        .unwrap_or(ErrorCode::Unknown)
+}
+
+impl RelevantPath {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RelevantPath::NodeRegistry => "Node registry",
+            RelevantPath::NodeLogs => "Node logs",
+            RelevantPath::NodeData => "Node data",
+            RelevantPath::ServiceDefinitions => "Service definitions",
+        }
+    }
+
+    /// The default locations, for both system-wide and user-mode services where they differ.
+    ///
+    /// Unlike the functions in the `config` module, this never creates any directories.
+    #[cfg(unix)]
+    pub fn locations(&self) -> Vec<String> {
+        let user_dir = dirs_next::data_dir()
+            .map(|dir| dir.join("autonomi").join("node").display().to_string())
+            .unwrap_or_else(|| "<user data dir>/autonomi/node".to_string());
+        match self {
+            RelevantPath::NodeRegistry => vec![
+                "/var/antctl/node_registry.json (system-wide services)".to_string(),
+                format!("{user_dir}/node_registry.json (user-mode services)"),
+            ],
+            RelevantPath::NodeLogs => vec![
+                "/var/log/antnode/<service-name> (system-wide services)".to_string(),
+                format!("{user_dir}/<service-name>/logs (user-mode services)"),
+            ],
+            RelevantPath::NodeData => vec![
+                "/var/antctl/services/<service-name> (system-wide services)".to_string(),
+                format!("{user_dir}/<service-name> (user-mode services)"),
+            ],
+            RelevantPath::ServiceDefinitions => service_definition_locations(),
+        }
+    }
+
+    #[cfg(windows)]
+    pub fn locations(&self) -> Vec<String> {
+        let path = match self {
+            RelevantPath::NodeRegistry => "C:\\ProgramData\\antctl\\node_registry.json",
+            RelevantPath::NodeLogs => "C:\\ProgramData\\antnode\\logs\\<service-name>",
+            RelevantPath::NodeData => "C:\\ProgramData\\antctl\\data\\<service-name>",
+            RelevantPath::ServiceDefinitions => "C:\\ProgramData\\antctl\\<service-name>.xml",
+        };
+        vec![path.to_string()]
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn service_definition_locations() -> Vec<String> {
+    vec![
+        "/etc/systemd/system/<service-name>.service (system-wide services)".to_string(),
+        "~/.config/systemd/user/<service-name>.service (user-mode services)".to_string(),
+    ]
+}
+
+#[cfg(target_os = "macos")]
+fn service_definition_locations() -> Vec<String> {
+    vec![
+        "/Library/LaunchDaemons/<service-name>.plist (system-wide services)".to_string(),
+        "~/Library/LaunchAgents/<service-name>.plist (user-mode services)".to_string(),
+    ]
+}
+
+#[cfg(all(unix, not(any(target_os = "linux", target_os = "macos"))))]
+fn service_definition_locations() -> Vec<String> {
+    vec!["/etc/init.d/<service-name>".to_string()]
+}
+
+impl fmt::Display for CatalogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} {} (exit code {})",
+            "Error code:".bold(),
+            self.code,
+            self.exit_code
+        )?;
+        writeln!(f, "{}", self.summary)?;
+
+        if !self.causes.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "{}", "Common causes:".bold())?;
+            for cause in self.causes {
+                writeln!(f, "  - {cause}")?;
+            }
+        }
+        if !self.fixes.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "{}", "Likely fixes:".bold())?;
+            for fix in self.fixes {
+                writeln!(f, "  - {fix}")?;
+            }
+        }
+        if !self.paths.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "{}", "Relevant paths:".bold())?;
+            for path in self.paths {
+                writeln!(f, "  {}:", path.name())?;
+                for location in path.locations() {
+                    writeln!(f, "    {location}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn every_code_has_a_matching_entry_with_a_unique_exit_code() {
+        let mut exit_codes = HashSet::new();
+        for code in ErrorCode::ALL {
+            let entry = code.entry();
+            assert_eq!(entry.code, *code);
+            assert!(entry.exit_code != 0 && entry.exit_code != 2);
+            assert!(
+                exit_codes.insert(entry.exit_code),
+                "{code} reuses an exit code"
+            );
+            assert_eq!(ErrorCode::from_name(&code.to_string()), Some(*code));
+        }
+        assert_eq!(
+            ErrorCode::from_name("pidnotfoundafterstarting"),
+            Some(ErrorCode::PidNotFoundAfterStarting)
+        );
+        assert_eq!(ErrorCode::from_name("NotAnErrorCode"), None);
+    }
+
+    #[test]
+    fn error_code_is_found_in_the_report_chain() {
+        let report =
+            color_eyre::Report::new(Error::PidNotFoundAfterStarting).wrap_err("Failed to start");
+        assert_eq!(
+            error_code_for_report(&report),
+            ErrorCode::PidNotFoundAfterStarting
+        );
+
+        let report = color_eyre::Report::new(Error::ServiceManagementError(
+            ServiceError::NodeNotFound("antnode1".to_string()),
+        ));
+        assert_eq!(error_code_for_report(&report), ErrorCode::NodeNotFound);
+
+        let report = color_eyre::eyre::eyre!("Something else went wrong");
+        assert_eq!(error_code_for_report(&report), ErrorCode::Unknown);
+        assert_eq!(
+            error_code_for_report(&report).exit_code(),
+            UNKNOWN_EXIT_CODE
+        );
+    }
+
+    #[test]
+    fn failures_of_many_services_use_the_shared_code() {
+        let same = Error::ServiceOperationsFailed {
+            verb: "start".to_string(),
+            failures: vec![
+                ("antnode1".to_string(), Error::PidNotFoundAfterStarting),
+                ("antnode2".to_string(), Error::PidNotFoundAfterStarting),
+            ],
+        };
+        assert_eq!(ErrorCode::from(&same), ErrorCode::PidNotFoundAfterStarting);
+
+        let mixed = Error::ServiceOperationsFailed {
+            verb: "start".to_string(),
+            failures: vec![
+                ("antnode1".to_string(), Error::PidNotFoundAfterStarting),
+                ("antnode2".to_string(), Error::PidNotSet),
+            ],
+        };
+        assert_eq!(ErrorCode::from(&mixed), ErrorCode::ServiceOperationsFailed);
+    }
+}
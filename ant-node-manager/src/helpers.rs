@@ -181,6 +181,11 @@ pub async fn download_and_extract_release(
                         .inspect_err(|err| error!("Error while extracting archive {err:?}"))?;
                     break binary_download_path;
                 }
+                // Only the artifact mirror rejects archives; downloading it again won't help
+                Err(ant_releases::Error::ReleaseBinaryNotFound(name)) => {
+                    error!("{name} from {url} was not approved in the artifact mirror manifest");
+                    bail!("{url} was not approved in the manifest of the artifact mirror");
+                }
                 Err(err) => {
                     error!("Error downloading release: {err:?}");
                     if verbosity != VerbosityLevel::Minimal {
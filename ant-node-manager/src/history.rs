@@ -0,0 +1,529 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! A log of the commands that changed the node registry, along with the changes they made, so an
+//! operator can see what was done to the fleet and undo the reversible operations.
+
+use crate::error::{Error, Result};
+use ant_service_management::{
+    control::ServiceControl, rpc::RpcClient, NodeRegistry, NodeService, NodeServiceData,
+    ServiceStateActions, ServiceStatus, UpgradeOptions,
+};
+use chrono::{DateTime, Utc};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    io::{Read, Write},
+    path::{Path, PathBuf},
+};
+
+/// The number of entries kept in the history. The oldest entries are dropped first.
+pub const MAX_HISTORY_ENTRIES: usize = 500;
+
+/// The commands that are recorded in the history.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommandKind {
+    Add,
+    Remove { keep_directories: bool },
+    Reset,
+    Start,
+    Stop,
+    Undo,
+    Upgrade,
+}
+
+impl CommandKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CommandKind::Add => "add",
+            CommandKind::Remove { .. } => "remove",
+            CommandKind::Reset => "reset",
+            CommandKind::Start => "start",
+            CommandKind::Stop => "stop",
+            CommandKind::Undo => "undo",
+            CommandKind::Upgrade => "upgrade",
+        }
+    }
+}
+
+/// How the changes of a command can be reverted.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum UndoAction {
+    /// Stop and remove the services that were added, along with their directories.
+    RemoveAddedServices { service_names: Vec<String> },
+    /// Install the services that were removed with their directories kept, using their previous
+    /// registry entries.
+    ReinstallRemovedServices { service_names: Vec<String> },
+    /// Stop the services that were started.
+    StopStartedServices { service_names: Vec<String> },
+    /// Start the services that were stopped.
+    StartStoppedServices { service_names: Vec<String> },
+}
+
+impl UndoAction {
+    pub fn service_names(&self) -> &[String] {
+        match self {
+            UndoAction::RemoveAddedServices { service_names }
+            | UndoAction::ReinstallRemovedServices { service_names }
+            | UndoAction::StopStartedServices { service_names }
+            | UndoAction::StartStoppedServices { service_names } => service_names,
+        }
+    }
+
+    pub fn description(&self) -> String {
+        let verb = match self {
+            UndoAction::RemoveAddedServices { .. } => "remove",
+            UndoAction::ReinstallRemovedServices { .. } => "re-add",
+            UndoAction::StopStartedServices { .. } => "stop",
+            UndoAction::StartStoppedServices { .. } => "start",
+        };
+        format!("{verb} {}", self.service_names().join(", "))
+    }
+}
+
+/// The registry entry of a service before and after a command. A missing entry means the service
+/// was not in the registry.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ServiceChange {
+    pub service_name: String,
+    pub before: Option<NodeServiceData>,
+    pub after: Option<NodeServiceData>,
+}
+
+impl ServiceChange {
+    /// The fields of the registry entry that differ, with their values before and after.
+    pub fn changed_fields(&self) -> Vec<(String, Value, Value)> {
+        let before = to_fields(self.before.as_ref());
+        let after = to_fields(self.after.as_ref());
+        let mut names: Vec<&String> = before.keys().chain(after.keys()).collect();
+        names.sort();
+        names.dedup();
+        names
+            .into_iter()
+            .filter_map(|name| {
+                let old = before.get(name).cloned().unwrap_or(Value::Null);
+                let new = after.get(name).cloned().unwrap_or(Value::Null);
+                (old != new).then(|| (name.clone(), old, new))
+            })
+            .collect()
+    }
+}
+
+fn to_fields(service: Option<&NodeServiceData>) -> serde_json::Map<String, Value> {
+    match service.map(serde_json::to_value) {
+        Some(Ok(Value::Object(fields))) => fields,
+        _ => serde_json::Map::new(),
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub id: u64,
+    pub timestamp: DateTime<Utc>,
+    pub command: String,
+    /// The arguments the command was run with, excluding the program name.
+    pub args: Vec<String>,
+    pub succeeded: bool,
+    pub changes: Vec<ServiceChange>,
+    /// How the changes can be reverted, if they can be.
+    pub undo: Option<UndoAction>,
+    /// The ID of the `undo` entry that reverted this one.
+    pub undone_by: Option<u64>,
+}
+
+impl HistoryEntry {
+    pub fn is_undoable(&self) -> bool {
+        self.undo.is_some() && self.undone_by.is_none()
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CommandHistory {
+    pub entries: Vec<HistoryEntry>,
+    #[serde(skip)]
+    save_path: PathBuf,
+}
+
+impl CommandHistory {
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut history = if path.exists() {
+            debug!("Loading command history from {path:?}");
+            let mut contents = String::new();
+            std::fs::File::open(path)?.read_to_string(&mut contents)?;
+            if contents.is_empty() {
+                CommandHistory::default()
+            } else {
+                serde_json::from_str(&contents)?
+            }
+        } else {
+            debug!("Loading empty command history as {path:?} does not exist");
+            CommandHistory::default()
+        };
+        history.save_path = path.to_path_buf();
+        Ok(history)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        debug!("Saving command history to {:?}", self.save_path);
+        if let Some(parent) = self.save_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string(self)?;
+        let mut file = std::fs::File::create(&self.save_path)?;
+        file.write_all(json.as_bytes())?;
+        Ok(())
+    }
+
+    pub fn get(&self, id: u64) -> Option<&HistoryEntry> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+
+    pub fn get_mut(&mut self, id: u64) -> Option<&mut HistoryEntry> {
+        self.entries.iter_mut().find(|entry| entry.id == id)
+    }
+
+    /// The ID the next entry will get.
+    pub fn next_id(&self) -> u64 {
+        self.entries.last().map_or(1, |entry| entry.id + 1)
+    }
+
+    /// The most recent entry whose changes can still be undone.
+    pub fn last_undoable(&self) -> Option<&HistoryEntry> {
+        self.entries.iter().rev().find(|entry| entry.is_undoable())
+    }
+
+    /// Append an entry for a command, given the registry entries of the services before and after
+    /// it ran. Returns the ID of the entry.
+    pub fn push(
+        &mut self,
+        kind: CommandKind,
+        args: Vec<String>,
+        succeeded: bool,
+        before: &[NodeServiceData],
+        after: &[NodeServiceData],
+    ) -> u64 {
+        let id = self.next_id();
+        let changes = diff_services(before, after);
+        let undo = undo_action(kind, &changes);
+        self.entries.push(HistoryEntry {
+            id,
+            timestamp: Utc::now(),
+            command: kind.name().to_string(),
+            args,
+            succeeded,
+            changes,
+            undo,
+            undone_by: None,
+        });
+        if self.entries.len() > MAX_HISTORY_ENTRIES {
+            let excess = self.entries.len() - MAX_HISTORY_ENTRIES;
+            let _ = self.entries.drain(..excess);
+        }
+        id
+    }
+}
+
+/// The services whose registry entries differ between the two snapshots.
+pub fn diff_services(before: &[NodeServiceData], after: &[NodeServiceData]) -> Vec<ServiceChange> {
+    let mut names: Vec<&String> = before
+        .iter()
+        .chain(after.iter())
+        .map(|service| &service.service_name)
+        .collect();
+    let mut seen = std::collections::HashSet::new();
+    names.retain(|name| seen.insert(*name));
+
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let change = ServiceChange {
+                service_name: name.clone(),
+                before: before.iter().find(|s| &s.service_name == name).cloned(),
+                after: after.iter().find(|s| &s.service_name == name).cloned(),
+            };
+            (!change.changed_fields().is_empty()).then_some(change)
+        })
+        .collect()
+}
+
+fn undo_action(kind: CommandKind, changes: &[ServiceChange]) -> Option<UndoAction> {
+    let names_where = |predicate: &dyn Fn(Option<ServiceStatus>, Option<ServiceStatus>) -> bool| {
+        changes
+            .iter()
+            .filter(|change| {
+                predicate(
+                    change.before.as_ref().map(|s| s.status.clone()),
+                    change.after.as_ref().map(|s| s.status.clone()),
+                )
+            })
+            .map(|change| change.service_name.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let service_names = match kind {
+        CommandKind::Add => names_where(&|before, after| before.is_none() && after.is_some()),
+        CommandKind::Remove {
+            keep_directories: true,
+        } => names_where(&|before, after| {
+            matches!(before, Some(status) if status != ServiceStatus::Removed)
+                && after == Some(ServiceStatus::Removed)
+        }),
+        CommandKind::Start => names_where(&|before, after| {
+            before != Some(ServiceStatus::Running) && after == Some(ServiceStatus::Running)
+        }),
+        CommandKind::Stop => names_where(&|before, after| {
+            before == Some(ServiceStatus::Running) && after == Some(ServiceStatus::Stopped)
+        }),
+        // Removing directories, resetting and upgrading can't be reverted from the registry.
+        CommandKind::Remove {
+            keep_directories: false,
+        }
+        | CommandKind::Reset
+        | CommandKind::Undo
+        | CommandKind::Upgrade => return None,
+    };
+    if service_names.is_empty() {
+        return None;
+    }
+
+    Some(match kind {
+        CommandKind::Add => UndoAction::RemoveAddedServices { service_names },
+        CommandKind::Remove { .. } => UndoAction::ReinstallRemovedServices { service_names },
+        CommandKind::Start => UndoAction::StopStartedServices { service_names },
+        _ => UndoAction::StartStoppedServices { service_names },
+    })
+}
+
+/// Records a command in the history by snapshotting the node registry before and after it runs.
+pub struct HistoryRecorder {
+    kind: CommandKind,
+    args: Vec<String>,
+    registry_path: PathBuf,
+    history_path: PathBuf,
+    before: Vec<NodeServiceData>,
+}
+
+impl HistoryRecorder {
+    pub fn start(
+        kind: CommandKind,
+        args: Vec<String>,
+        registry_path: PathBuf,
+        history_path: PathBuf,
+    ) -> Result<Self> {
+        let before = NodeRegistry::load(&registry_path)?.nodes;
+        Ok(Self {
+            kind,
+            args,
+            registry_path,
+            history_path,
+            before,
+        })
+    }
+
+    /// Append the entry for the command, returning its ID.
+    pub fn finish(self, succeeded: bool) -> Result<u64> {
+        let after = NodeRegistry::load(&self.registry_path)?.nodes;
+        // Load the history only now, since the command itself may have updated it.
+        let mut history = CommandHistory::load(&self.history_path)?;
+        let id = history.push(self.kind, self.args, succeeded, &self.before, &after);
+        history.save()?;
+        Ok(id)
+    }
+}
+
+/// Install a service that was removed with its directories kept, and restore its registry entry
+/// from the one it had before being removed.
+pub fn reinstall_removed_service(
+    service_data: &mut NodeServiceData,
+    previous: &NodeServiceData,
+    env_variables: Option<Vec<(String, String)>>,
+    service_control: &dyn ServiceControl,
+) -> Result<()> {
+    let undo_not_possible = |reason: &str| Error::UndoNotPossible {
+        service_name: service_data.service_name.clone(),
+        reason: reason.to_string(),
+    };
+    if service_data.status != ServiceStatus::Removed {
+        return Err(undo_not_possible("it is not removed"));
+    }
+    if !previous.data_dir_path.exists() {
+        return Err(undo_not_possible("its data directory no longer exists"));
+    }
+    if !previous.antnode_path.exists() {
+        return Err(undo_not_possible("its antnode binary no longer exists"));
+    }
+
+    let mut restored = previous.clone();
+    restored.pid = None;
+    restored.connected_peers = None;
+    if restored.status == ServiceStatus::Running {
+        restored.status = ServiceStatus::Stopped;
+    }
+
+    let rpc_client = RpcClient::from_socket_addr(restored.rpc_socket_addr);
+    let options = UpgradeOptions {
+        auto_restart: restored.auto_restart,
+        env_variables,
+        force: false,
+        start_service: false,
+        target_bin_path: restored.antnode_path.clone(),
+        target_version: Version::parse(&restored.version)?,
+    };
+    let user_mode = restored.user_mode;
+    let install_ctx = NodeService::new(&mut restored, Box::new(rpc_client))
+        .build_upgrade_install_context(options)?;
+    service_control.install(install_ctx, user_mode)?;
+
+    *service_data = restored;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ant_bootstrap::PeersArgs;
+    use ant_evm::EvmNetwork;
+    use assert_fs::prelude::*;
+    use std::{
+        net::{IpAddr, Ipv4Addr, SocketAddr},
+        str::FromStr,
+    };
+
+    fn service(number: u16, status: ServiceStatus) -> NodeServiceData {
+        NodeServiceData {
+            antnode_path: PathBuf::from(format!("/var/antctl/services/antnode{number}/antnode")),
+            auto_restart: false,
+            connected_peers: None,
+            data_dir_path: PathBuf::from(format!("/var/antctl/services/antnode{number}")),
+            evm_network: EvmNetwork::ArbitrumOne,
+            home_network: false,
+            listen_addr: None,
+            log_dir_path: PathBuf::from(format!("/var/log/antnode/antnode{number}")),
+            log_format: None,
+            max_archived_log_files: None,
+            max_log_files: None,
+            metrics_port: None,
+            network_id: None,
+            node_ip: None,
+            node_port: None,
+            number,
+            owner: None,
+            peer_id: None,
+            peers_args: PeersArgs::default(),
+            pid: None,
+            rewards_address: ant_evm::RewardsAddress::from_str(
+                "0x03B770D9cD32077cC0bF330c13C114a87643B124",
+            )
+            .unwrap(),
+            reward_balance: None,
+            rpc_socket_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 8080 + number),
+            service_name: format!("antnode{number}"),
+            status,
+            upnp: false,
+            user: Some("ant".to_string()),
+            user_mode: false,
+            version: "0.98.1".to_string(),
+        }
+    }
+
+    #[test]
+    fn adding_services_can_be_undone_by_removing_them() {
+        let before = vec![service(1, ServiceStatus::Running)];
+        let after = vec![
+            service(1, ServiceStatus::Running),
+            service(2, ServiceStatus::Added),
+            service(3, ServiceStatus::Added),
+        ];
+
+        let changes = diff_services(&before, &after);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            undo_action(CommandKind::Add, &changes),
+            Some(UndoAction::RemoveAddedServices {
+                service_names: vec!["antnode2".to_string(), "antnode3".to_string()]
+            })
+        );
+    }
+
+    #[test]
+    fn removal_is_only_undoable_when_directories_were_kept() {
+        let before = vec![service(1, ServiceStatus::Stopped)];
+        let after = vec![service(1, ServiceStatus::Removed)];
+        let changes = diff_services(&before, &after);
+
+        let fields = changes[0].changed_fields();
+        assert_eq!(
+            fields,
+            vec![(
+                "status".to_string(),
+                Value::from("Stopped"),
+                Value::from("Removed")
+            )]
+        );
+        assert_eq!(
+            undo_action(
+                CommandKind::Remove {
+                    keep_directories: true
+                },
+                &changes
+            ),
+            Some(UndoAction::ReinstallRemovedServices {
+                service_names: vec!["antnode1".to_string()]
+            })
+        );
+        assert_eq!(
+            undo_action(
+                CommandKind::Remove {
+                    keep_directories: false
+                },
+                &changes
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn history_is_persisted_and_bounded() -> color_eyre::Result<()> {
+        let tmp_dir = assert_fs::TempDir::new()?;
+        let history_file = tmp_dir.child("command_history.json");
+        let mut history = CommandHistory::load(history_file.path())?;
+
+        let before = vec![service(1, ServiceStatus::Stopped)];
+        let after = vec![service(1, ServiceStatus::Running)];
+        for _ in 0..MAX_HISTORY_ENTRIES + 2 {
+            let _ = history.push(CommandKind::Start, vec![], true, &before, &after);
+        }
+        history
+            .get_mut(MAX_HISTORY_ENTRIES as u64 + 2)
+            .unwrap()
+            .undone_by = Some(1000);
+        history.save()?;
+
+        let history = CommandHistory::load(history_file.path())?;
+        assert_eq!(history.entries.len(), MAX_HISTORY_ENTRIES);
+        assert_eq!(history.entries[0].id, 3);
+        assert_eq!(
+            history.last_undoable().map(|entry| entry.id),
+            Some(MAX_HISTORY_ENTRIES as u64 + 1)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn reinstall_is_refused_when_the_data_directory_is_gone() {
+        let previous = service(1, ServiceStatus::Stopped);
+        let mut current = service(1, ServiceStatus::Removed);
+        let service_control = ant_service_management::control::ServiceController {};
+
+        let result = reinstall_removed_service(&mut current, &previous, None, &service_control);
+        assert!(matches!(result, Err(Error::UndoNotPossible { .. })));
+        assert_eq!(current.status, ServiceStatus::Removed);
+    }
+}
@@ -11,14 +11,19 @@ extern crate tracing;
 
 pub mod add_services;
 pub mod cmd;
+pub mod compatibility;
 pub mod config;
 pub mod error;
+pub mod error_catalog;
 pub mod helpers;
+pub mod history;
 pub mod local;
+pub mod mirror;
 pub mod rpc;
 pub mod rpc_client;
 
 pub const DEFAULT_NODE_STARTUP_CONNECTION_TIMEOUT_S: u64 = 300;
+pub const DEFAULT_CONNECTIVITY_MIN_PEERS: usize = 5;
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum VerbosityLevel {
@@ -47,12 +52,88 @@ use ant_service_management::{
 };
 use colored::Colorize;
 use semver::Version;
+use std::time::{Duration, Instant};
 use tracing::debug;
 
 pub const DAEMON_DEFAULT_PORT: u16 = 12500;
 pub const DAEMON_SERVICE_NAME: &str = "antctld";
 
 const RPC_START_UP_DELAY_MS: u64 = 3000;
+const CONNECTIVITY_POLL_INTERVAL_MS: u64 = 1000;
+
+/// The number of peers a node must be connected to before it is considered useful.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConnectivityRequirement {
+    pub min_peers: usize,
+    pub timeout: Duration,
+}
+
+impl ConnectivityRequirement {
+    /// Build the requirement from the optional `[min_peers] [timeout]` values of the
+    /// `--wait-for-connectivity` argument. The timeout is in seconds.
+    pub fn from_args(values: &[u64]) -> Self {
+        ConnectivityRequirement {
+            min_peers: values
+                .first()
+                .map_or(DEFAULT_CONNECTIVITY_MIN_PEERS, |min_peers| {
+                    *min_peers as usize
+                }),
+            timeout: Duration::from_secs(
+                values
+                    .get(1)
+                    .copied()
+                    .unwrap_or(DEFAULT_NODE_STARTUP_CONNECTION_TIMEOUT_S),
+            ),
+        }
+    }
+}
+
+/// Poll the RPC endpoint of a node until it reports at least the required number of connected
+/// peers, returning the number of connected peers.
+///
+/// The endpoint not being reachable is not an error, because it is usually not listening yet
+/// straight after the node has been started.
+pub async fn wait_for_connectivity(
+    service_name: &str,
+    rpc_client: &dyn RpcActions,
+    requirement: ConnectivityRequirement,
+) -> Result<usize> {
+    let started_at = Instant::now();
+    loop {
+        let connected_peers = match rpc_client.network_info().await {
+            Ok(network_info) => network_info.connected_peers.len(),
+            Err(err) => {
+                debug!("Could not obtain the network info for {service_name}: {err}");
+                0
+            }
+        };
+        if connected_peers >= requirement.min_peers {
+            info!("The {service_name} service is connected to {connected_peers} peers");
+            return Ok(connected_peers);
+        }
+
+        let elapsed = started_at.elapsed();
+        if elapsed >= requirement.timeout {
+            error!(
+                "The {service_name} service only connected to {connected_peers} of the {} required peers",
+                requirement.min_peers
+            );
+            return Err(Error::ConnectivityTimeout {
+                service_name: service_name.to_string(),
+                min_peers: requirement.min_peers,
+                connected_peers,
+                timeout_s: requirement.timeout.as_secs(),
+            });
+        }
+        trace!(
+            "The {service_name} service is connected to {connected_peers} of the {} required peers",
+            requirement.min_peers
+        );
+        let delay =
+            Duration::from_millis(CONNECTIVITY_POLL_INTERVAL_MS).min(requirement.timeout - elapsed);
+        tokio::time::sleep(delay).await;
+    }
+}
 
 pub struct ServiceManager<T: ServiceStateActions + Send> {
     pub service: T,
@@ -655,7 +736,7 @@ mod tests {
     use ant_service_management::{
         error::{Error as ServiceControlError, Result as ServiceControlResult},
         node::{NodeService, NodeServiceData},
-        rpc::{NetworkInfo, NodeInfo, RecordAddress, RpcActions},
+        rpc::{ListenerStatus, NetworkInfo, NodeInfo, RecordAddress, RpcActions, StoreCostInfo},
         UpgradeOptions, UpgradeResult,
     };
     use assert_fs::prelude::*;
@@ -681,6 +762,8 @@ mod tests {
             async fn node_info(&self) -> ServiceControlResult<NodeInfo>;
             async fn network_info(&self) -> ServiceControlResult<NetworkInfo>;
             async fn record_addresses(&self) -> ServiceControlResult<Vec<RecordAddress>>;
+            async fn store_cost_info(&self) -> ServiceControlResult<StoreCostInfo>;
+            async fn listener_status(&self) -> ServiceControlResult<ListenerStatus>;
             async fn node_restart(&self, delay_millis: u64, retain_peer_id: bool) -> ServiceControlResult<()>;
             async fn node_stop(&self, delay_millis: u64) -> ServiceControlResult<()>;
             async fn node_update(&self, delay_millis: u64) -> ServiceControlResult<()>;
@@ -2755,6 +2838,7 @@ mod tests {
                 disable_mainnet_contacts: false,
                 ignore_cache: false,
                 bootstrap_cache_dir: None,
+                proxy: Default::default(),
             },
             pid: Some(1000),
             rewards_address: RewardsAddress::from_str(
@@ -2930,6 +3014,7 @@ network_id: None,
                 disable_mainnet_contacts: false,
                 ignore_cache: false,
         bootstrap_cache_dir: None,
+        proxy: Default::default(),
     },
             pid: Some(1000),
             rewards_address: RewardsAddress::from_str(
@@ -3266,6 +3351,7 @@ network_id: None,
                 disable_mainnet_contacts: false,
                 ignore_cache: false,
                 bootstrap_cache_dir: None,
+                proxy: Default::default(),
             },
             pid: Some(1000),
             rewards_address: RewardsAddress::from_str(
@@ -3439,6 +3525,7 @@ network_id: None,
                 disable_mainnet_contacts: false,
                 ignore_cache: false,
                 bootstrap_cache_dir: None,
+                proxy: Default::default(),
             },
             pid: Some(1000),
             rewards_address: RewardsAddress::from_str(
@@ -3616,6 +3703,7 @@ network_id: None,
                 disable_mainnet_contacts: true,
                 ignore_cache: false,
                 bootstrap_cache_dir: None,
+                proxy: Default::default(),
             },
             pid: Some(1000),
             rewards_address: RewardsAddress::from_str(
@@ -3791,6 +3879,7 @@ network_id: None,
                 disable_mainnet_contacts: false,
                 ignore_cache: true,
                 bootstrap_cache_dir: None,
+                proxy: Default::default(),
             },
             pid: Some(1000),
             rewards_address: RewardsAddress::from_str(
@@ -3963,6 +4052,7 @@ network_id: None,
                 bootstrap_cache_dir: Some(PathBuf::from(
                     "/var/antctl/services/antnode1/bootstrap_cache",
                 )),
+                proxy: Default::default(),
             },
             pid: Some(1000),
             rewards_address: RewardsAddress::from_str(
@@ -6714,4 +6804,98 @@ network_id: None,
 
         Ok(())
     }
+
+    #[test]
+    fn connectivity_requirement_should_use_the_defaults_for_missing_values() {
+        assert_eq!(
+            ConnectivityRequirement::from_args(&[]),
+            ConnectivityRequirement {
+                min_peers: DEFAULT_CONNECTIVITY_MIN_PEERS,
+                timeout: Duration::from_secs(DEFAULT_NODE_STARTUP_CONNECTION_TIMEOUT_S),
+            }
+        );
+        assert_eq!(
+            ConnectivityRequirement::from_args(&[10]),
+            ConnectivityRequirement {
+                min_peers: 10,
+                timeout: Duration::from_secs(DEFAULT_NODE_STARTUP_CONNECTION_TIMEOUT_S),
+            }
+        );
+        assert_eq!(
+            ConnectivityRequirement::from_args(&[10, 120]),
+            ConnectivityRequirement {
+                min_peers: 10,
+                timeout: Duration::from_secs(120),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn wait_for_connectivity_should_poll_until_enough_peers_are_connected() -> Result<()> {
+        let mut mock_rpc_client = MockRpcClient::new();
+        let mut calls = 0;
+        mock_rpc_client
+            .expect_network_info()
+            .times(2)
+            .returning(move || {
+                calls += 1;
+                if calls == 1 {
+                    return Err(ServiceControlError::RpcConnectionError(
+                        "connection refused".to_string(),
+                    ));
+                }
+                Ok(NetworkInfo {
+                    connected_peers: vec![PeerId::random(), PeerId::random(), PeerId::random()],
+                    listeners: Vec::new(),
+                })
+            });
+
+        let connected_peers = wait_for_connectivity(
+            "antnode1",
+            &mock_rpc_client,
+            ConnectivityRequirement {
+                min_peers: 3,
+                timeout: Duration::from_secs(10),
+            },
+        )
+        .await?;
+
+        assert_eq!(connected_peers, 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wait_for_connectivity_should_time_out_if_not_enough_peers_are_connected() -> Result<()>
+    {
+        let mut mock_rpc_client = MockRpcClient::new();
+        mock_rpc_client
+            .expect_network_info()
+            .times(1)
+            .returning(|| {
+                Ok(NetworkInfo {
+                    connected_peers: vec![PeerId::random()],
+                    listeners: Vec::new(),
+                })
+            });
+
+        let result = wait_for_connectivity(
+            "antnode1",
+            &mock_rpc_client,
+            ConnectivityRequirement {
+                min_peers: 3,
+                timeout: Duration::ZERO,
+            },
+        )
+        .await;
+
+        assert_matches!(
+            result,
+            Err(Error::ConnectivityTimeout {
+                min_peers: 3,
+                connected_peers: 1,
+                ..
+            })
+        );
+        Ok(())
+    }
 }
@@ -449,6 +449,7 @@ pub async fn run_node(
             disable_mainnet_contacts: true,
             ignore_cache: true,
             bootstrap_cache_dir: None,
+            proxy: Default::default(),
         },
         pid: Some(node_info.pid),
         rewards_address: run_options.rewards_address,
@@ -531,7 +532,7 @@ mod tests {
     use ant_evm::utils::dummy_address;
     use ant_service_management::{
         error::Result as RpcResult,
-        rpc::{NetworkInfo, NodeInfo, RecordAddress, RpcActions},
+        rpc::{ListenerStatus, NetworkInfo, NodeInfo, RecordAddress, RpcActions, StoreCostInfo},
     };
     use async_trait::async_trait;
     use libp2p_identity::PeerId;
@@ -546,6 +547,8 @@ mod tests {
             async fn node_info(&self) -> RpcResult<NodeInfo>;
             async fn network_info(&self) -> RpcResult<NetworkInfo>;
             async fn record_addresses(&self) -> RpcResult<Vec<RecordAddress>>;
+            async fn store_cost_info(&self) -> RpcResult<StoreCostInfo>;
+            async fn listener_status(&self) -> RpcResult<ListenerStatus>;
             async fn node_restart(&self, delay_millis: u64, retain_peer_id: bool) -> RpcResult<()>;
             async fn node_stop(&self, delay_millis: u64) -> RpcResult<()>;
             async fn node_update(&self, delay_millis: u64) -> RpcResult<()>;
@@ -0,0 +1,551 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Downloading release binaries from an internal artifact mirror.
+//!
+//! A mirror is an HTTP server with the same layout as the public release buckets, i.e. archives
+//! named `<binary>-<version>-<platform>.<ext>` at its base URL, along with:
+//!
+//! - `manifest.json`: the latest approved version of each binary, and the SHA-256 checksum of each
+//!   approved archive.
+//! - `manifest.json.sig`: the hex-encoded ed25519 signature of `manifest.json`.
+//!
+//! The signature is verified with the public key configured for the mirror, and only archives with
+//! a checksum listed in the manifest are installed, so fleets only pull binaries that were approved.
+//! The manifest carries a sequence number, and optionally an expiry time, so an older signed
+//! manifest can't be served again to roll the fleet back to binaries that were since withdrawn.
+
+use crate::{
+    config,
+    error::{Error, Result},
+};
+use ant_releases::{
+    AntReleaseRepoActions, AntReleaseRepository, ArchiveType, Platform, ProgressCallback,
+    ReleaseType,
+};
+use async_trait::async_trait;
+use libp2p_identity::ed25519;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+pub const MANIFEST_SIGNATURE_FILE_NAME: &str = "manifest.json.sig";
+
+/// The artifact mirror that release binaries are downloaded from, in place of the public releases.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MirrorConfig {
+    /// The base URL of the mirror, without a trailing slash.
+    pub base_url: String,
+    /// The hex-encoded ed25519 public key the manifest is signed with.
+    pub public_key: String,
+    /// The highest manifest sequence number accepted so far. Manifests with a lower one are
+    /// rejected.
+    #[serde(default)]
+    pub last_sequence: u64,
+}
+
+impl MirrorConfig {
+    pub fn new(base_url: &str, public_key: &str) -> Result<Self> {
+        let config = Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            public_key: public_key.trim().to_string(),
+            last_sequence: 0,
+        };
+        let _ = config.decode_public_key()?;
+        Ok(config)
+    }
+
+    /// Load the configured mirror, if there is one.
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let contents = std::fs::read_to_string(path)?;
+        Ok(Some(serde_json::from_str(&contents)?))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn manifest_url(&self) -> String {
+        format!("{}/{MANIFEST_FILE_NAME}", self.base_url)
+    }
+
+    fn decode_public_key(&self) -> Result<ed25519::PublicKey> {
+        hex::decode(&self.public_key)
+            .ok()
+            .and_then(|bytes| ed25519::PublicKey::try_from_bytes(&bytes).ok())
+            .ok_or_else(|| Error::InvalidMirrorPublicKey(self.public_key.clone()))
+    }
+}
+
+/// The binaries approved for download from a mirror.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MirrorManifest {
+    /// The latest approved version of each binary, keyed by binary name, e.g. `antnode`.
+    pub latest: BTreeMap<String, Version>,
+    /// The hex-encoded SHA-256 checksum of each approved archive, keyed by archive file name.
+    pub artifacts: BTreeMap<String, String>,
+    /// Increased every time a new manifest is signed.
+    #[serde(default)]
+    pub sequence: u64,
+    /// The UNIX timestamp after which the manifest is no longer accepted, if it expires.
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+}
+
+impl MirrorManifest {
+    /// Parse the manifest, after verifying its signature was made with the key of the mirror, and
+    /// that it is neither expired nor older than the last manifest accepted from the mirror.
+    pub fn verify_and_parse(
+        config: &MirrorConfig,
+        manifest_bytes: &[u8],
+        signature_hex: &str,
+    ) -> Result<Self> {
+        let public_key = config.decode_public_key()?;
+        let signature =
+            hex::decode(signature_hex.trim()).map_err(|err| Error::MirrorManifestUnusable {
+                url: config.manifest_url(),
+                reason: format!("the signature is not valid hex: {err}"),
+            })?;
+        if !public_key.verify(manifest_bytes, &signature) {
+            return Err(Error::MirrorManifestUnusable {
+                url: config.manifest_url(),
+                reason: "the signature does not match the public key of the mirror".to_string(),
+            });
+        }
+
+        let manifest: Self = serde_json::from_slice(manifest_bytes).map_err(|err| {
+            Error::MirrorManifestUnusable {
+                url: config.manifest_url(),
+                reason: format!("it could not be parsed: {err}"),
+            }
+        })?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+        manifest.check_freshness(config, now)?;
+        Ok(manifest)
+    }
+
+    fn check_freshness(&self, config: &MirrorConfig, now: u64) -> Result<()> {
+        if self.sequence < config.last_sequence {
+            return Err(Error::MirrorManifestUnusable {
+                url: config.manifest_url(),
+                reason: format!(
+                    "its sequence number {} is older than the last accepted manifest ({})",
+                    self.sequence, config.last_sequence
+                ),
+            });
+        }
+        if let Some(expires_at) = self.expires_at {
+            if expires_at <= now {
+                return Err(Error::MirrorManifestUnusable {
+                    url: config.manifest_url(),
+                    reason: format!("it expired at {expires_at}"),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the contents of the archive match the checksum of an approved archive.
+    ///
+    /// If the file name of the archive is listed, its checksum has to match that entry. Otherwise,
+    /// e.g. for an archive from a URL that was explicitly supplied, it has to match any of the
+    /// approved checksums, so the archive is approved by its contents rather than by its name.
+    fn verify_archive(&self, archive_path: &Path) -> ant_releases::Result<()> {
+        let file_name = archive_file_name(archive_path)?;
+        let actual = hex::encode(Sha256::digest(std::fs::read(archive_path)?));
+        match self.artifacts.get(&file_name) {
+            Some(expected) if !actual.eq_ignore_ascii_case(expected) => {
+                error!("The checksum of {archive_path:?} is {actual}, but {expected} was approved");
+                Err(ant_releases::Error::Io(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("The checksum of {file_name} does not match the checksum in the mirror manifest"),
+                )))
+            }
+            Some(_) => Ok(()),
+            None if self
+                .artifacts
+                .values()
+                .any(|expected| actual.eq_ignore_ascii_case(expected)) =>
+            {
+                debug!("{file_name} is not listed by name, but its checksum was approved");
+                Ok(())
+            }
+            None => {
+                error!("The checksum of {archive_path:?} is {actual}, which was not approved");
+                Err(ant_releases::Error::ReleaseBinaryNotFound(file_name))
+            }
+        }
+    }
+}
+
+/// Get the release repository to download binaries with.
+///
+/// If a mirror is configured, its manifest is fetched and verified, and binaries are only
+/// downloaded from the mirror; otherwise the public releases are used.
+pub async fn get_release_repo() -> color_eyre::Result<Box<dyn AntReleaseRepoActions>> {
+    let config_path = config::get_artifact_mirror_config_path()?;
+    match MirrorConfig::load(&config_path)? {
+        Some(mut config) => {
+            info!("Using the artifact mirror at {}", config.base_url);
+            let repo = MirrorReleaseRepository::connect(config.clone()).await?;
+            if repo.manifest.sequence > config.last_sequence {
+                config.last_sequence = repo.manifest.sequence;
+                config.save(&config_path)?;
+            }
+            Ok(Box::new(repo))
+        }
+        None => Ok(<dyn AntReleaseRepoActions>::default_config()),
+    }
+}
+
+/// A release repository backed by an artifact mirror.
+pub struct MirrorReleaseRepository {
+    manifest: MirrorManifest,
+    mirror: AntReleaseRepository,
+}
+
+impl MirrorReleaseRepository {
+    /// Fetch and verify the manifest of the mirror.
+    pub async fn connect(config: MirrorConfig) -> Result<Self> {
+        let manifest_url = config.manifest_url();
+        let signature_url = format!("{}/{MANIFEST_SIGNATURE_FILE_NAME}", config.base_url);
+        debug!("Fetching the mirror manifest from {manifest_url}");
+        let manifest_bytes = fetch(&manifest_url).await?;
+        let signature = fetch(&signature_url).await?;
+        let signature =
+            String::from_utf8(signature).map_err(|err| Error::MirrorManifestUnusable {
+                url: manifest_url.clone(),
+                reason: format!("the signature is not valid UTF-8: {err}"),
+            })?;
+        let manifest = MirrorManifest::verify_and_parse(&config, &manifest_bytes, &signature)?;
+        info!(
+            "The mirror manifest approves {} archive(s)",
+            manifest.artifacts.len()
+        );
+
+        Ok(Self::new(config, manifest))
+    }
+
+    pub fn new(config: MirrorConfig, manifest: MirrorManifest) -> Self {
+        let mirror = AntReleaseRepository {
+            ant_base_url: config.base_url.clone(),
+            antctl_base_url: config.base_url.clone(),
+            antnode_base_url: config.base_url.clone(),
+            antnode_rpc_client_base_url: config.base_url.clone(),
+            github_api_base_url: config.base_url.clone(),
+            nat_detection_base_url: config.base_url.clone(),
+            node_launchpad_base_url: config.base_url.clone(),
+        };
+        Self { manifest, mirror }
+    }
+
+    pub fn manifest(&self) -> &MirrorManifest {
+        &self.manifest
+    }
+}
+
+#[async_trait]
+impl AntReleaseRepoActions for MirrorReleaseRepository {
+    /// The latest version approved in the manifest, rather than the latest published version.
+    async fn get_latest_version(
+        &self,
+        release_type: &ReleaseType,
+    ) -> ant_releases::Result<Version> {
+        let name = release_type.to_string().to_lowercase();
+        self.manifest
+            .latest
+            .get(&name)
+            .cloned()
+            .ok_or(ant_releases::Error::LatestReleaseNotFound(name))
+    }
+
+    async fn download_release_from_s3(
+        &self,
+        release_type: &ReleaseType,
+        version: &Version,
+        platform: &Platform,
+        archive_type: &ArchiveType,
+        dest_path: &Path,
+        callback: &ProgressCallback,
+    ) -> ant_releases::Result<PathBuf> {
+        let archive_name = format!(
+            "{}-{version}-{platform}.{archive_type}",
+            release_type.to_string().to_lowercase()
+        );
+        if !self.manifest.artifacts.contains_key(&archive_name) {
+            error!("{archive_name} is not approved in the mirror manifest");
+            return Err(ant_releases::Error::ReleaseBinaryNotFound(archive_name));
+        }
+
+        let archive_path = self
+            .mirror
+            .download_release_from_s3(
+                release_type,
+                version,
+                platform,
+                archive_type,
+                dest_path,
+                callback,
+            )
+            .await?;
+        if let Err(err) = self.manifest.verify_archive(&archive_path) {
+            let _ = std::fs::remove_file(&archive_path);
+            return Err(err);
+        }
+        Ok(archive_path)
+    }
+
+    /// Download an archive from a URL that was explicitly supplied.
+    ///
+    /// The archive is rejected unless its checksum was approved in the manifest.
+    async fn download_release(
+        &self,
+        url: &str,
+        dest_dir_path: &Path,
+        callback: &ProgressCallback,
+    ) -> ant_releases::Result<PathBuf> {
+        let archive_path = self
+            .mirror
+            .download_release(url, dest_dir_path, callback)
+            .await?;
+        if let Err(err) = self.manifest.verify_archive(&archive_path) {
+            error!("{url} was not approved in the mirror manifest");
+            let _ = std::fs::remove_file(&archive_path);
+            return Err(err);
+        }
+        Ok(archive_path)
+    }
+
+    /// WinSW is not a release binary, so it's always obtained from its public location.
+    async fn download_winsw(
+        &self,
+        dest_path: &Path,
+        callback: &ProgressCallback,
+    ) -> ant_releases::Result<()> {
+        self.mirror.download_winsw(dest_path, callback).await
+    }
+
+    /// Archives are verified before they are extracted, so an archive cached from an earlier
+    /// download, or supplied as a local path, is only used if it was approved.
+    fn extract_release_archive(
+        &self,
+        archive_path: &Path,
+        dest_dir_path: &Path,
+    ) -> ant_releases::Result<PathBuf> {
+        self.manifest.verify_archive(archive_path)?;
+        self.mirror
+            .extract_release_archive(archive_path, dest_dir_path)
+    }
+}
+
+fn archive_file_name(archive_path: &Path) -> ant_releases::Result<String> {
+    archive_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .ok_or(ant_releases::Error::CannotParseFilenameFromUrl)
+}
+
+async fn fetch(url: &str) -> Result<Vec<u8>> {
+    let unusable = |reason: String| Error::MirrorManifestUnusable {
+        url: url.to_string(),
+        reason,
+    };
+    let response = reqwest::get(url)
+        .await
+        .map_err(|err| unusable(format!("it could not be fetched: {err}")))?;
+    if !response.status().is_success() {
+        return Err(unusable(format!(
+            "the mirror responded with {}",
+            response.status()
+        )));
+    }
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|err| unusable(format!("it could not be fetched: {err}")))?;
+    Ok(bytes.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_fs::prelude::*;
+
+    fn signed_manifest(keypair: &ed25519::Keypair, manifest: &MirrorManifest) -> (Vec<u8>, String) {
+        let bytes = serde_json::to_vec(manifest).unwrap();
+        let signature = hex::encode(keypair.sign(&bytes));
+        (bytes, signature)
+    }
+
+    fn mirror_config(keypair: &ed25519::Keypair) -> MirrorConfig {
+        MirrorConfig::new(
+            "https://mirror.example.com/releases/",
+            &hex::encode(keypair.public().to_bytes()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn manifest_signed_with_the_mirror_key_is_accepted() {
+        let keypair = ed25519::Keypair::generate();
+        let manifest = MirrorManifest {
+            latest: BTreeMap::from([("antnode".to_string(), Version::new(0, 3, 1))]),
+            artifacts: BTreeMap::from([(
+                "antnode-0.3.1-x86_64-unknown-linux-musl.tar.gz".to_string(),
+                "ab".repeat(32),
+            )]),
+            sequence: 3,
+            expires_at: None,
+        };
+        let (bytes, signature) = signed_manifest(&keypair, &manifest);
+        let config = mirror_config(&keypair);
+
+        assert_eq!(
+            config.manifest_url(),
+            "https://mirror.example.com/releases/manifest.json"
+        );
+        assert_eq!(
+            MirrorManifest::verify_and_parse(&config, &bytes, &signature).unwrap(),
+            manifest
+        );
+    }
+
+    #[test]
+    fn manifest_signed_with_another_key_is_rejected() {
+        let keypair = ed25519::Keypair::generate();
+        let (bytes, signature) =
+            signed_manifest(&ed25519::Keypair::generate(), &MirrorManifest::default());
+
+        let result = MirrorManifest::verify_and_parse(&mirror_config(&keypair), &bytes, &signature);
+        assert!(matches!(result, Err(Error::MirrorManifestUnusable { .. })));
+    }
+
+    #[test]
+    fn invalid_public_key_is_rejected() {
+        assert!(matches!(
+            MirrorConfig::new("https://mirror.example.com", "not-a-key"),
+            Err(Error::InvalidMirrorPublicKey(_))
+        ));
+    }
+
+    #[test]
+    fn only_approved_archives_with_matching_checksums_are_verified() {
+        let temp_dir = assert_fs::TempDir::new().unwrap();
+        let approved = temp_dir.child("antnode-0.3.1-x86_64-unknown-linux-musl.tar.gz");
+        approved.write_binary(b"approved").unwrap();
+        let tampered = temp_dir.child("antnode-0.3.0-x86_64-unknown-linux-musl.tar.gz");
+        tampered.write_binary(b"tampered").unwrap();
+        let unlisted = temp_dir.child("antnode-0.2.0-x86_64-unknown-linux-musl.tar.gz");
+        unlisted.write_binary(b"unlisted").unwrap();
+
+        let manifest = MirrorManifest {
+            latest: BTreeMap::new(),
+            artifacts: BTreeMap::from([
+                (
+                    "antnode-0.3.1-x86_64-unknown-linux-musl.tar.gz".to_string(),
+                    hex::encode(Sha256::digest(b"approved")),
+                ),
+                (
+                    "antnode-0.3.0-x86_64-unknown-linux-musl.tar.gz".to_string(),
+                    hex::encode(Sha256::digest(b"original")),
+                ),
+            ]),
+            ..Default::default()
+        };
+
+        assert!(manifest.verify_archive(approved.path()).is_ok());
+        assert!(matches!(
+            manifest.verify_archive(tampered.path()),
+            Err(ant_releases::Error::Io(err)) if err.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(matches!(
+            manifest.verify_archive(unlisted.path()),
+            Err(ant_releases::Error::ReleaseBinaryNotFound(_))
+        ));
+
+        // An archive supplied by URL under another name is approved by its contents.
+        let renamed = temp_dir.child("custom-antnode.tar.gz");
+        renamed.write_binary(b"approved").unwrap();
+        assert!(manifest.verify_archive(renamed.path()).is_ok());
+        let renamed_tampered = temp_dir.child("custom-antnode-2.tar.gz");
+        renamed_tampered.write_binary(b"tampered").unwrap();
+        assert!(matches!(
+            manifest.verify_archive(renamed_tampered.path()),
+            Err(ant_releases::Error::ReleaseBinaryNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn replayed_or_expired_manifests_are_rejected() {
+        let keypair = ed25519::Keypair::generate();
+        let mut config = mirror_config(&keypair);
+        config.last_sequence = 5;
+
+        let manifest = |sequence, expires_at| MirrorManifest {
+            sequence,
+            expires_at,
+            ..Default::default()
+        };
+        assert!(manifest(5, None).check_freshness(&config, 1000).is_ok());
+        assert!(manifest(6, Some(1001))
+            .check_freshness(&config, 1000)
+            .is_ok());
+        assert!(matches!(
+            manifest(4, None).check_freshness(&config, 1000),
+            Err(Error::MirrorManifestUnusable { .. })
+        ));
+        assert!(matches!(
+            manifest(6, Some(1000)).check_freshness(&config, 1000),
+            Err(Error::MirrorManifestUnusable { .. })
+        ));
+
+        let (bytes, signature) = signed_manifest(&keypair, &manifest(4, None));
+        assert!(matches!(
+            MirrorManifest::verify_and_parse(&config, &bytes, &signature),
+            Err(Error::MirrorManifestUnusable { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn latest_version_comes_from_the_manifest() {
+        let keypair = ed25519::Keypair::generate();
+        let manifest = MirrorManifest {
+            latest: BTreeMap::from([("antnode".to_string(), Version::new(0, 3, 1))]),
+            ..Default::default()
+        };
+        let repo = MirrorReleaseRepository::new(mirror_config(&keypair), manifest);
+
+        assert_eq!(
+            repo.get_latest_version(&ReleaseType::AntNode)
+                .await
+                .unwrap(),
+            Version::new(0, 3, 1)
+        );
+        assert!(matches!(
+            repo.get_latest_version(&ReleaseType::AntCtl).await,
+            Err(ant_releases::Error::LatestReleaseNotFound(_))
+        ));
+    }
+}
@@ -48,6 +48,9 @@ enum Cmd {
     /// Retrieve information about the node's connections to the network
     #[clap(name = "netinfo")]
     Netinfo,
+    /// Retrieve the node's listeners and advertised addresses for each IP family
+    #[clap(name = "listeners")]
+    Listeners,
     /// Start listening for node events.
     /// Note this blocks the app and it will print events as they are broadcasted by the node
     #[clap(name = "events")]
@@ -126,6 +129,7 @@ async fn main() -> Result<()> {
     match opt.cmd {
         Cmd::Info => node_info(addr).await,
         Cmd::Netinfo => network_info(addr).await,
+        Cmd::Listeners => listener_status(addr).await,
         Cmd::Events => node_events(addr).await,
         Cmd::Restart {
             delay_millis,
@@ -175,6 +179,26 @@ pub async fn network_info(addr: SocketAddr) -> Result<()> {
     Ok(())
 }
 
+pub async fn listener_status(addr: SocketAddr) -> Result<()> {
+    let endpoint = format!("https://{addr}");
+    let client = RpcClient::new(&endpoint);
+    let status = client.listener_status().await?;
+
+    println!("Node's IP mode: {}", status.ip_mode);
+    for family in status.families.iter() {
+        println!();
+        println!("{}: {}", family.family, family.state);
+        for listener in family.listeners.iter() {
+            println!("Listener: {listener}");
+        }
+        for address in family.external_addresses.iter() {
+            println!("Advertised: {address}");
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn node_events(addr: SocketAddr) -> Result<()> {
     let endpoint = format!("https://{addr}");
     let mut client = AntNodeClient::connect(endpoint).await?;
@@ -18,7 +18,9 @@ use ant_evm::{get_evm_network_from_env, EvmNetwork, RewardsAddress};
 #[cfg(feature = "local")]
 use ant_logging::metrics::init_metrics;
 use ant_logging::{Level, LogFormat, LogOutputDest, ReloadHandle};
-use ant_node::{Marker, NodeBuilder, NodeEvent, NodeEventsReceiver};
+use ant_node::{
+    IpMode, ListenerState, Marker, NodeBuilder, NodeEvent, NodeEventsReceiver, RunningNode,
+};
 use ant_protocol::{
     node::get_antnode_root_dir,
     node_rpc::{NodeCtrl, StopResult},
@@ -169,6 +171,29 @@ struct Opt {
     #[clap(long, default_value_t = IpAddr::V4(Ipv4Addr::UNSPECIFIED))]
     ip: IpAddr,
 
+    /// Only listen on, and advertise, IPv6 addresses.
+    ///
+    /// If the IP to listen on is left as `0.0.0.0`, the node listens on `::` instead.
+    ///
+    /// The node is stopped at startup if it fails to listen over IPv6.
+    #[clap(long, conflicts_with = "dual_stack")]
+    ipv6_only: bool,
+
+    /// Listen over both IPv4 and IPv6, on the same port.
+    ///
+    /// The node listens on the IP provided by `--ip` and on the unspecified address of the other
+    /// IP family, i.e. `0.0.0.0` or `::`. Both IPv4 and IPv6 addresses are advertised, unless
+    /// `--prefer-ipv6` is used.
+    ///
+    /// The node is stopped at startup if it fails to listen over either family.
+    #[clap(long)]
+    dual_stack: bool,
+
+    /// When running dual-stack, only advertise the node's IPv6 addresses while it has any,
+    /// falling back to its IPv4 addresses otherwise.
+    #[clap(long, requires = "dual_stack")]
+    prefer_ipv6: bool,
+
     #[command(flatten)]
     peers: PeersArgs,
 
@@ -272,6 +297,15 @@ fn main() -> Result<()> {
     println!("EVM network: {evm_network:?}");
 
     let node_socket_addr = SocketAddr::new(opt.ip, opt.port);
+    let ip_mode = if opt.ipv6_only {
+        IpMode::Ipv6Only
+    } else if opt.dual_stack {
+        IpMode::DualStack {
+            prefer_ipv6: opt.prefer_ipv6,
+        }
+    } else {
+        IpMode::SingleStack
+    };
     let (root_dir, keypair) = get_root_dir_and_keypair(&opt.root_dir)?;
 
     let (log_output_dest, log_reload_handle, _log_appender_guard) =
@@ -324,6 +358,7 @@ fn main() -> Result<()> {
         node_builder.initial_peers(initial_peres);
         node_builder.bootstrap_cache(bootstrap_cache);
         node_builder.is_behind_home_network(opt.home_network);
+        node_builder.ip_mode(ip_mode);
         #[cfg(feature = "open-metrics")]
         let mut node_builder = node_builder;
         // if enable flag is provided or only if the port is specified then enable the server by setting Some()
@@ -335,8 +370,14 @@ fn main() -> Result<()> {
         };
         #[cfg(feature = "open-metrics")]
         node_builder.metrics_server_port(metrics_server_port);
-        let restart_options =
-            run_node(node_builder, opt.rpc, &log_output_dest, log_reload_handle).await?;
+        let restart_options = run_node(
+            node_builder,
+            opt.rpc,
+            opt.peers.local,
+            &log_output_dest,
+            log_reload_handle,
+        )
+        .await?;
 
         Ok::<_, eyre::Report>(restart_options)
     })?;
@@ -363,6 +404,7 @@ fn main() -> Result<()> {
 async fn run_node(
     node_builder: NodeBuilder,
     rpc: Option<SocketAddr>,
+    local: bool,
     log_output_dest: &str,
     log_reload_handle: ReloadHandle,
 ) -> Result<Option<(bool, PathBuf, u16)>> {
@@ -395,6 +437,9 @@ You can check your reward balance by running:
     let node_events_rx = running_node.node_events_channel().subscribe();
     monitor_node_events(node_events_rx, ctrl_tx.clone());
 
+    // Check the node is listening over each IP family it should be
+    validate_ip_families(running_node.clone(), local, ctrl_tx.clone());
+
     // Monitor ctrl-c
     let ctrl_tx_clone = ctrl_tx.clone();
     tokio::spawn(async move {
@@ -523,6 +568,70 @@ You can check your reward balance by running:
     }
 }
 
+/// Check the listeners of each IP family once the node has had time to start them.
+///
+/// In the IPv6-only and dual-stack modes the node is stopped if it isn't listening over a family
+/// it should be, since it would otherwise only be partially reachable. Listeners that can't be
+/// reached from the internet are only warned about.
+fn validate_ip_families(running_node: RunningNode, local: bool, ctrl_tx: mpsc::Sender<NodeCtrl>) {
+    const LISTENER_START_DELAY: Duration = Duration::from_secs(10);
+
+    let _handle = tokio::spawn(async move {
+        sleep(LISTENER_START_DELAY).await;
+        let ip_mode = running_node.ip_mode();
+        let state = match running_node.get_swarm_local_state().await {
+            Ok(state) => state,
+            Err(err) => {
+                error!("Failed to get the listeners to validate the IP families: {err}");
+                return;
+            }
+        };
+
+        let mut not_listening = Vec::new();
+        for status in &state.ip_families {
+            info!(
+                "{} listener status in {ip_mode} mode: {}, listening on {:?}",
+                status.family, status.state, status.listeners
+            );
+            match status.state {
+                ListenerState::NotListening => not_listening.push(status.family.to_string()),
+                ListenerState::LocalOnly if !local => {
+                    let msg = format!(
+                        "The node only listens on {} addresses that can't be reached from the internet: {:?}",
+                        status.family, status.listeners
+                    );
+                    warn!("{msg}");
+                    println!("Warning: {msg}");
+                }
+                _ => {}
+            }
+        }
+
+        if not_listening.is_empty() {
+            return;
+        }
+        let msg = format!(
+            "The node failed to listen over {} in {ip_mode} mode",
+            not_listening.join(" and ")
+        );
+        if ip_mode == IpMode::SingleStack {
+            warn!("{msg}");
+            return;
+        }
+        if let Err(err) = ctrl_tx
+            .send(NodeCtrl::Stop {
+                delay: Duration::from_secs(1),
+                result: StopResult::Error(eyre!(
+                    "{msg}. Check the host has a usable address and route for each IP family."
+                )),
+            })
+            .await
+        {
+            error!("Failed to send node control msg to antnode bin main thread: {err}");
+        }
+    });
+}
+
 fn monitor_node_events(mut node_events_rx: NodeEventsReceiver, ctrl_tx: mpsc::Sender<NodeCtrl>) {
     let _handle = tokio::spawn(async move {
         loop {
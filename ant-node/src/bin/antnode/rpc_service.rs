@@ -7,13 +7,15 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use ant_logging::ReloadHandle;
-use ant_node::RunningNode;
+use ant_node::{RunningNode, RECENT_PUTS_WINDOW};
 use ant_protocol::antnode_proto::{
     ant_node_server::{AntNode, AntNodeServer},
-    k_buckets_response, KBucketsRequest, KBucketsResponse, NetworkInfoRequest, NetworkInfoResponse,
-    NodeEvent, NodeEventsRequest, NodeInfoRequest, NodeInfoResponse, RecordAddressesRequest,
-    RecordAddressesResponse, RestartRequest, RestartResponse, StopRequest, StopResponse,
-    UpdateLogLevelRequest, UpdateLogLevelResponse, UpdateRequest, UpdateResponse,
+    k_buckets_response, listener_status_response, store_cost_info_response, KBucketsRequest,
+    KBucketsResponse, ListenerStatusRequest, ListenerStatusResponse, NetworkInfoRequest,
+    NetworkInfoResponse, NodeEvent, NodeEventsRequest, NodeInfoRequest, NodeInfoResponse,
+    RecordAddressesRequest, RecordAddressesResponse, RestartRequest, RestartResponse, StopRequest,
+    StopResponse, StoreCostInfoRequest, StoreCostInfoResponse, UpdateLogLevelRequest,
+    UpdateLogLevelResponse, UpdateRequest, UpdateResponse,
 };
 use ant_protocol::node_rpc::{NodeCtrl, StopResult};
 use eyre::{ErrReport, Result};
@@ -185,6 +187,93 @@ impl AntNode for SafeNodeRpcService {
         Ok(Response::new(KBucketsResponse { kbuckets }))
     }
 
+    async fn store_cost_info(
+        &self,
+        request: Request<StoreCostInfoRequest>,
+    ) -> Result<Response<StoreCostInfoResponse>, Status> {
+        debug!(
+            "RPC request received at {}: {:?}",
+            self.addr,
+            request.get_ref()
+        );
+
+        let info = self.running_node.store_cost_info().await.map_err(|err| {
+            Status::new(
+                Code::Unavailable,
+                format!("Failed to get the store cost: {err}"),
+            )
+        })?;
+
+        let store_cost_curve = info
+            .store_cost_curve
+            .into_iter()
+            .map(
+                |(records_stored, store_cost)| store_cost_info_response::CostPoint {
+                    records_stored: records_stored as u64,
+                    store_cost: store_cost.to_string(),
+                },
+            )
+            .collect();
+        let recent_puts_rejected = info
+            .recent_puts
+            .rejected
+            .into_iter()
+            .map(|(reason, count)| (reason.to_string(), count as u64))
+            .collect();
+
+        Ok(Response::new(StoreCostInfoResponse {
+            store_cost: info.store_cost.to_string(),
+            store_cost_curve,
+            records_stored: info.records_stored as u64,
+            max_records: info.max_records as u64,
+            received_payment_count: info.received_payment_count as u64,
+            recent_puts_window_secs: RECENT_PUTS_WINDOW.as_secs(),
+            recent_puts_accepted: info.recent_puts.accepted as u64,
+            recent_puts_rejected,
+        }))
+    }
+
+    async fn listener_status(
+        &self,
+        request: Request<ListenerStatusRequest>,
+    ) -> Result<Response<ListenerStatusResponse>, Status> {
+        debug!(
+            "RPC request received at {}: {:?}",
+            self.addr,
+            request.get_ref()
+        );
+
+        let state = self
+            .running_node
+            .get_swarm_local_state()
+            .await
+            .map_err(|err| {
+                Status::new(
+                    Code::Unavailable,
+                    format!("Failed to get the listeners: {err}"),
+                )
+            })?;
+        let families = state
+            .ip_families
+            .into_iter()
+            .map(|status| listener_status_response::IpFamilyStatus {
+                family: status.family.to_string(),
+                state: status.state.to_string(),
+                listeners: status.listeners.iter().map(|m| m.to_string()).collect(),
+                external_addresses: status
+                    .external_addresses
+                    .iter()
+                    .map(|m| m.to_string())
+                    .collect(),
+            })
+            .collect();
+
+        Ok(Response::new(ListenerStatusResponse {
+            ip_mode: self.running_node.ip_mode().to_string(),
+            families,
+        }))
+    }
+
     async fn stop(&self, request: Request<StopRequest>) -> Result<Response<StopResponse>, Status> {
         debug!(
             "RPC request received at {}: {:?}",
@@ -8,6 +8,7 @@
 
 use ant_evm::AttoTokens;
 use ant_protocol::{NetworkAddress, PrettyPrintRecordKey};
+use libp2p::PeerId;
 use thiserror::Error;
 
 pub(super) type Result<T, E = Error> = std::result::Result<T, E>;
@@ -70,6 +71,10 @@ pub enum Error {
     },
     #[error("A payment we received contains cash notes already confirmed to be spent")]
     ReusedPayment,
+    #[error("Payment is not valid for record {0}")]
+    InvalidPayment(PrettyPrintRecordKey<'static>),
+    #[error("Payment quote has out-of-range payees {0:?}")]
+    PayeesOutOfRange(Vec<PeerId>),
 
     // ---------- Initialize Errors
     #[error("Failed to generate a reward key")]
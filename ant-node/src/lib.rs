@@ -32,14 +32,18 @@ mod put_validation;
 mod python;
 mod quote;
 mod replication;
+mod store_stats;
 
 pub use self::{
     event::{NodeEvent, NodeEventsChannel, NodeEventsReceiver},
     log_markers::Marker,
     node::{NodeBuilder, PERIODIC_REPLICATION_INTERVAL_MAX_S},
+    store_stats::{PutRejectionReason, RecentPuts, StoreCostInfo, RECENT_PUTS_WINDOW},
 };
+pub use ant_networking::{IpFamily, IpFamilyStatus, IpMode, ListenerState};
 
 use crate::error::{Error, Result};
+use crate::store_stats::PutOutcomes;
 
 use ant_networking::{Network, SwarmLocalState};
 use ant_protocol::{get_port_from_multiaddr, NetworkAddress};
@@ -49,7 +53,7 @@ use std::{
     path::PathBuf,
 };
 
-use ant_evm::RewardsAddress;
+use ant_evm::{EvmNetwork, RewardsAddress};
 
 /// Once a node is started and running, the user obtains
 /// a `NodeRunning` object which can be used to interact with it.
@@ -59,6 +63,9 @@ pub struct RunningNode {
     node_events_channel: NodeEventsChannel,
     root_dir_path: PathBuf,
     rewards_address: RewardsAddress,
+    evm_network: EvmNetwork,
+    put_outcomes: PutOutcomes,
+    ip_mode: IpMode,
 }
 
 impl RunningNode {
@@ -125,4 +132,17 @@ impl RunningNode {
     pub fn reward_address(&self) -> &RewardsAddress {
         &self.rewards_address
     }
+
+    /// Returns the IP families the node listens on, and which of its addresses it advertises
+    pub fn ip_mode(&self) -> IpMode {
+        self.ip_mode
+    }
+
+    /// Returns the node's current store cost, how it changes as the node fills up, and the
+    /// outcome of the record PUTs received recently.
+    ///
+    /// This queries the payment vault contract, so it shouldn't be called too often.
+    pub async fn store_cost_info(&self) -> Result<StoreCostInfo> {
+        store_stats::store_cost_info(&self.network, &self.evm_network, &self.put_outcomes).await
+    }
 }
@@ -6,7 +6,7 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-use crate::Marker;
+use crate::{Marker, PutRejectionReason};
 use ant_networking::target_arch::Instant;
 #[cfg(feature = "open-metrics")]
 use ant_networking::MetricsRegistries;
@@ -27,6 +27,10 @@ pub(crate) struct NodeMetricsRecorder {
     /// put record
     put_record_ok: Family<PutRecordOk, Counter>,
     put_record_err: Counter,
+    put_record_rejected: Family<PutRecordRejected, Counter>,
+
+    // the price this node currently quotes for storing a record
+    pub(crate) store_cost: Gauge,
 
     /// replication
     replication_triggered: Counter,
@@ -50,6 +54,11 @@ struct PutRecordOk {
     record_type: RecordType,
 }
 
+#[derive(EncodeLabelSet, Hash, Clone, Eq, PartialEq, Debug)]
+struct PutRecordRejected {
+    reason: PutRejectionReason,
+}
+
 #[derive(EncodeLabelValue, Hash, Clone, Eq, PartialEq, Debug)]
 enum RecordType {
     Chunk,
@@ -85,6 +94,19 @@ impl NodeMetricsRecorder {
             "Number of errors during record PUTs",
             put_record_err.clone(),
         );
+        let put_record_rejected = Family::default();
+        sub_registry.register(
+            "put_record_rejected",
+            "Number of rejected record PUTs, by reason",
+            put_record_rejected.clone(),
+        );
+
+        let store_cost = Gauge::default();
+        sub_registry.register(
+            "store_cost",
+            "The price in atto tokens this node currently quotes for storing a record",
+            store_cost.clone(),
+        );
 
         let replication_triggered = Counter::default();
         sub_registry.register(
@@ -139,6 +161,8 @@ impl NodeMetricsRecorder {
         Self {
             put_record_ok,
             put_record_err,
+            put_record_rejected,
+            store_cost,
             replication_triggered,
             replication_keys_to_fetch,
             peer_added_to_routing_table,
@@ -180,8 +204,12 @@ impl NodeMetricsRecorder {
                     .inc();
             }
 
-            Marker::RecordRejected(_, _) => {
+            Marker::RecordRejected(_, err) => {
                 let _ = self.put_record_err.inc();
+                let _ = self
+                    .put_record_rejected
+                    .get_or_create(&PutRecordRejected { reason: err.into() })
+                    .inc();
             }
 
             Marker::IntervalReplicationTriggered => {
@@ -11,13 +11,14 @@ use super::{
 };
 #[cfg(feature = "open-metrics")]
 use crate::metrics::NodeMetricsRecorder;
-use crate::RunningNode;
+use crate::{store_stats::PutOutcomes, RunningNode};
 use ant_bootstrap::BootstrapCacheStore;
 use ant_evm::RewardsAddress;
 #[cfg(feature = "open-metrics")]
 use ant_networking::MetricsRegistries;
 use ant_networking::{
-    target_arch::sleep, Instant, Network, NetworkBuilder, NetworkEvent, NodeIssue, SwarmDriver,
+    target_arch::sleep, Instant, IpMode, Network, NetworkBuilder, NetworkEvent, NodeIssue,
+    SwarmDriver,
 };
 use ant_protocol::{
     convert_distance_to_u256,
@@ -62,6 +63,9 @@ const STORE_CHALLENGE_INTERVAL_MAX_S: u64 = 7200;
 /// Interval to update the nodes uptime metric
 const UPTIME_METRICS_UPDATE_INTERVAL: Duration = Duration::from_secs(10);
 
+/// Interval to update the store cost metric. Each update queries the payment vault contract.
+const STORE_COST_METRICS_UPDATE_INTERVAL: Duration = Duration::from_secs(600);
+
 /// Interval to clean up unrelevant records
 const UNRELEVANT_RECORDS_CLEANUP_INTERVAL: Duration = Duration::from_secs(3600);
 
@@ -94,6 +98,7 @@ pub struct NodeBuilder {
     metrics_server_port: Option<u16>,
     /// Enable hole punching for nodes connecting from home networks.
     is_behind_home_network: bool,
+    ip_mode: IpMode,
     #[cfg(feature = "upnp")]
     upnp: bool,
 }
@@ -122,6 +127,7 @@ impl NodeBuilder {
             #[cfg(feature = "open-metrics")]
             metrics_server_port: None,
             is_behind_home_network: false,
+            ip_mode: IpMode::default(),
             #[cfg(feature = "upnp")]
             upnp,
         }
@@ -148,6 +154,11 @@ impl NodeBuilder {
         self.is_behind_home_network = is_behind_home_network;
     }
 
+    /// Set the IP families to listen on, and which of the node's addresses are advertised
+    pub fn ip_mode(&mut self, ip_mode: IpMode) {
+        self.ip_mode = ip_mode;
+    }
+
     /// Asynchronously runs a new node instance, setting up the swarm driver,
     /// creating a data storage, and handling network events. Returns the
     /// created `RunningNode` which contains a `NodeEventsChannel` for listening
@@ -177,6 +188,7 @@ impl NodeBuilder {
         };
 
         network_builder.listen_addr(self.addr);
+        network_builder.ip_mode(self.ip_mode);
         #[cfg(feature = "open-metrics")]
         network_builder.metrics_server_port(self.metrics_server_port);
         network_builder.is_behind_home_network(self.is_behind_home_network);
@@ -190,6 +202,7 @@ impl NodeBuilder {
         let (network, network_event_receiver, swarm_driver) =
             network_builder.build_node(self.root_dir.clone())?;
         let node_events_channel = NodeEventsChannel::default();
+        let put_outcomes = PutOutcomes::default();
 
         let node = NodeInner {
             network: network.clone(),
@@ -198,7 +211,8 @@ impl NodeBuilder {
             reward_address: self.evm_address,
             #[cfg(feature = "open-metrics")]
             metrics_recorder,
-            evm_network: self.evm_network,
+            evm_network: self.evm_network.clone(),
+            put_outcomes: put_outcomes.clone(),
         };
         let node = Node {
             inner: Arc::new(node),
@@ -208,6 +222,9 @@ impl NodeBuilder {
             node_events_channel,
             root_dir_path: self.root_dir,
             rewards_address: self.evm_address,
+            evm_network: self.evm_network,
+            put_outcomes,
+            ip_mode: self.ip_mode,
         };
 
         // Run the node
@@ -236,6 +253,7 @@ struct NodeInner {
     metrics_recorder: Option<NodeMetricsRecorder>,
     reward_address: RewardsAddress,
     evm_network: EvmNetwork,
+    put_outcomes: PutOutcomes,
 }
 
 impl Node {
@@ -297,6 +315,9 @@ impl Node {
                 tokio::time::interval(UNRELEVANT_RECORDS_CLEANUP_INTERVAL);
             let _ = irrelevant_records_cleanup_interval.tick().await; // first tick completes immediately
 
+            let mut store_cost_metrics_update_interval =
+                tokio::time::interval(STORE_COST_METRICS_UPDATE_INTERVAL);
+
             // use a random neighbour storage challenge ticker to ensure
             // neighbours do not carryout challenges at the same time
             let storage_challenge_interval: u64 =
@@ -363,6 +384,24 @@ impl Node {
                             let _ = metrics_recorder.uptime.set(metrics_recorder.started_instant.elapsed().as_secs() as i64);
                         }
                     }
+                    _ = store_cost_metrics_update_interval.tick() => {
+                        #[cfg(feature = "open-metrics")]
+                        if let Some(metrics_recorder) = self.metrics_recorder() {
+                            let metrics_recorder = metrics_recorder.clone();
+                            let network = self.network().clone();
+                            let evm_network = self.evm_network().clone();
+                            let put_outcomes = self.inner.put_outcomes.clone();
+
+                            let _handle = spawn(async move {
+                                match crate::store_stats::store_cost_info(&network, &evm_network, &put_outcomes).await {
+                                    Ok(info) => {
+                                        let _ = metrics_recorder.store_cost.set(info.store_cost.try_into().unwrap_or(i64::MAX));
+                                    }
+                                    Err(err) => warn!("Failed to update the store cost metric: {err}"),
+                                }
+                            });
+                        }
+                    }
                     _ = irrelevant_records_cleanup_interval.tick() => {
                         let network = self.network().clone();
 
@@ -503,9 +542,13 @@ impl Node {
                 let _handle = spawn(async move {
                     let key = PrettyPrintRecordKey::from(&record.key).into_owned();
                     match self_clone.validate_and_store_record(record).await {
-                        Ok(()) => debug!("UnverifiedRecord {key} has been stored"),
+                        Ok(()) => {
+                            debug!("UnverifiedRecord {key} has been stored");
+                            self_clone.inner.put_outcomes.record(Ok(()));
+                        }
                         Err(err) => {
                             self_clone.record_metrics(Marker::RecordRejected(&key, &err));
+                            self_clone.inner.put_outcomes.record(Err((&err).into()));
                         }
                     }
                 });
@@ -646,18 +646,14 @@ impl Node {
         let self_peer_id = self.network().peer_id();
         if !payment.verify_for(self_peer_id) {
             warn!("Payment is not valid for record {pretty_key}");
-            return Err(Error::InvalidRequest(format!(
-                "Payment is not valid for record {pretty_key}"
-            )));
+            return Err(Error::InvalidPayment(pretty_key));
         }
         debug!("Payment is valid for record {pretty_key}");
 
         // verify quote expiration
         if payment.has_expired() {
             warn!("Payment quote has expired for record {pretty_key}");
-            return Err(Error::InvalidRequest(format!(
-                "Payment quote has expired for record {pretty_key}"
-            )));
+            return Err(Error::QuoteExpired(address.clone()));
         }
 
         // verify the claimed payees are all known to us within the certain range.
@@ -665,9 +661,7 @@ impl Node {
         let mut payees = payment.payees();
         payees.retain(|peer_id| !closest_k_peers.contains(peer_id));
         if !payees.is_empty() {
-            return Err(Error::InvalidRequest(format!(
-                "Payment quote has out-of-range payees {payees:?}"
-            )));
+            return Err(Error::PayeesOutOfRange(payees));
         }
 
         let owned_payment_quotes = payment
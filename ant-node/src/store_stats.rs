@@ -0,0 +1,277 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::error::{Error, Result};
+use ant_evm::{payment_vault::get_market_price, Amount, EvmNetwork, QuotingMetrics};
+use ant_networking::Network;
+use ant_protocol::NetworkAddress;
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+/// The period over which the outcomes of recent record PUTs are reported.
+pub const RECENT_PUTS_WINDOW: Duration = Duration::from_secs(3600);
+
+/// Upper bound on the number of PUT outcomes kept, so a flood of PUTs can't grow it unbounded.
+const MAX_RECENT_PUTS: usize = 10_000;
+
+/// Number of points, evenly spread from empty to full, of the reported store cost curve.
+const STORE_COST_CURVE_POINTS: usize = 5;
+
+/// Why a record PUT to this node was rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "open-metrics",
+    derive(prometheus_client::encoding::EncodeLabelValue)
+)]
+pub enum PutRejectionReason {
+    /// The record required a payment, but was sent without one.
+    MissingPayment,
+    /// The payment isn't for this node, or its quotes or amounts are invalid.
+    InvalidPayment,
+    /// The quote the payment was made for has expired.
+    QuoteExpired,
+    /// The payment was made to nodes this node doesn't consider close to the record.
+    PayeesOutOfRange,
+    /// The payment could not be verified on chain.
+    PaymentVerificationFailed,
+    /// The record itself is invalid, e.g. its key doesn't match its content.
+    InvalidRecord,
+    /// A newer version of the record is already stored.
+    OutdatedRecord,
+    /// Any other failure, e.g. a network or storage error.
+    Other,
+}
+
+impl From<&Error> for PutRejectionReason {
+    fn from(err: &Error) -> Self {
+        match err {
+            Error::InvalidPutWithoutPayment(_) => Self::MissingPayment,
+            Error::InvalidPayment(_)
+            | Error::InvalidQuoteContent
+            | Error::InvalidQuoteSignature
+            | Error::NoPaymentToOurNode(_)
+            | Error::NoNetworkRoyaltiesPayment(_)
+            | Error::PaymentInsufficientAmount { .. }
+            | Error::ReusedPayment
+            | Error::Transfers(_) => Self::InvalidPayment,
+            Error::QuoteExpired(_) => Self::QuoteExpired,
+            Error::PayeesOutOfRange(_) => Self::PayeesOutOfRange,
+            Error::EvmNetwork(_) => Self::PaymentVerificationFailed,
+            Error::UnexpectedRecordWithPayment(_)
+            | Error::RecordKeyMismatch
+            | Error::InvalidScratchpadSignature
+            | Error::Register(_) => Self::InvalidRecord,
+            Error::IgnoringOutdatedScratchpadPut => Self::OutdatedRecord,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl fmt::Display for PutRejectionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// The outcome of the record PUTs received over the last [`RECENT_PUTS_WINDOW`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RecentPuts {
+    /// Number of PUTs that were validated and stored.
+    pub accepted: usize,
+    /// Number of PUTs that were rejected, per reason.
+    pub rejected: BTreeMap<PutRejectionReason, usize>,
+}
+
+/// When a PUT was received, and whether it was accepted.
+type PutOutcome = (Instant, Result<(), PutRejectionReason>);
+
+/// A time window of the outcomes of the record PUTs received by the node.
+///
+/// Clones share the same outcomes.
+#[derive(Clone, Default)]
+pub(crate) struct PutOutcomes(Arc<Mutex<VecDeque<PutOutcome>>>);
+
+impl PutOutcomes {
+    pub(crate) fn record(&self, outcome: Result<(), PutRejectionReason>) {
+        self.record_at(Instant::now(), outcome);
+    }
+
+    pub(crate) fn recent(&self) -> RecentPuts {
+        self.recent_at(Instant::now())
+    }
+
+    fn record_at(&self, at: Instant, outcome: Result<(), PutRejectionReason>) {
+        let mut outcomes = self.lock();
+        outcomes.push_back((at, outcome));
+        if outcomes.len() > MAX_RECENT_PUTS {
+            let _ = outcomes.pop_front();
+        }
+        Self::prune(&mut outcomes, at);
+    }
+
+    fn recent_at(&self, now: Instant) -> RecentPuts {
+        let mut outcomes = self.lock();
+        Self::prune(&mut outcomes, now);
+
+        let mut recent = RecentPuts::default();
+        for (_, outcome) in outcomes.iter() {
+            match outcome {
+                Ok(()) => recent.accepted += 1,
+                Err(reason) => *recent.rejected.entry(*reason).or_default() += 1,
+            }
+        }
+        recent
+    }
+
+    fn prune(outcomes: &mut VecDeque<PutOutcome>, now: Instant) {
+        while let Some((at, _)) = outcomes.front() {
+            if now.saturating_duration_since(*at) <= RECENT_PUTS_WINDOW {
+                break;
+            }
+            let _ = outcomes.pop_front();
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, VecDeque<PutOutcome>> {
+        // The outcomes are always left in a valid state, so a panic while holding the lock doesn't matter.
+        self.0.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+/// The cost of storing a record at this node, and what goes into it.
+#[derive(Clone, Debug)]
+pub struct StoreCostInfo {
+    /// The price this node currently quotes for storing a record.
+    pub store_cost: Amount,
+    /// The prices this node would quote as it fills up, as `(records stored, price)` pairs,
+    /// from empty up to `max_records`.
+    pub store_cost_curve: Vec<(usize, Amount)>,
+    /// Number of records stored that this node is responsible for.
+    pub records_stored: usize,
+    /// Maximum number of records this node will store.
+    pub max_records: usize,
+    /// Number of payments this node has received.
+    pub received_payment_count: usize,
+    /// The outcome of the record PUTs received over the last [`RECENT_PUTS_WINDOW`].
+    pub recent_puts: RecentPuts,
+}
+
+/// Obtain the current store cost of the node from the payment vault, along with its curve and the
+/// recent PUT outcomes.
+pub(crate) async fn store_cost_info(
+    network: &Network,
+    evm_network: &EvmNetwork,
+    put_outcomes: &PutOutcomes,
+) -> Result<StoreCostInfo> {
+    let key = NetworkAddress::from_peer(network.peer_id()).to_record_key();
+    let (quoting_metrics, _) = network.get_local_quoting_metrics(key).await?;
+
+    let curve_records = store_cost_curve_records(quoting_metrics.max_records);
+    let mut metrics = vec![quoting_metrics.clone()];
+    metrics.extend(curve_records.iter().map(|records| QuotingMetrics {
+        close_records_stored: *records,
+        ..quoting_metrics.clone()
+    }));
+
+    // A single call prices the current metrics and every point of the curve.
+    let prices = get_market_price(evm_network, metrics)
+        .await
+        .map_err(|err| Error::EvmNetwork(format!("Failed to get the market price: {err}")))?;
+    let Some((store_cost, curve_prices)) = prices.split_first() else {
+        return Err(Error::EvmNetwork(
+            "The market price was not returned".to_string(),
+        ));
+    };
+
+    Ok(StoreCostInfo {
+        store_cost: *store_cost,
+        store_cost_curve: curve_records
+            .into_iter()
+            .zip(curve_prices.iter().copied())
+            .collect(),
+        records_stored: quoting_metrics.close_records_stored,
+        max_records: quoting_metrics.max_records,
+        received_payment_count: quoting_metrics.received_payment_count,
+        recent_puts: put_outcomes.recent(),
+    })
+}
+
+/// The record counts at which the store cost curve is sampled.
+fn store_cost_curve_records(max_records: usize) -> Vec<usize> {
+    (0..STORE_COST_CURVE_POINTS)
+        .map(|point| max_records * point / (STORE_COST_CURVE_POINTS - 1))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recent_puts_only_count_outcomes_within_the_window() {
+        let outcomes = PutOutcomes::default();
+        let start = Instant::now();
+
+        outcomes.record_at(start, Err(PutRejectionReason::QuoteExpired));
+        outcomes.record_at(start + Duration::from_secs(60), Ok(()));
+        outcomes.record_at(
+            start + Duration::from_secs(120),
+            Err(PutRejectionReason::PayeesOutOfRange),
+        );
+        outcomes.record_at(
+            start + Duration::from_secs(180),
+            Err(PutRejectionReason::PayeesOutOfRange),
+        );
+
+        let recent = outcomes.recent_at(start + RECENT_PUTS_WINDOW + Duration::from_secs(90));
+        assert_eq!(recent.accepted, 0);
+        assert_eq!(
+            recent.rejected,
+            BTreeMap::from([(PutRejectionReason::PayeesOutOfRange, 2)])
+        );
+    }
+
+    #[test]
+    fn recent_puts_are_bounded() {
+        let outcomes = PutOutcomes::default();
+        let now = Instant::now();
+        for _ in 0..MAX_RECENT_PUTS + 10 {
+            outcomes.record_at(now, Ok(()));
+        }
+
+        assert_eq!(outcomes.recent_at(now).accepted, MAX_RECENT_PUTS);
+    }
+
+    #[test]
+    fn store_cost_curve_spans_empty_to_full() {
+        assert_eq!(
+            store_cost_curve_records(4096),
+            vec![0, 1024, 2048, 3072, 4096]
+        );
+    }
+
+    #[test]
+    fn payment_errors_map_to_rejection_reasons() {
+        assert_eq!(
+            PutRejectionReason::from(&Error::PayeesOutOfRange(vec![])),
+            PutRejectionReason::PayeesOutOfRange
+        );
+        assert_eq!(
+            PutRejectionReason::from(&Error::EvmNetwork("rpc".to_string())),
+            PutRejectionReason::PaymentVerificationFailed
+        );
+        assert_eq!(
+            PutRejectionReason::from(&Error::FailedToGetNodePort),
+            PutRejectionReason::Other
+        );
+    }
+}
@@ -23,7 +23,68 @@ pub struct DaemonServiceData {
     pub pid: Option<u32>,
     pub service_name: String,
     pub status: ServiceStatus,
+    #[serde(default)]
+    pub textfile: DaemonTextfileOptions,
     pub version: String,
+    #[serde(default)]
+    pub watchdog: DaemonWatchdogOptions,
+}
+
+/// The options for the textfile the daemon writes for the Prometheus node exporter, supplied to
+/// `antctld` as arguments.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DaemonTextfileOptions {
+    /// The interval in seconds; the daemon uses its default if not set.
+    pub textfile_interval: Option<u64>,
+    pub textfile_path: Option<PathBuf>,
+}
+
+impl DaemonTextfileOptions {
+    pub fn push_arguments(&self, args: &mut Vec<OsString>) {
+        if let Some(textfile_interval) = self.textfile_interval {
+            args.push(OsString::from("--textfile-interval"));
+            args.push(OsString::from(textfile_interval.to_string()));
+        }
+        if let Some(textfile_path) = &self.textfile_path {
+            args.push(OsString::from("--textfile-path"));
+            args.push(OsString::from(textfile_path));
+        }
+    }
+}
+
+/// The options for the resource watchdog of the daemon, supplied to `antctld` as arguments.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DaemonWatchdogOptions {
+    pub max_cpu_percent: Option<f32>,
+    pub max_memory_mb: Option<u64>,
+    pub max_pending_requests: Option<usize>,
+    pub restart_on_resource_limit: bool,
+    /// The interval in seconds; the daemon uses its default if not set.
+    pub watchdog_interval: Option<u64>,
+}
+
+impl DaemonWatchdogOptions {
+    pub fn push_arguments(&self, args: &mut Vec<OsString>) {
+        if let Some(max_cpu_percent) = self.max_cpu_percent {
+            args.push(OsString::from("--max-cpu-percent"));
+            args.push(OsString::from(max_cpu_percent.to_string()));
+        }
+        if let Some(max_memory_mb) = self.max_memory_mb {
+            args.push(OsString::from("--max-memory-mb"));
+            args.push(OsString::from(max_memory_mb.to_string()));
+        }
+        if let Some(max_pending_requests) = self.max_pending_requests {
+            args.push(OsString::from("--max-pending-requests"));
+            args.push(OsString::from(max_pending_requests.to_string()));
+        }
+        if self.restart_on_resource_limit {
+            args.push(OsString::from("--restart-on-resource-limit"));
+        }
+        if let Some(watchdog_interval) = self.watchdog_interval {
+            args.push(OsString::from("--watchdog-interval"));
+            args.push(OsString::from(watchdog_interval.to_string()));
+        }
+    }
 }
 
 pub struct DaemonService<'a> {
@@ -58,13 +119,16 @@ impl ServiceStateActions for DaemonService<'_> {
                 Error::DaemonEndpointNotSet
             })
             .map(|e| (e.ip().to_string(), e.port().to_string()))?;
+        let mut args = vec![
+            OsString::from("--port"),
+            OsString::from(port),
+            OsString::from("--address"),
+            OsString::from(address),
+        ];
+        self.service_data.textfile.push_arguments(&mut args);
+        self.service_data.watchdog.push_arguments(&mut args);
         let install_ctx = ServiceInstallCtx {
-            args: vec![
-                OsString::from("--port"),
-                OsString::from(port),
-                OsString::from("--address"),
-                OsString::from(address),
-            ],
+            args,
             autostart: true,
             contents: None,
             environment: None,
@@ -42,6 +42,10 @@ pub enum Error {
     RpcNodeUpdateError(String),
     #[error("Could not obtain record addresses through RPC: {0}")]
     RpcRecordAddressError(String),
+    #[error("Could not obtain store cost info through RPC: {0}")]
+    RpcStoreCostInfoError(String),
+    #[error("Could not obtain listener status through RPC: {0}")]
+    RpcListenerStatusError(String),
     #[error("Could not find process at '{0}'")]
     ServiceProcessNotFound(String),
     #[error("The service '{0}' does not exists and cannot be removed.")]
@@ -31,7 +31,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-pub use daemon::{DaemonService, DaemonServiceData};
+pub use daemon::{DaemonService, DaemonServiceData, DaemonTextfileOptions, DaemonWatchdogOptions};
 pub use error::{Error, Result};
 pub use faucet::{FaucetService, FaucetServiceData};
 pub use node::{NodeService, NodeServiceData};
@@ -7,7 +7,7 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::{error::Result, rpc::RpcActions, ServiceStateActions, ServiceStatus, UpgradeOptions};
-use ant_bootstrap::PeersArgs;
+use ant_bootstrap::{PeersArgs, ProxyConfig};
 use ant_evm::{AttoTokens, EvmNetwork, RewardsAddress};
 use ant_logging::LogFormat;
 use ant_protocol::get_port_from_multiaddr;
@@ -433,4 +433,8 @@ pub fn push_arguments_from_peers_args(peers_args: &PeersArgs, args: &mut Vec<OsS
         args.push(OsString::from("--bootstrap-cache-dir"));
         args.push(OsString::from(path.to_string_lossy().to_string()));
     }
+    if peers_args.proxy != ProxyConfig::System {
+        args.push(OsString::from("--proxy"));
+        args.push(OsString::from(peers_args.proxy.to_string()));
+    }
 }
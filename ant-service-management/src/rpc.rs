@@ -9,14 +9,15 @@
 use crate::error::{Error, Result};
 use ant_protocol::{
     antnode_proto::{
-        ant_node_client::AntNodeClient, NetworkInfoRequest, NodeInfoRequest,
-        RecordAddressesRequest, RestartRequest, StopRequest, UpdateLogLevelRequest, UpdateRequest,
+        ant_node_client::AntNodeClient, ListenerStatusRequest, NetworkInfoRequest, NodeInfoRequest,
+        RecordAddressesRequest, RestartRequest, StopRequest, StoreCostInfoRequest,
+        UpdateLogLevelRequest, UpdateRequest,
     },
     CLOSE_GROUP_SIZE,
 };
 use async_trait::async_trait;
 use libp2p::{kad::RecordKey, Multiaddr, PeerId};
-use std::{net::SocketAddr, path::PathBuf, str::FromStr};
+use std::{collections::BTreeMap, net::SocketAddr, path::PathBuf, str::FromStr};
 use tokio::time::Duration;
 use tonic::Request;
 use tracing::error;
@@ -43,11 +44,48 @@ pub struct RecordAddress {
     pub key: RecordKey,
 }
 
+/// The store cost of a node and the outcome of the record PUTs it received recently.
+///
+/// Prices are in atto tokens.
+#[derive(Debug, Clone)]
+pub struct StoreCostInfo {
+    pub store_cost: String,
+    /// `(records stored, price)` pairs, from empty up to `max_records`.
+    pub store_cost_curve: Vec<(u64, String)>,
+    pub records_stored: u64,
+    pub max_records: u64,
+    pub received_payment_count: u64,
+    pub recent_puts_window: Duration,
+    pub recent_puts_accepted: u64,
+    /// Number of rejected PUTs, per reason.
+    pub recent_puts_rejected: BTreeMap<String, u64>,
+}
+
+/// The listeners and advertised addresses of a node for an IP family.
+#[derive(Debug, Clone)]
+pub struct IpFamilyListenerStatus {
+    /// `IPv4` or `IPv6`.
+    pub family: String,
+    /// One of `disabled`, `not listening`, `local only`, `listening` or `advertised`.
+    pub state: String,
+    pub listeners: Vec<Multiaddr>,
+    pub external_addresses: Vec<Multiaddr>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ListenerStatus {
+    /// The IP mode the node was started with, e.g. `dual-stack`.
+    pub ip_mode: String,
+    pub families: Vec<IpFamilyListenerStatus>,
+}
+
 #[async_trait]
 pub trait RpcActions: Sync {
     async fn node_info(&self) -> Result<NodeInfo>;
     async fn network_info(&self) -> Result<NetworkInfo>;
     async fn record_addresses(&self) -> Result<Vec<RecordAddress>>;
+    async fn store_cost_info(&self) -> Result<StoreCostInfo>;
+    async fn listener_status(&self) -> Result<ListenerStatus>;
     async fn node_restart(&self, delay_millis: u64, retain_peer_id: bool) -> Result<()>;
     async fn node_stop(&self, delay_millis: u64) -> Result<()>;
     async fn node_update(&self, delay_millis: u64) -> Result<()>;
@@ -188,6 +226,69 @@ impl RpcActions for RpcClient {
         Ok(record_addresses)
     }
 
+    async fn store_cost_info(&self) -> Result<StoreCostInfo> {
+        let mut client = self.connect_with_retry().await?;
+        let response = client
+            .store_cost_info(Request::new(StoreCostInfoRequest {}))
+            .await
+            .map_err(|e| {
+                error!("Could not obtain store cost info through RPC: {e:?}");
+                Error::RpcStoreCostInfoError(e.to_string())
+            })?;
+        let info = response.into_inner();
+        Ok(StoreCostInfo {
+            store_cost: info.store_cost,
+            store_cost_curve: info
+                .store_cost_curve
+                .into_iter()
+                .map(|point| (point.records_stored, point.store_cost))
+                .collect(),
+            records_stored: info.records_stored,
+            max_records: info.max_records,
+            received_payment_count: info.received_payment_count,
+            recent_puts_window: Duration::from_secs(info.recent_puts_window_secs),
+            recent_puts_accepted: info.recent_puts_accepted,
+            recent_puts_rejected: info.recent_puts_rejected.into_iter().collect(),
+        })
+    }
+
+    async fn listener_status(&self) -> Result<ListenerStatus> {
+        let mut client = self.connect_with_retry().await?;
+        let response = client
+            .listener_status(Request::new(ListenerStatusRequest {}))
+            .await
+            .map_err(|e| {
+                error!("Could not obtain listener status through RPC: {e:?}");
+                Error::RpcListenerStatusError(e.to_string())
+            })?;
+        let status = response.into_inner();
+
+        let mut families = Vec::new();
+        for family in status.families {
+            let listeners = family
+                .listeners
+                .iter()
+                .map(|addr| Multiaddr::from_str(addr))
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            let external_addresses = family
+                .external_addresses
+                .iter()
+                .map(|addr| Multiaddr::from_str(addr))
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            families.push(IpFamilyListenerStatus {
+                family: family.family,
+                state: family.state,
+                listeners,
+                external_addresses,
+            });
+        }
+
+        Ok(ListenerStatus {
+            ip_mode: status.ip_mode,
+            families,
+        })
+    }
+
     async fn node_restart(&self, delay_millis: u64, retain_peer_id: bool) -> Result<()> {
         let mut client = self.connect_with_retry().await?;
         let _response = client
@@ -9,17 +9,22 @@
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::sync::LazyLock;
 
+use ant_evm::payment_vault::{self, verify_data_payment};
 use ant_evm::{Amount, EvmWalletError};
 use ant_networking::NetworkError;
-use ant_protocol::storage::Chunk;
+use ant_protocol::storage::{Chunk, ChunkAddress};
 use ant_protocol::NetworkAddress;
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 use xor_name::XorName;
 
-use crate::client::payment::PaymentOption;
+use crate::client::idempotency::{
+    pay_recorded, IdempotencyKey, IdempotencyRecord, IdempotencyStoreError,
+};
+use crate::client::payment::{PaymentOption, Receipt};
 use crate::client::{ClientEvent, UploadSummary};
 use crate::{self_encryption::encrypt, Client};
+use self_encryption::DataMap;
 
 pub mod public;
 
@@ -60,6 +65,129 @@ pub static CHUNK_DOWNLOAD_BATCH_SIZE: LazyLock<usize> = LazyLock::new(|| {
 /// Number of retries to upload chunks.
 pub(crate) const RETRY_ATTEMPTS: usize = 3;
 
+/// Minimum number of chunk uploads in an operation before the circuit breaker can trip.
+pub(crate) const CIRCUIT_BREAKER_MIN_UPLOADS: usize = 10;
+
+/// Retry behaviour for the chunk uploads of a single put operation.
+///
+/// The default retries each failed chunk up to [`RETRY_ATTEMPTS`] times, without an overall budget or circuit breaker.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChunkRetryConfig {
+    /// Maximum number of times the failed chunks are retried.
+    pub max_attempts: usize,
+    /// Maximum number of chunk re-uploads for the whole operation, across all chunks.
+    ///
+    /// A retry round is only started if the remaining budget covers all the chunks that failed.
+    /// `None` means retries are only limited by `max_attempts`.
+    pub retry_budget: Option<usize>,
+    /// Abort the operation without further retries once the ratio of failed uploads to attempted uploads
+    /// exceeds this threshold (between `0.0` and `1.0`).
+    ///
+    /// Only takes effect once at least `circuit_breaker_min_uploads` uploads were attempted, so small
+    /// uploads are still retried. `None` disables the circuit breaker.
+    pub max_failure_ratio: Option<f64>,
+    /// Minimum number of attempted uploads before the circuit breaker can trip.
+    pub circuit_breaker_min_uploads: usize,
+}
+
+impl Default for ChunkRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: RETRY_ATTEMPTS,
+            retry_budget: None,
+            max_failure_ratio: None,
+            circuit_breaker_min_uploads: CIRCUIT_BREAKER_MIN_UPLOADS,
+        }
+    }
+}
+
+/// Progress of the chunk uploads of a single put operation, used to decide whether to retry.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ChunkRetryState {
+    /// The upload round that just finished, starting at 1.
+    pub attempt: usize,
+    /// Number of chunk re-uploads done so far.
+    pub retries: usize,
+    /// Number of chunk uploads attempted so far, including retries.
+    pub uploads: usize,
+    /// Number of chunk uploads that failed so far, including retries.
+    pub failures: usize,
+}
+
+impl ChunkRetryConfig {
+    /// Returns why the `failing` chunks should not be retried, or `None` if another round may be started.
+    pub(crate) fn stop_reason(
+        &self,
+        state: &ChunkRetryState,
+        failing: usize,
+    ) -> Option<RetryStopReason> {
+        if let Some(max_ratio) = self.max_failure_ratio {
+            if state.uploads >= self.circuit_breaker_min_uploads && state.uploads > 0 {
+                let failure_ratio = state.failures as f64 / state.uploads as f64;
+                if failure_ratio > max_ratio {
+                    return Some(RetryStopReason::CircuitBroken { failure_ratio });
+                }
+            }
+        }
+
+        if state.attempt > self.max_attempts {
+            return Some(RetryStopReason::AttemptsExhausted);
+        }
+
+        if let Some(budget) = self.retry_budget {
+            if state.retries + failing > budget {
+                return Some(RetryStopReason::BudgetExhausted);
+            }
+        }
+
+        None
+    }
+}
+
+/// The reason chunk uploads were not retried any further.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RetryStopReason {
+    /// The failed chunks were retried [`ChunkRetryConfig::max_attempts`] times.
+    AttemptsExhausted,
+    /// Retrying the failed chunks would exceed [`ChunkRetryConfig::retry_budget`].
+    BudgetExhausted,
+    /// The failure ratio of the operation exceeded [`ChunkRetryConfig::max_failure_ratio`].
+    CircuitBroken { failure_ratio: f64 },
+}
+
+impl std::fmt::Display for RetryStopReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RetryStopReason::AttemptsExhausted => write!(f, "retry attempts exhausted"),
+            RetryStopReason::BudgetExhausted => write!(f, "retry budget exhausted"),
+            RetryStopReason::CircuitBroken { failure_ratio } => write!(
+                f,
+                "aborted early, {:.0}% of uploads failed",
+                failure_ratio * 100.0
+            ),
+        }
+    }
+}
+
+/// The chunks that could not be uploaded during a put operation.
+#[derive(Debug, thiserror::Error)]
+#[error("Failed to upload {} out of {total} chunks: {reason}", failed.len())]
+pub struct ChunkUploadFailures {
+    /// The chunks that failed, along with the last error for each of them.
+    pub failed: Vec<(ChunkAddress, PutError)>,
+    /// Total number of chunks the operation had to upload.
+    pub total: usize,
+    /// Why the failed chunks were not retried any further.
+    pub reason: RetryStopReason,
+}
+
+impl ChunkUploadFailures {
+    /// The addresses of the chunks that failed to upload.
+    pub fn addresses(&self) -> Vec<ChunkAddress> {
+        self.failed.iter().map(|(addr, _)| *addr).collect()
+    }
+}
+
 /// Raw Data Address (points to a DataMap)
 pub type DataAddr = XorName;
 /// Raw Chunk Address (points to a [`Chunk`])
@@ -86,6 +214,23 @@ pub enum PutError {
     PaymentUnexpectedlyInvalid(NetworkAddress),
     #[error("The payment proof contains no payees.")]
     PayeesMissing,
+    #[error(transparent)]
+    ChunkUploads(#[from] ChunkUploadFailures),
+    #[error("The idempotency key {0} was already used to upload different data")]
+    IdempotencyKeyReused(IdempotencyKey),
+    #[error(transparent)]
+    IdempotencyStore(#[from] IdempotencyStoreError),
+}
+
+/// How the chunks of an upload are paid for.
+pub(crate) enum UploadPayment {
+    /// An upload with the same idempotency key already completed, so there's nothing to upload.
+    Completed,
+    Paid {
+        receipt: Receipt,
+        /// Whether the receipt was recorded for the idempotency key by an earlier attempt.
+        reused: bool,
+    },
 }
 
 /// Errors that can occur during the pay operation.
@@ -133,7 +278,7 @@ pub enum CostError {
 
 /// Private data on the network can be accessed with this
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct DataMapChunk(Chunk);
+pub struct DataMapChunk(pub(crate) Chunk);
 
 impl DataMapChunk {
     pub fn to_hex(&self) -> String {
@@ -184,12 +329,50 @@ impl Client {
             "Fetching private data from Data Map {:?}",
             data_map.0.address()
         );
-        let data = self.fetch_from_data_map_chunk(data_map.0.value()).await?;
+        let data_map = self.data_map_private(&data_map).await?;
+        let data = self.fetch_from_data_map(&data_map).await?;
 
         debug!("Successfully fetched a blob of private data from the network");
         Ok(data)
     }
 
+    /// Fetch `len` bytes of a blob of private data from the network, starting at `offset`.
+    ///
+    /// Only the chunks covering the range are fetched, and the data map is cached so that
+    /// subsequent reads of the same data don't need to resolve it again.
+    /// The range is truncated at the end of the data.
+    pub async fn data_get_range(
+        &self,
+        data_map: &DataMapChunk,
+        offset: usize,
+        len: usize,
+    ) -> Result<Bytes, GetError> {
+        info!(
+            "Fetching {len} bytes at offset {offset} of private data from Data Map {:?}",
+            data_map.0.address()
+        );
+        let data_map = self.data_map_private(data_map).await?;
+        let data = self
+            .fetch_range_from_data_map(&data_map, offset, len)
+            .await?;
+
+        debug!("Successfully fetched a range of private data from the network");
+        Ok(data)
+    }
+
+    /// Resolve the data map wrapped in a [`DataMapChunk`], from the cache if possible.
+    async fn data_map_private(&self, data_map: &DataMapChunk) -> Result<DataMap, GetError> {
+        let addr = *data_map.0.name();
+        if let Some(cached) = self.data_map_cache.get(&addr) {
+            debug!("Using the cached data map of {:?}", data_map.0.address());
+            return Ok(cached);
+        }
+
+        let resolved = self.resolve_data_map(data_map.0.value()).await?;
+        self.data_map_cache.insert(addr, resolved.clone());
+        Ok(resolved)
+    }
+
     /// Upload a piece of private data to the network. This data will be self-encrypted.
     /// The [`DataMapChunk`] is not uploaded to the network, keeping the data private.
     ///
@@ -214,6 +397,32 @@ impl Client {
         &self,
         data: Bytes,
         payment_option: PaymentOption,
+    ) -> Result<DataMapChunk, PutError> {
+        self.data_put_with_key(data, payment_option, None).await
+    }
+
+    /// Upload a piece of private data to the network, like [`Client::data_put`], identifying
+    /// the upload with an idempotency key.
+    ///
+    /// The payment is recorded under the key, so retrying with the same key after a failure
+    /// reuses the payment instead of paying again, and retrying after the upload completed
+    /// returns the [`DataMapChunk`] without uploading anything. A key can't be used for
+    /// different data. See [`crate::ClientConfig::idempotency`] for where the records are kept.
+    pub async fn data_put_idempotent(
+        &self,
+        data: Bytes,
+        payment_option: PaymentOption,
+        key: IdempotencyKey,
+    ) -> Result<DataMapChunk, PutError> {
+        self.data_put_with_key(data, payment_option, Some(&key))
+            .await
+    }
+
+    async fn data_put_with_key(
+        &self,
+        data: Bytes,
+        payment_option: PaymentOption,
+        idempotency_key: Option<&IdempotencyKey>,
     ) -> Result<DataMapChunk, PutError> {
         let now = ant_networking::target_arch::Instant::now();
         let (data_map_chunk, chunks) = encrypt(data)?;
@@ -222,36 +431,38 @@ impl Client {
         // Pay for all chunks
         let xor_names: Vec<_> = chunks.iter().map(|chunk| *chunk.name()).collect();
         info!("Paying for {} addresses", xor_names.len());
-        let receipt = self
-            .pay_for_content_addrs(xor_names.into_iter(), payment_option)
-            .await
-            .inspect_err(|err| error!("Error paying for data: {err:?}"))?;
+        let (receipt, reused) = match self
+            .pay_for_upload(
+                *data_map_chunk.name(),
+                xor_names,
+                payment_option,
+                idempotency_key,
+            )
+            .await?
+        {
+            UploadPayment::Completed => return Ok(DataMapChunk(data_map_chunk)),
+            UploadPayment::Paid { receipt, reused } => (receipt, reused),
+        };
 
         // Upload the chunks with the payments
         debug!("Uploading {} chunks", chunks.len());
 
-        let mut failed_uploads = self
-            .upload_chunks_with_retries(chunks.iter().collect(), &receipt)
-            .await;
-
-        // Return the last chunk upload error
-        if let Some(last_chunk_fail) = failed_uploads.pop() {
-            tracing::error!(
-                "Error uploading chunk ({:?}): {:?}",
-                last_chunk_fail.0.address(),
-                last_chunk_fail.1
-            );
-            return Err(last_chunk_fail.1);
-        }
+        self.upload_chunks_with_retries(chunks.iter().collect(), &receipt)
+            .await?;
+        self.record_upload_completed(*data_map_chunk.name(), receipt.clone(), idempotency_key);
 
         let record_count = chunks.len();
 
         // Reporting
         if let Some(channel) = self.client_event_sender.as_ref() {
-            let tokens_spent = receipt
-                .values()
-                .map(|(_, cost)| cost.as_atto())
-                .sum::<Amount>();
+            let tokens_spent = if reused {
+                Amount::ZERO
+            } else {
+                receipt
+                    .values()
+                    .map(|(_, cost)| cost.as_atto())
+                    .sum::<Amount>()
+            };
 
             let summary = UploadSummary {
                 record_count,
@@ -264,12 +475,231 @@ impl Client {
 
         Ok(DataMapChunk(data_map_chunk))
     }
+
+    /// Pay for the chunks of the data at `data_addr`, unless a payment was already recorded for
+    /// the idempotency key.
+    ///
+    /// With a key, the quotes and the payments to make are recorded before paying, so a retry
+    /// after paying failed part way, or after a payment timed out but went through, pays for the
+    /// same quotes and only makes the payments that weren't made.
+    pub(crate) async fn pay_for_upload(
+        &self,
+        data_addr: XorName,
+        content_addrs: Vec<XorName>,
+        payment_option: PaymentOption,
+        idempotency_key: Option<&IdempotencyKey>,
+    ) -> Result<UploadPayment, PutError> {
+        let Some(key) = idempotency_key else {
+            let receipt = self
+                .pay_for_content_addrs(content_addrs.into_iter(), payment_option)
+                .await
+                .inspect_err(|err| error!("Error paying for data: {err:?}"))?;
+            return Ok(UploadPayment::Paid {
+                receipt,
+                reused: false,
+            });
+        };
+
+        let (mut record, reused) = match self.idempotency_store.get(key) {
+            Some(record) => {
+                if record.data_addr != data_addr {
+                    return Err(PutError::IdempotencyKeyReused(key.clone()));
+                }
+                if record.completed {
+                    info!("The upload with idempotency key {key} already completed");
+                    return Ok(UploadPayment::Completed);
+                }
+                info!("Reusing the payment recorded for idempotency key {key}");
+                (record, true)
+            }
+            None => {
+                let record = match &payment_option {
+                    PaymentOption::Wallet(_) => {
+                        let quotes = self
+                            .get_store_quotes(content_addrs.into_iter())
+                            .await
+                            .map_err(PayError::from)?;
+                        IdempotencyRecord::new(data_addr, quotes)
+                    }
+                    PaymentOption::Receipt(receipt) => IdempotencyRecord {
+                        data_addr,
+                        receipt: receipt.clone(),
+                        completed: false,
+                        unconfirmed_payments: vec![],
+                    },
+                };
+                // Without a record of what is about to be paid, a retry could pay again
+                self.idempotency_store.insert(key.clone(), record.clone())?;
+                (record, false)
+            }
+        };
+
+        if !record.is_paid() {
+            let wallet = match payment_option {
+                PaymentOption::Wallet(wallet) => wallet,
+                PaymentOption::Receipt(receipt) => {
+                    // The payments were made by other means this time
+                    warn!("Using the given receipt rather than the payments recorded for idempotency key {key}");
+                    return Ok(UploadPayment::Paid {
+                        receipt,
+                        reused: false,
+                    });
+                }
+            };
+            if reused {
+                self.confirm_recorded_payments(&mut record).await?;
+            }
+            record = pay_recorded(
+                &self.idempotency_store,
+                key,
+                record,
+                |payments| async move {
+                    // Make sure nobody else can use the wallet while we are paying
+                    let _lock_guard = wallet.lock().await;
+                    wallet
+                        .pay_for_quotes(payments)
+                        .await
+                        .map_err(|err| (PayError::from(err.0), err.1))
+                },
+            )
+            .await
+            .inspect_err(|err| error!("Error paying for data: {err:?}"))?;
+        }
+
+        Ok(UploadPayment::Paid {
+            receipt: record.receipt,
+            reused,
+        })
+    }
+
+    /// Check which of the unconfirmed payments of the record were made, e.g. by an earlier attempt
+    /// that timed out waiting for a transaction that went through, so they aren't made again.
+    async fn confirm_recorded_payments(
+        &self,
+        record: &mut IdempotencyRecord,
+    ) -> Result<(), PayError> {
+        let unconfirmed = record.unconfirmed_quotes();
+        let mut confirmed = Vec::new();
+        for (proof, _) in record.receipt.values() {
+            let payments: Vec<_> = proof
+                .digest()
+                .into_iter()
+                .filter(|(quote_hash, _, _)| unconfirmed.contains(quote_hash))
+                .collect();
+            if payments.is_empty() {
+                continue;
+            }
+            let quote_hashes: Vec<_> = payments
+                .iter()
+                .map(|(quote_hash, _, _)| *quote_hash)
+                .collect();
+            match verify_data_payment(&self.evm_network, quote_hashes.clone(), payments).await {
+                Ok(_) => confirmed.extend(quote_hashes),
+                Err(payment_vault::error::Error::PaymentInvalid) => {}
+                Err(err) => {
+                    error!("Failed to check the recorded payments: {err}");
+                    return Err(EvmWalletError::from(err).into());
+                }
+            }
+        }
+        if !confirmed.is_empty() {
+            info!(
+                "{} of the recorded payments were already made",
+                confirmed.len()
+            );
+            record.confirm_payments(&confirmed);
+        }
+        Ok(())
+    }
+
+    /// Record that the upload with the idempotency key completed, so a retry doesn't upload again.
+    pub(crate) fn record_upload_completed(
+        &self,
+        data_addr: XorName,
+        receipt: Receipt,
+        idempotency_key: Option<&IdempotencyKey>,
+    ) {
+        let Some(key) = idempotency_key else {
+            return;
+        };
+        let record = IdempotencyRecord {
+            data_addr,
+            receipt,
+            completed: true,
+            unconfirmed_payments: vec![],
+        };
+        if let Err(err) = self.idempotency_store.insert(key.clone(), record) {
+            error!("Failed to record the completed upload for idempotency key {key}: {err}");
+        }
+    }
+
+    /// Forget the upload recorded for an idempotency key, returning whether there was one.
+    ///
+    /// Once forgotten, an upload with the key pays again.
+    pub fn forget_idempotency_key(
+        &self,
+        key: &IdempotencyKey,
+    ) -> Result<bool, IdempotencyStoreError> {
+        self.idempotency_store.remove(key)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_retry_stop_reason() {
+        let config = ChunkRetryConfig {
+            max_attempts: 3,
+            retry_budget: Some(10),
+            max_failure_ratio: Some(0.5),
+            circuit_breaker_min_uploads: 10,
+        };
+        let state = ChunkRetryState {
+            attempt: 1,
+            retries: 0,
+            uploads: 20,
+            failures: 5,
+        };
+        assert_eq!(config.stop_reason(&state, 5), None);
+
+        // Failing chunks no longer fit in the budget
+        assert_eq!(
+            config.stop_reason(&state, 11),
+            Some(RetryStopReason::BudgetExhausted)
+        );
+
+        let state = ChunkRetryState {
+            attempt: 4,
+            ..state
+        };
+        assert_eq!(
+            config.stop_reason(&state, 1),
+            Some(RetryStopReason::AttemptsExhausted)
+        );
+
+        // Too many failures trips the circuit breaker, but only after enough uploads
+        let state = ChunkRetryState {
+            attempt: 1,
+            retries: 0,
+            uploads: 4,
+            failures: 4,
+        };
+        assert_eq!(config.stop_reason(&state, 4), None);
+        let state = ChunkRetryState {
+            uploads: 10,
+            failures: 8,
+            ..state
+        };
+        assert_eq!(
+            config.stop_reason(&state, 8),
+            Some(RetryStopReason::CircuitBroken { failure_ratio: 0.8 })
+        );
+
+        assert_eq!(ChunkRetryConfig::default().stop_reason(&state, 8), None);
+    }
+
     #[test]
     fn test_hex() {
         let data_map = DataMapChunk(Chunk::new(Bytes::from_static(b"hello")));
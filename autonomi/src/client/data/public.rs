@@ -9,7 +9,11 @@
 use bytes::Bytes;
 use libp2p::kad::Quorum;
 use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
+use crate::client::events::UploadProgress;
+use crate::client::idempotency::IdempotencyKey;
 use crate::client::payment::{PaymentOption, Receipt};
 use crate::client::utils::process_tasks_with_max_concurrency;
 use crate::client::{ClientEvent, UploadSummary};
@@ -27,15 +31,47 @@ impl Client {
     /// Fetch a blob of data from the network
     pub async fn data_get_public(&self, addr: DataAddr) -> Result<Bytes, GetError> {
         info!("Fetching data from Data Address: {addr:?}");
-        let data_map_chunk = self.chunk_get(addr).await?;
+        let data_map = self.data_map_public(addr).await?;
+        let data = self.fetch_from_data_map(&data_map).await?;
+
+        debug!("Successfully fetched a blob of data from the network");
+        Ok(data)
+    }
+
+    /// Fetch `len` bytes of a blob of data from the network, starting at `offset`.
+    ///
+    /// Only the chunks covering the range are fetched, and the data map is cached so that
+    /// subsequent reads of the same data don't need to fetch it again.
+    /// The range is truncated at the end of the data.
+    pub async fn data_get_public_range(
+        &self,
+        addr: DataAddr,
+        offset: usize,
+        len: usize,
+    ) -> Result<Bytes, GetError> {
+        info!("Fetching {len} bytes at offset {offset} from Data Address: {addr:?}");
+        let data_map = self.data_map_public(addr).await?;
         let data = self
-            .fetch_from_data_map_chunk(data_map_chunk.value())
+            .fetch_range_from_data_map(&data_map, offset, len)
             .await?;
 
-        debug!("Successfully fetched a blob of data from the network");
+        debug!("Successfully fetched a range of data from the network");
         Ok(data)
     }
 
+    /// Get the data map of the data at the given address, from the cache if possible.
+    async fn data_map_public(&self, addr: DataAddr) -> Result<DataMap, GetError> {
+        if let Some(data_map) = self.data_map_cache.get(&addr) {
+            debug!("Using the cached data map of {addr:?}");
+            return Ok(data_map);
+        }
+
+        let data_map_chunk = self.chunk_get(addr).await?;
+        let data_map = self.resolve_data_map(data_map_chunk.value()).await?;
+        self.data_map_cache.insert(addr, data_map.clone());
+        Ok(data_map)
+    }
+
     /// Upload a piece of data to the network.
     /// Returns the Data Address at which the data was stored.
     /// This data is publicly accessible.
@@ -43,6 +79,30 @@ impl Client {
         &self,
         data: Bytes,
         payment_option: PaymentOption,
+    ) -> Result<DataAddr, PutError> {
+        self.data_put_public_with_key(data, payment_option, None)
+            .await
+    }
+
+    /// Upload a piece of public data to the network, like [`Client::data_put_public`],
+    /// identifying the upload with an idempotency key.
+    ///
+    /// See [`Client::data_put_idempotent`].
+    pub async fn data_put_public_idempotent(
+        &self,
+        data: Bytes,
+        payment_option: PaymentOption,
+        key: IdempotencyKey,
+    ) -> Result<DataAddr, PutError> {
+        self.data_put_public_with_key(data, payment_option, Some(&key))
+            .await
+    }
+
+    async fn data_put_public_with_key(
+        &self,
+        data: Bytes,
+        payment_option: PaymentOption,
+        idempotency_key: Option<&IdempotencyKey>,
     ) -> Result<DataAddr, PutError> {
         let now = ant_networking::target_arch::Instant::now();
         let (data_map_chunk, chunks) = encrypt(data)?;
@@ -59,42 +119,39 @@ impl Client {
 
         // Pay for all chunks + data map chunk
         info!("Paying for {} addresses", xor_names.len());
-        let receipt = self
-            .pay_for_content_addrs(xor_names.into_iter(), payment_option)
-            .await
-            .inspect_err(|err| error!("Error paying for data: {err:?}"))?;
+        let (receipt, reused) = match self
+            .pay_for_upload(map_xor_name, xor_names, payment_option, idempotency_key)
+            .await?
+        {
+            UploadPayment::Completed => return Ok(map_xor_name),
+            UploadPayment::Paid { receipt, reused } => (receipt, reused),
+        };
 
         // Upload all the chunks in parallel including the data map chunk
         debug!("Uploading {} chunks", chunks.len());
 
-        let mut failed_uploads = self
-            .upload_chunks_with_retries(
-                chunks
-                    .iter()
-                    .chain(std::iter::once(&data_map_chunk))
-                    .collect(),
-                &receipt,
-            )
-            .await;
-
-        // Return the last chunk upload error
-        if let Some(last_chunk_fail) = failed_uploads.pop() {
-            tracing::error!(
-                "Error uploading chunk ({:?}): {:?}",
-                last_chunk_fail.0.address(),
-                last_chunk_fail.1
-            );
-            return Err(last_chunk_fail.1);
-        }
+        self.upload_chunks_with_retries(
+            chunks
+                .iter()
+                .chain(std::iter::once(&data_map_chunk))
+                .collect(),
+            &receipt,
+        )
+        .await?;
+        self.record_upload_completed(map_xor_name, receipt.clone(), idempotency_key);
 
         let record_count = chunks.len() + 1;
 
         // Reporting
         if let Some(channel) = self.client_event_sender.as_ref() {
-            let tokens_spent = receipt
-                .values()
-                .map(|(_proof, price)| price.as_atto())
-                .sum::<Amount>();
+            let tokens_spent = if reused {
+                Amount::ZERO
+            } else {
+                receipt
+                    .values()
+                    .map(|(_proof, price)| price.as_atto())
+                    .sum::<Amount>()
+            };
 
             let summary = UploadSummary {
                 record_count,
@@ -176,15 +233,30 @@ impl Client {
         Ok(total_cost)
     }
 
-    // Upload chunks and retry failed uploads up to `RETRY_ATTEMPTS` times.
-    pub(crate) async fn upload_chunks_with_retries<'a>(
+    /// Upload chunks and retry failed uploads according to the client's [`ChunkRetryConfig`].
+    ///
+    /// Returns all chunks that could not be uploaded, along with the reason they were not retried any further.
+    pub(crate) async fn upload_chunks_with_retries(
         &self,
-        mut chunks: Vec<&'a Chunk>,
+        mut chunks: Vec<&Chunk>,
         receipt: &Receipt,
-    ) -> Vec<(&'a Chunk, PutError)> {
-        let mut current_attempt: usize = 1;
+    ) -> Result<(), ChunkUploadFailures> {
+        let config = self.chunk_retry_config;
+        let mut state = ChunkRetryState::default();
+        let mut total = None;
+        let uploaded = Arc::new(AtomicUsize::new(0));
 
         loop {
+            state.attempt += 1;
+
+            // Only the chunks of the first attempt count towards the total, retries are of some of them.
+            let total_chunks = *total.get_or_insert_with(|| {
+                chunks
+                    .iter()
+                    .filter(|chunk| receipt.contains_key(chunk.name()))
+                    .count()
+            });
+
             let mut upload_tasks = vec![];
             for chunk in chunks {
                 let self_clone = self.clone();
@@ -195,13 +267,18 @@ impl Client {
                     continue;
                 };
 
+                let uploaded = Arc::clone(&uploaded);
                 upload_tasks.push(async move {
-                    self_clone
+                    let result = self_clone
                         .chunk_upload_with_payment(chunk, proof.clone())
                         .await
-                        .inspect_err(|err| error!("Error uploading chunk {address:?} :{err:?}"))
-                        // Return chunk reference too, to re-use it next attempt/iteration
-                        .map_err(|err| (chunk, err))
+                        .inspect_err(|err| error!("Error uploading chunk {address:?} :{err:?}"));
+                    if result.is_ok() {
+                        let chunks_uploaded = uploaded.fetch_add(1, Ordering::Relaxed) + 1;
+                        self_clone.send_upload_progress(chunks_uploaded, total_chunks);
+                    }
+                    // Return chunk reference too, to re-use it next attempt/iteration
+                    result.map_err(|err| (chunk, err))
                 });
             }
             let uploads =
@@ -214,25 +291,50 @@ impl Client {
                 "Uploaded {} chunks out of {total_uploads}",
                 total_uploads - uploads_failed.len()
             );
+            state.uploads += total_uploads;
+            state.failures += uploads_failed.len();
 
             // All uploads succeeded.
             if uploads_failed.is_empty() {
-                return vec![];
+                return Ok(());
             }
 
-            // Max retries reached.
-            if current_attempt > RETRY_ATTEMPTS {
-                return uploads_failed;
+            if let Some(reason) = config.stop_reason(&state, uploads_failed.len()) {
+                let failures = ChunkUploadFailures {
+                    failed: uploads_failed
+                        .into_iter()
+                        .map(|(chunk, err)| (*chunk.address(), err))
+                        .collect(),
+                    total: total_chunks,
+                    reason,
+                };
+                error!("{failures}, failed chunks: {:?}", failures.addresses());
+                return Err(failures);
             }
 
             tracing::info!(
-                "Retrying putting {} failed chunks (attempt {current_attempt}/3)",
-                uploads_failed.len()
+                "Retrying putting {} failed chunks (attempt {}/{})",
+                uploads_failed.len(),
+                state.attempt,
+                config.max_attempts
             );
 
             // Re-iterate over the failed chunks
+            state.retries += uploads_failed.len();
             chunks = uploads_failed.into_iter().map(|(chunk, _)| chunk).collect();
-            current_attempt += 1;
+        }
+    }
+
+    /// Report the progress of an upload without waiting, dropping the event if the receiver is behind.
+    fn send_upload_progress(&self, chunks_uploaded: usize, chunks_total: usize) {
+        if let Some(channel) = self.client_event_sender.as_ref() {
+            let progress = ClientEvent::UploadProgress(UploadProgress {
+                chunks_uploaded,
+                chunks_total,
+            });
+            if let Err(err) = channel.try_send(progress) {
+                trace!("Did not send the upload progress event: {err:?}");
+            }
         }
     }
 }
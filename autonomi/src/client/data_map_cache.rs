@@ -0,0 +1,122 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use lru::LruCache;
+use self_encryption::DataMap;
+use std::{
+    num::NonZeroUsize,
+    sync::{Arc, Mutex, MutexGuard},
+};
+use xor_name::XorName;
+
+/// Number of resolved data maps kept by default, see [`crate::client::ClientConfig::data_map_cache_size`].
+pub const DEFAULT_DATA_MAP_CACHE_SIZE: usize = 256;
+
+/// A bounded cache of resolved data maps, keyed by the address of their data map chunk.
+///
+/// Resolving a data map takes one or more network round trips, so repeated reads of the same
+/// data (e.g. range reads of a large file) reuse the data map resolved by the first read.
+/// Clones of the cache share the same entries.
+#[derive(Clone, Default)]
+pub(crate) struct DataMapCache(Option<Arc<Mutex<LruCache<XorName, DataMap>>>>);
+
+impl DataMapCache {
+    /// A cache holding up to `capacity` data maps. A capacity of 0 disables the cache.
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self(
+            NonZeroUsize::new(capacity)
+                .map(|capacity| Arc::new(Mutex::new(LruCache::new(capacity)))),
+        )
+    }
+
+    pub(crate) fn get(&self, addr: &XorName) -> Option<DataMap> {
+        self.lock()?.get(addr).cloned()
+    }
+
+    pub(crate) fn insert(&self, addr: XorName, data_map: DataMap) {
+        if let Some(mut cache) = self.lock() {
+            let _ = cache.put(addr, data_map);
+        }
+    }
+
+    /// Remove the data map of the given address, returning whether it was cached.
+    pub(crate) fn invalidate(&self, addr: &XorName) -> bool {
+        self.lock()
+            .map(|mut cache| cache.pop(addr).is_some())
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn clear(&self) {
+        if let Some(mut cache) = self.lock() {
+            cache.clear();
+        }
+    }
+
+    fn lock(&self) -> Option<MutexGuard<'_, LruCache<XorName, DataMap>>> {
+        // The cache is always left in a valid state, so a panic while holding the lock doesn't matter.
+        self.0
+            .as_ref()
+            .map(|cache| cache.lock().unwrap_or_else(|err| err.into_inner()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use self_encryption::ChunkInfo;
+
+    fn data_map(src_size: usize) -> DataMap {
+        DataMap::new(vec![ChunkInfo {
+            index: 0,
+            dst_hash: XorName::random(&mut rand::thread_rng()),
+            src_hash: XorName::random(&mut rand::thread_rng()),
+            src_size,
+        }])
+    }
+
+    #[test]
+    fn least_recently_used_data_map_is_evicted() {
+        let cache = DataMapCache::new(2);
+        let addrs: Vec<_> = (0..3)
+            .map(|_| XorName::random(&mut rand::thread_rng()))
+            .collect();
+
+        cache.insert(addrs[0], data_map(0));
+        cache.insert(addrs[1], data_map(1));
+        // Reading the first entry makes the second one the least recently used.
+        assert_eq!(cache.get(&addrs[0]).map(|map| map.file_size()), Some(0));
+        cache.insert(addrs[2], data_map(2));
+
+        assert!(cache.get(&addrs[0]).is_some());
+        assert!(cache.get(&addrs[1]).is_none());
+        assert_eq!(cache.get(&addrs[2]).map(|map| map.file_size()), Some(2));
+    }
+
+    #[test]
+    fn invalidated_data_map_is_removed_from_all_clones() {
+        let cache = DataMapCache::new(2);
+        let clone = cache.clone();
+        let addr = XorName::random(&mut rand::thread_rng());
+
+        cache.insert(addr, data_map(0));
+        assert!(clone.get(&addr).is_some());
+        assert!(clone.invalidate(&addr));
+        assert!(cache.get(&addr).is_none());
+        assert!(!cache.invalidate(&addr));
+    }
+
+    #[test]
+    fn zero_capacity_disables_the_cache() {
+        let cache = DataMapCache::new(0);
+        let addr = XorName::random(&mut rand::thread_rng());
+
+        cache.insert(addr, data_map(0));
+        assert!(cache.get(&addr).is_none());
+        assert!(!cache.invalidate(&addr));
+    }
+}
@@ -0,0 +1,712 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Append-only logs of signed entries, such as feeds or audit logs, that only a set of writers can
+//! append to.
+//!
+//! Entries are stored in batches, as segments of public data that each point to the previous
+//! segment. A register owned by the creator of the log points to the latest segment, and its
+//! permissions are the keys allowed to append. Readers page through the log from the latest entry
+//! back, verifying the signature of each entry and that its writer is allowed to append.
+//!
+//! Each entry signs the segment before it, so entries can't be moved to another history, and each
+//! value of the register records the head it was written atop of. A head only counts if it extends
+//! the latest head it was written atop of, so a writer can't replace the entries of others with a
+//! longer history of its own. When concurrent appends fork the log, the writer whose head lost
+//! appends its entries again on top of the winning head.
+
+#![allow(deprecated)]
+
+use crate::client::data::{DataAddr, GetError, PutError};
+use crate::client::registers::{
+    Register, RegisterAddress, RegisterError, RegisterPermissions, RegisterSecretKey,
+};
+use crate::Client;
+use ant_evm::EvmWallet;
+use bls::{PublicKey, Signature};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The address of an event log, which is the address of its register.
+pub type EventLogAddress = RegisterAddress;
+
+/// The maximum number of entries stored in a segment. Flushing more entries at once stores
+/// several segments, so readers never fetch more than this to read an entry.
+pub const MAX_SEGMENT_ENTRIES: usize = 256;
+
+/// The number of times a flush appends its entries again after losing to a concurrent append.
+const MAX_FLUSH_ATTEMPTS: usize = 3;
+
+#[derive(Debug, thiserror::Error)]
+pub enum EventLogError {
+    #[error("Register error: {0}")]
+    Register(#[from] RegisterError),
+    #[error("Failed to store a segment: {0}")]
+    Put(#[from] PutError),
+    #[error("Failed to fetch a segment: {0}")]
+    Get(#[from] GetError),
+    #[error("Failed to serialize the log: {0}")]
+    Serialization(#[from] rmp_serde::encode::Error),
+    #[error("Failed to deserialize the log: {0}")]
+    Deserialization(#[from] rmp_serde::decode::Error),
+    #[error("The key {0:?} is not allowed to append to the log")]
+    UnauthorizedWriter(PublicKey),
+    #[error("The signature of entry {0} is invalid")]
+    InvalidSignature(u64),
+    #[error("The segment at {0:?} is not a valid segment of the log")]
+    InvalidSegment(DataAddr),
+    #[error("Concurrent appends to the log kept winning over this writer, the entries are still pending")]
+    ConcurrentAppend,
+}
+
+/// The value of the register of a log, pointing to its latest segment.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct LogHead {
+    segment: DataAddr,
+    entry_count: u64,
+    /// The segment of the head this one was appended to, if the log wasn't empty.
+    base: Option<DataAddr>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct Segment {
+    log: EventLogAddress,
+    previous: Option<DataAddr>,
+    /// The sequence number of the first entry of the segment. Entries are numbered from 0.
+    first_sequence: u64,
+    entries: Vec<SignedEntry>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SignedEntry {
+    writer: PublicKey,
+    payload: Bytes,
+    /// Signs the log address, sequence number and previous segment along with the payload, so the
+    /// entry can't be replayed in another log, position or history.
+    signature: Signature,
+}
+
+/// An entry of an event log, whose signature and writer were verified.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogEntry {
+    pub sequence: u64,
+    pub writer: PublicKey,
+    pub payload: Bytes,
+}
+
+/// Where to continue reading an event log from, to read older entries.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventLogCursor {
+    segment: DataAddr,
+    /// Only entries before this sequence number are left to read.
+    end: u64,
+}
+
+/// A page of entries of an event log, returned by [`Client::event_log_read`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventLogPage {
+    /// The entries, oldest first.
+    pub entries: Vec<LogEntry>,
+    /// The cursor to read the entries before these, if there are any.
+    pub older: Option<EventLogCursor>,
+    /// The number of entries in the log.
+    pub total_entries: u64,
+}
+
+fn signed_bytes(
+    log: &EventLogAddress,
+    sequence: u64,
+    previous: &Option<DataAddr>,
+    payload: &[u8],
+) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+    rmp_serde::to_vec(&(log, sequence, previous, payload))
+}
+
+impl SignedEntry {
+    fn new(
+        log: &EventLogAddress,
+        sequence: u64,
+        previous: &Option<DataAddr>,
+        payload: Bytes,
+        key: &RegisterSecretKey,
+    ) -> Result<Self, rmp_serde::encode::Error> {
+        let signature = key.sign(signed_bytes(log, sequence, previous, &payload)?);
+        Ok(Self {
+            writer: key.public_key(),
+            payload,
+            signature,
+        })
+    }
+}
+
+impl Segment {
+    /// The entries of the segment, if it belongs to the log and all its entries were appended by
+    /// writers of the log.
+    #[allow(clippy::result_large_err)]
+    fn verify(
+        &self,
+        address: DataAddr,
+        log: &EventLogAddress,
+        permissions: &RegisterPermissions,
+    ) -> Result<Vec<LogEntry>, EventLogError> {
+        if self.log != *log || self.entries.is_empty() {
+            return Err(EventLogError::InvalidSegment(address));
+        }
+
+        let mut entries = Vec::with_capacity(self.entries.len());
+        for (sequence, entry) in (self.first_sequence..).zip(&self.entries) {
+            if !permissions.can_write(&entry.writer) {
+                return Err(EventLogError::UnauthorizedWriter(entry.writer));
+            }
+            if !entry.writer.verify(
+                &entry.signature,
+                signed_bytes(log, sequence, &self.previous, &entry.payload)?,
+            ) {
+                return Err(EventLogError::InvalidSignature(sequence));
+            }
+            entries.push(LogEntry {
+                sequence,
+                writer: entry.writer,
+                payload: entry.payload.clone(),
+            });
+        }
+        Ok(entries)
+    }
+
+    fn end(&self) -> u64 {
+        self.first_sequence + self.entries.len() as u64
+    }
+}
+
+/// A head that extends the head it was written atop of.
+struct ResolvedHead<K> {
+    /// The number of heads before this one.
+    depth: u64,
+    head: LogHead,
+    /// The register entry of the head this one extends.
+    parent: Option<K>,
+}
+
+/// The latest valid head of the log, followed by the heads it extends, newest first.
+///
+/// `history` holds every value of the register of the log, along with the entries it was written
+/// atop of. A head is only valid if it adds entries to the latest valid head it was written atop
+/// of. The latest head is the one with the most heads before it, then the most entries, so readers
+/// agree on a single branch when concurrent appends forked the log, and a fork from an older state
+/// doesn't win by appending more entries at once.
+fn latest_head<K: Copy + Eq + Hash>(history: &HashMap<K, (Bytes, Vec<K>)>) -> Vec<LogHead> {
+    let rank = |resolved: &ResolvedHead<K>| {
+        (
+            resolved.depth,
+            resolved.head.entry_count,
+            resolved.head.segment,
+        )
+    };
+
+    // The heads each entry was written atop of are resolved before the entry itself.
+    let mut resolved: HashMap<K, Option<ResolvedHead<K>>> = HashMap::new();
+    for key in history.keys() {
+        let mut to_resolve = vec![*key];
+        while let Some(key) = to_resolve.last().copied() {
+            if resolved.contains_key(&key) {
+                let _ = to_resolve.pop();
+                continue;
+            }
+            let Some((value, previous)) = history.get(&key) else {
+                let _ = resolved.insert(key, None);
+                let _ = to_resolve.pop();
+                continue;
+            };
+            let unresolved = previous
+                .iter()
+                .filter(|previous| !resolved.contains_key(previous))
+                .copied()
+                .collect::<Vec<_>>();
+            if !unresolved.is_empty() {
+                to_resolve.extend(unresolved);
+                continue;
+            }
+            let _ = to_resolve.pop();
+
+            let parent = previous
+                .iter()
+                .filter_map(|previous| {
+                    resolved
+                        .get(previous)
+                        .and_then(Option::as_ref)
+                        .map(|parent| (*previous, parent))
+                })
+                .max_by_key(|(_, parent)| rank(parent));
+            let head = match rmp_serde::from_slice::<LogHead>(value) {
+                Ok(head) => Some(head),
+                Err(err) => {
+                    warn!("Ignoring an invalid event log head: {err}");
+                    None
+                }
+            };
+            let head = head.and_then(|head| {
+                let (base, depth, entry_count) = match parent {
+                    Some((_, parent)) => (
+                        Some(parent.head.segment),
+                        parent.depth + 1,
+                        parent.head.entry_count,
+                    ),
+                    None => (None, 0, 0),
+                };
+                if head.base != base || head.entry_count <= entry_count {
+                    warn!(
+                        "Ignoring the event log head at {:?}, as it does not extend the head it was written atop of",
+                        head.segment
+                    );
+                    return None;
+                }
+                Some(ResolvedHead {
+                    depth,
+                    head,
+                    parent: parent.map(|(key, _)| key),
+                })
+            });
+            let _ = resolved.insert(key, head);
+        }
+    }
+
+    let mut path = Vec::new();
+    let mut next = resolved.values().flatten().max_by_key(|head| rank(head));
+    while let Some(resolved_head) = next {
+        path.push(resolved_head.head.clone());
+        next = resolved_head
+            .parent
+            .and_then(|parent| resolved.get(&parent))
+            .and_then(Option::as_ref);
+    }
+    path
+}
+
+/// Buffers entries to append to an event log, storing them in a batch on [`Self::flush`].
+///
+/// Obtained with [`Client::event_log_writer`].
+pub struct EventLogWriter {
+    client: Client,
+    address: EventLogAddress,
+    key: RegisterSecretKey,
+    pending: Vec<Bytes>,
+}
+
+impl EventLogWriter {
+    pub fn address(&self) -> &EventLogAddress {
+        &self.address
+    }
+
+    /// Buffer an entry, to be appended on the next flush.
+    pub fn append(&mut self, payload: impl Into<Bytes>) {
+        self.pending.push(payload.into());
+    }
+
+    /// The number of buffered entries.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Append the buffered entries to the log, returning the number of entries in the log.
+    ///
+    /// The entries stay buffered if the flush fails, so it can be retried. Segments stored by the
+    /// failed flush are then found on the network rather than paid for again.
+    ///
+    /// If another writer appended to the log at the same time and its head won, the entries are
+    /// appended again on top of it.
+    pub async fn flush(&mut self, wallet: &EvmWallet) -> Result<u64, EventLogError> {
+        for attempt in 1..=MAX_FLUSH_ATTEMPTS {
+            let (register, path) = self.client.event_log_head(self.address).await?;
+            let head = path.into_iter().next();
+            let entry_count = head.as_ref().map_or(0, |head| head.entry_count);
+            if self.pending.is_empty() {
+                return Ok(entry_count);
+            }
+            let writer = self.key.public_key();
+            if !register.permissions().can_write(&writer) {
+                return Err(EventLogError::UnauthorizedWriter(writer));
+            }
+
+            let Some(new_head) = self.store_segments(head, wallet).await? else {
+                return Ok(entry_count);
+            };
+            self.client
+                .register_update(
+                    register,
+                    Bytes::from(rmp_serde::to_vec(&new_head)?),
+                    self.key.clone(),
+                )
+                .await?;
+
+            // Concurrent appends fork the register, and readers only follow one of the branches
+            let (_, path) = self.client.event_log_head(self.address).await?;
+            if path.contains(&new_head) {
+                info!(
+                    "Appended {} entries to event log {}",
+                    self.pending.len(),
+                    self.address
+                );
+                self.pending.clear();
+                return Ok(new_head.entry_count);
+            }
+            warn!(
+                "Event log {} was appended to concurrently, appending the entries again on top of the other writer ({attempt}/{MAX_FLUSH_ATTEMPTS})",
+                self.address
+            );
+        }
+        Err(EventLogError::ConcurrentAppend)
+    }
+
+    /// Store the buffered entries in segments following `head`, returning the head to write, or
+    /// `None` if there are no entries.
+    async fn store_segments(
+        &self,
+        head: Option<LogHead>,
+        wallet: &EvmWallet,
+    ) -> Result<Option<LogHead>, EventLogError> {
+        let base = head.as_ref().map(|head| head.segment);
+        let mut entry_count = head.as_ref().map_or(0, |head| head.entry_count);
+        let mut previous = base;
+        for batch in self.pending.chunks(MAX_SEGMENT_ENTRIES) {
+            let entries = (entry_count..)
+                .zip(batch)
+                .map(|(sequence, payload)| {
+                    SignedEntry::new(
+                        &self.address,
+                        sequence,
+                        &previous,
+                        payload.clone(),
+                        &self.key,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let segment = Segment {
+                log: self.address,
+                previous,
+                first_sequence: entry_count,
+                entries,
+            };
+            let segment_addr = self
+                .client
+                .data_put_public(Bytes::from(rmp_serde::to_vec(&segment)?), wallet.into())
+                .await?;
+            debug!(
+                "Stored segment of {} entries of event log {} at {segment_addr:?}",
+                batch.len(),
+                self.address
+            );
+            previous = Some(segment_addr);
+            entry_count = segment.end();
+        }
+
+        Ok(previous
+            .filter(|_| !self.pending.is_empty())
+            .map(|segment| LogHead {
+                segment,
+                entry_count,
+                base,
+            }))
+    }
+}
+
+impl Client {
+    /// Create an empty event log, which its owner and the given writers can append to.
+    ///
+    /// The writers can't be changed once the log is created.
+    pub async fn event_log_create(
+        &self,
+        name: &str,
+        owner: RegisterSecretKey,
+        writers: impl IntoIterator<Item = PublicKey>,
+        wallet: &EvmWallet,
+    ) -> Result<EventLogAddress, EventLogError> {
+        let permissions = RegisterPermissions::new_with(writers);
+        let register = self
+            .register_create_with_permissions(None, name, owner, permissions, wallet)
+            .await?;
+        info!("Created event log {}", register.address());
+        Ok(*register.address())
+    }
+
+    /// A writer that appends to the event log, signing the entries with the key.
+    ///
+    /// The key must be that of the owner of the log or of one of its writers.
+    pub fn event_log_writer(
+        &self,
+        address: EventLogAddress,
+        key: RegisterSecretKey,
+    ) -> EventLogWriter {
+        EventLogWriter {
+            client: self.clone(),
+            address,
+            key,
+            pending: Vec::new(),
+        }
+    }
+
+    /// The number of entries in the event log.
+    pub async fn event_log_len(&self, address: EventLogAddress) -> Result<u64, EventLogError> {
+        let (_, path) = self.event_log_head(address).await?;
+        Ok(path.first().map_or(0, |head| head.entry_count))
+    }
+
+    /// Read up to `limit` entries of the event log.
+    ///
+    /// Without a cursor, the latest entries are read. Use the cursor of the page to read the
+    /// entries before it, until a page has no cursor.
+    pub async fn event_log_read(
+        &self,
+        address: EventLogAddress,
+        cursor: Option<EventLogCursor>,
+        limit: usize,
+    ) -> Result<EventLogPage, EventLogError> {
+        let (register, path) = self.event_log_head(address).await?;
+        let total_entries = path.first().map_or(0, |head| head.entry_count);
+        // The segments the heads before the latest one point to must be part of its history
+        let boundaries = path
+            .iter()
+            .map(|head| (head.entry_count, head.segment))
+            .collect::<HashMap<_, _>>();
+        let (mut next_segment, mut end) = match (cursor, path.into_iter().next()) {
+            (Some(cursor), _) => (Some(cursor.segment), cursor.end),
+            (None, Some(head)) => (Some(head.segment), head.entry_count),
+            (None, None) => (None, 0),
+        };
+
+        // Entries are collected newest first, from the latest segment back.
+        let mut entries = Vec::new();
+        while let Some(segment_addr) = next_segment {
+            if entries.len() == limit || end == 0 {
+                break;
+            }
+            let bytes = self.data_get_public(segment_addr).await?;
+            let segment: Segment = rmp_serde::from_slice(&bytes)?;
+            // Each segment must hold the entry before `end`, so reading always moves back.
+            if end <= segment.first_sequence || end > segment.end() {
+                return Err(EventLogError::InvalidSegment(segment_addr));
+            }
+            if boundaries
+                .get(&segment.end())
+                .is_some_and(|expected| *expected != segment_addr)
+            {
+                error!("Event log {address} does not extend its earlier head at {segment_addr:?}");
+                return Err(EventLogError::InvalidSegment(segment_addr));
+            }
+            let verified = segment.verify(segment_addr, &address, register.permissions())?;
+            let before = end;
+            for entry in verified
+                .into_iter()
+                .rev()
+                .filter(|entry| entry.sequence < before)
+                .take(limit - entries.len())
+            {
+                end = entry.sequence;
+                entries.push(entry);
+            }
+            if end == segment.first_sequence {
+                next_segment = segment.previous;
+            }
+        }
+        entries.reverse();
+
+        let older = next_segment
+            .filter(|_| end > 0)
+            .map(|segment| EventLogCursor { segment, end });
+        debug!(
+            "Read {} entries of event log {address}, of {total_entries}",
+            entries.len()
+        );
+        Ok(EventLogPage {
+            entries,
+            older,
+            total_entries,
+        })
+    }
+
+    /// The register of the log, and its latest head followed by the heads it extends.
+    async fn event_log_head(
+        &self,
+        address: EventLogAddress,
+    ) -> Result<(Register, Vec<LogHead>), EventLogError> {
+        let register = self.register_get(address).await?;
+        let path = latest_head(&register.history());
+        Ok((register, path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(log: EventLogAddress, keys: &[&RegisterSecretKey]) -> Segment {
+        let entries = keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| {
+                SignedEntry::new(
+                    &log,
+                    10 + i as u64,
+                    &None,
+                    Bytes::from(format!("entry {i}")),
+                    key,
+                )
+                .unwrap()
+            })
+            .collect();
+        Segment {
+            log,
+            previous: None,
+            first_sequence: 10,
+            entries,
+        }
+    }
+
+    #[test]
+    fn only_entries_signed_by_writers_are_verified() {
+        let owner = RegisterSecretKey::random();
+        let writer = RegisterSecretKey::random();
+        let other = RegisterSecretKey::random();
+        let log = Client::register_address("log", &owner);
+        let permissions = RegisterPermissions::new_with([owner.public_key(), writer.public_key()]);
+        let addr = DataAddr::random(&mut rand::thread_rng());
+
+        let entries = segment(log, &[&owner, &writer])
+            .verify(addr, &log, &permissions)
+            .unwrap();
+        assert_eq!(
+            entries
+                .iter()
+                .map(|entry| entry.sequence)
+                .collect::<Vec<_>>(),
+            vec![10, 11]
+        );
+        assert_eq!(entries[1].writer, writer.public_key());
+        assert_eq!(entries[1].payload, Bytes::from("entry 1"));
+
+        assert!(matches!(
+            segment(log, &[&owner, &other]).verify(addr, &log, &permissions),
+            Err(EventLogError::UnauthorizedWriter(key)) if key == other.public_key()
+        ));
+
+        let other_log = Client::register_address("other log", &owner);
+        assert!(matches!(
+            segment(log, &[&owner]).verify(addr, &other_log, &permissions),
+            Err(EventLogError::InvalidSegment(_))
+        ));
+    }
+
+    #[test]
+    fn entries_cant_be_tampered_with_or_moved() {
+        let owner = RegisterSecretKey::random();
+        let log = Client::register_address("log", &owner);
+        let permissions = RegisterPermissions::new_with([owner.public_key()]);
+        let addr = DataAddr::random(&mut rand::thread_rng());
+
+        let mut tampered = segment(log, &[&owner, &owner]);
+        tampered.entries[1].payload = Bytes::from("tampered");
+        assert!(matches!(
+            tampered.verify(addr, &log, &permissions),
+            Err(EventLogError::InvalidSignature(11))
+        ));
+
+        let mut moved = segment(log, &[&owner]);
+        moved.first_sequence = 20;
+        assert!(matches!(
+            moved.verify(addr, &log, &permissions),
+            Err(EventLogError::InvalidSignature(20))
+        ));
+
+        let mut rebased = segment(log, &[&owner]);
+        rebased.previous = Some(DataAddr::random(&mut rand::thread_rng()));
+        assert!(matches!(
+            rebased.verify(addr, &log, &permissions),
+            Err(EventLogError::InvalidSignature(10))
+        ));
+    }
+
+    /// Register history in which each entry is written atop of the given entries.
+    struct History(HashMap<u32, (Bytes, Vec<u32>)>);
+
+    impl History {
+        fn write(&mut self, key: u32, head: &LogHead, atop: &[u32]) {
+            let value = Bytes::from(rmp_serde::to_vec(head).unwrap());
+            let _ = self.0.insert(key, (value, atop.to_vec()));
+        }
+    }
+
+    fn head(entry_count: u64, base: Option<&LogHead>) -> LogHead {
+        LogHead {
+            segment: DataAddr::random(&mut rand::thread_rng()),
+            entry_count,
+            base: base.map(|base| base.segment),
+        }
+    }
+
+    #[test]
+    fn the_latest_head_extends_the_heads_before_it() {
+        let mut history = History(HashMap::new());
+        assert_eq!(latest_head(&history.0), vec![]);
+
+        let first = head(2, None);
+        let second = head(5, Some(&first));
+        history.write(1, &first, &[]);
+        history.write(2, &second, &[1]);
+        let _ = history.0.insert(3, (Bytes::from("not a head"), vec![2]));
+        assert_eq!(latest_head(&history.0), vec![second.clone(), first.clone()]);
+
+        // Concurrent appends: the head with the most entries wins, and the other writer appends
+        // again on top of it.
+        let winner = head(8, Some(&second));
+        let loser = head(7, Some(&second));
+        history.write(4, &winner, &[2]);
+        history.write(5, &loser, &[2]);
+        assert_eq!(latest_head(&history.0)[0], winner);
+        let appended_again = head(10, Some(&winner));
+        history.write(6, &appended_again, &[4, 5]);
+        assert_eq!(
+            latest_head(&history.0),
+            vec![
+                appended_again.clone(),
+                winner,
+                second.clone(),
+                first.clone()
+            ]
+        );
+    }
+
+    #[test]
+    fn heads_that_rewrite_the_log_are_ignored() {
+        let mut history = History(HashMap::new());
+        let first = head(2, None);
+        let second = head(5, Some(&first));
+        let third = head(6, Some(&second));
+        history.write(1, &first, &[]);
+        history.write(2, &second, &[1]);
+        history.write(3, &third, &[2]);
+        let expected = vec![third.clone(), second.clone(), first.clone()];
+
+        // A longer history written atop the latest head, that doesn't extend it.
+        history.write(4, &head(100, None), &[3]);
+        history.write(5, &head(100, Some(&first)), &[3]);
+        // One that doesn't add any entries.
+        history.write(6, &head(6, Some(&third)), &[3]);
+        // A new history that isn't written atop of anything.
+        history.write(7, &head(100, Some(&second)), &[]);
+        assert_eq!(latest_head(&history.0), expected);
+
+        // A fork from an older head doesn't win by appending more entries at once.
+        let fork = head(100, Some(&first));
+        history.write(8, &fork, &[1]);
+        assert_eq!(latest_head(&history.0), expected);
+        // Nor do heads building on a rejected one.
+        history.write(9, &head(101, Some(&head(100, None))), &[4]);
+        assert_eq!(latest_head(&history.0), expected);
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::client::Amount;
+use serde::{Deserialize, Serialize};
+
+/// The version of the serialized form of the events, as found in [`EventMessage`].
+///
+/// The schema only evolves by addition: new event types, and new fields on existing events, which
+/// bump this version. Existing event types and fields are never renamed, removed or given a new
+/// type. Consumers should therefore ignore event types and fields they don't know, and can
+/// rely on everything that was present in the version they were written against.
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// Events that can be broadcasted by the client.
+///
+/// Receive them with [`Client::enable_client_events`], and use [`EventMessage`] to serialize them
+/// for a user interface.
+///
+/// [`Client::enable_client_events`]: crate::Client::enable_client_events
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ClientEvent {
+    /// Some of the chunks of an upload have been stored.
+    ///
+    /// These events are dropped rather than delaying the upload if the receiver falls behind, so
+    /// each one carries the totals so far.
+    UploadProgress(UploadProgress),
+    UploadComplete(UploadSummary),
+    /// An event type from a later version of the schema than this one.
+    ///
+    /// The client never broadcasts it; it is only produced when parsing an [`EventMessage`].
+    #[serde(other)]
+    Unknown,
+}
+
+/// Progress of the chunk uploads of an upload operation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UploadProgress {
+    /// Number of chunks stored so far.
+    pub chunks_uploaded: usize,
+    /// Number of chunks to store, excluding those that were already stored.
+    pub chunks_total: usize,
+}
+
+/// Summary of an upload operation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UploadSummary {
+    pub record_count: usize,
+    /// Serialized as a decimal string of atto tokens, as the amount may not fit in a JSON number.
+    #[serde(with = "amount_as_decimal")]
+    pub tokens_spent: Amount,
+}
+
+/// A [`ClientEvent`] along with the version of the schema it was serialized with.
+///
+/// For example, as JSON:
+///
+/// ```json
+/// {"schema_version":1,"type":"upload_complete","record_count":3,"tokens_spent":"1500"}
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventMessage {
+    pub schema_version: u32,
+    #[serde(flatten)]
+    pub event: ClientEvent,
+}
+
+impl From<ClientEvent> for EventMessage {
+    fn from(event: ClientEvent) -> Self {
+        Self {
+            schema_version: EVENT_SCHEMA_VERSION,
+            event,
+        }
+    }
+}
+
+impl EventMessage {
+    /// Serialize the message as a single line of JSON, e.g. to be written to stdout.
+    pub fn to_json_line(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+mod amount_as_decimal {
+    use super::Amount;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+    use std::str::FromStr;
+
+    pub(super) fn serialize<S: Serializer>(
+        amount: &Amount,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(amount)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Amount, D::Error> {
+        let amount = String::deserialize(deserializer)?;
+        Amount::from_str(&amount).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // These are the serialized forms of version 1 of the schema. They must keep serializing the
+    // same way, since user interfaces depend on them: add new fields and events instead.
+    const UPLOAD_PROGRESS_V1: &str =
+        r#"{"schema_version":1,"type":"upload_progress","chunks_uploaded":2,"chunks_total":5}"#;
+    const UPLOAD_COMPLETE_V1: &str = r#"{"schema_version":1,"type":"upload_complete","record_count":3,"tokens_spent":"340282366920938463463374607431768211456"}"#;
+
+    fn upload_complete() -> ClientEvent {
+        ClientEvent::UploadComplete(UploadSummary {
+            record_count: 3,
+            tokens_spent: Amount::from(u128::MAX) + Amount::from(1),
+        })
+    }
+
+    #[test]
+    fn events_serialize_to_the_published_schema() {
+        let progress = EventMessage::from(ClientEvent::UploadProgress(UploadProgress {
+            chunks_uploaded: 2,
+            chunks_total: 5,
+        }));
+        assert_eq!(progress.to_json_line().unwrap(), UPLOAD_PROGRESS_V1);
+
+        let complete = EventMessage::from(upload_complete());
+        assert_eq!(complete.to_json_line().unwrap(), UPLOAD_COMPLETE_V1);
+        assert_eq!(
+            EventMessage::from_json(UPLOAD_COMPLETE_V1).unwrap(),
+            complete
+        );
+    }
+
+    #[test]
+    fn unknown_fields_are_ignored() {
+        let json = r#"{"schema_version":2,"type":"upload_complete","record_count":3,"tokens_spent":"340282366920938463463374607431768211456","added_later":true}"#;
+        let message = EventMessage::from_json(json).unwrap();
+        assert_eq!(message.schema_version, 2);
+        assert_eq!(message.event, upload_complete());
+    }
+
+    #[test]
+    fn unknown_event_types_are_parsed() {
+        let json = r#"{"schema_version":2,"type":"download_progress","chunks_downloaded":1}"#;
+        let message = EventMessage::from_json(json).unwrap();
+        assert_eq!(message.schema_version, 2);
+        assert_eq!(message.event, ClientEvent::Unknown);
+    }
+}
@@ -16,10 +16,14 @@ use ant_networking::target_arch::{Duration, SystemTime, UNIX_EPOCH};
 use crate::{
     client::{
         data::{DataMapChunk, GetError, PutError},
+        files::archive_public::ArchiveAddr,
         payment::PaymentOption,
     },
     Client,
 };
+use ant_protocol::storage::Chunk;
+use bls::Ciphertext;
+use blst::min_pk::SecretKey as BlstSecretKey;
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -27,12 +31,38 @@ use thiserror::Error;
 /// Private archive data map, allowing access to the [`PrivateArchive`] data.
 pub type PrivateArchiveAccess = DataMapChunk;
 
+/// Secret key from which the per-entry keys of an [`EncryptedPrivateArchive`] are derived.
+pub type ArchiveSecretKey = bls::SecretKey;
+
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum RenameError {
     #[error("File not found in archive: {0}")]
     FileNotFound(PathBuf),
 }
 
+/// Errors that can occur when accessing an entry of an [`EncryptedPrivateArchive`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ArchiveEntryError {
+    #[error("File not found in archive: {0}")]
+    FileNotFound(PathBuf),
+    #[error("Failed to decrypt archive entry with the given key: {0}")]
+    Decryption(PathBuf),
+    #[error("Invalid archive entry access: {0}")]
+    InvalidAccess(String),
+    #[error("Failed to derive the key of archive entry: {0}")]
+    KeyDerivation(PathBuf),
+}
+
+/// Errors that can occur when fetching a single entry of an [`EncryptedPrivateArchive`] from the network.
+#[allow(clippy::large_enum_variant)]
+#[derive(Error, Debug)]
+pub enum ArchiveEntryGetError {
+    #[error("Failed to fetch archive: {0}")]
+    GetError(#[from] GetError),
+    #[error("Failed to access archive entry: {0}")]
+    Entry(#[from] ArchiveEntryError),
+}
+
 /// Metadata for a file in an archive. Time values are UNIX timestamps.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Metadata {
@@ -148,6 +178,217 @@ impl PrivateArchive {
 
         Ok(root_serialized)
     }
+
+    /// Encrypt the data map of every entry with its own key, derived from `key` and the entry path.
+    ///
+    /// The resulting [`EncryptedPrivateArchive`] can be shared as a whole while only granting access
+    /// to the entries whose [`ArchiveEntryAccess`] has been handed out.
+    pub fn encrypt_entries(
+        &self,
+        key: &ArchiveSecretKey,
+    ) -> Result<EncryptedPrivateArchive, ArchiveEntryError> {
+        let map = self
+            .map
+            .iter()
+            .map(|(path, (data_map, meta))| {
+                let entry_key = derive_entry_key(key, path)?.public_key();
+                let entry = EncryptedEntry {
+                    data_map: entry_key.encrypt(data_map.0.value()),
+                    key: entry_key,
+                    meta: meta.clone(),
+                };
+                Ok((path.clone(), entry))
+            })
+            .collect::<Result<_, ArchiveEntryError>>()?;
+        debug!(
+            "Encrypted {} entries of the private archive",
+            self.map.len()
+        );
+
+        Ok(EncryptedPrivateArchive { map })
+    }
+}
+
+/// Domain separation of the entry keys from other keys derived from the same secret.
+const ENTRY_KEY_INFO: &[u8] = b"autonomi private archive entry";
+
+/// Derive the key of a single archive entry from the archive key and the entry path.
+///
+/// This uses the HKDF based key generation of the BLS signature spec, rather than BLS child key
+/// derivation, as the latter is linear: anyone holding a child key and its path could compute the
+/// archive key from them, and with it the keys of all other entries.
+fn derive_entry_key(
+    key: &ArchiveSecretKey,
+    path: &Path,
+) -> Result<bls::SecretKey, ArchiveEntryError> {
+    let key_info = [ENTRY_KEY_INFO, path.to_string_lossy().as_bytes()].concat();
+    let entry_key = BlstSecretKey::key_gen(&key.to_bytes(), &key_info)
+        .map_err(|_| ArchiveEntryError::KeyDerivation(path.to_path_buf()))?;
+    bls::SecretKey::from_bytes(entry_key.to_bytes())
+        .map_err(|_| ArchiveEntryError::KeyDerivation(path.to_path_buf()))
+}
+
+/// A [`PrivateArchive`] of which the data map of each entry is encrypted with its own key.
+///
+/// The entry keys are derived from a single [`ArchiveSecretKey`], so the owner only needs to keep track of that key,
+/// while a single file can be shared by handing out its [`ArchiveEntryAccess`] without revealing the other entries.
+/// Note that the paths and metadata of the entries are not encrypted.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct EncryptedPrivateArchive {
+    map: HashMap<PathBuf, EncryptedEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+struct EncryptedEntry {
+    /// The data map, encrypted to `key`.
+    data_map: Ciphertext,
+    /// Public key of the entry, used to check a decryption key before using it.
+    key: bls::PublicKey,
+    meta: Metadata,
+}
+
+impl EncryptedEntry {
+    fn decrypt(
+        &self,
+        entry_key: &bls::SecretKey,
+        path: &Path,
+    ) -> Result<DataMapChunk, ArchiveEntryError> {
+        // Decrypting with the wrong key does not fail but yields garbage, so check the key first
+        if entry_key.public_key() != self.key {
+            return Err(ArchiveEntryError::Decryption(path.to_path_buf()));
+        }
+        let bytes = entry_key
+            .decrypt(&self.data_map)
+            .ok_or(ArchiveEntryError::Decryption(path.to_path_buf()))?;
+        Ok(DataMapChunk::from(Chunk::new(Bytes::from(bytes))))
+    }
+}
+
+impl EncryptedPrivateArchive {
+    /// List all files in the archive
+    pub fn files(&self) -> Vec<(PathBuf, Metadata)> {
+        self.map
+            .iter()
+            .map(|(path, entry)| (path.clone(), entry.meta.clone()))
+            .collect()
+    }
+
+    /// Decrypt all entries, using the key the archive was encrypted with.
+    pub fn decrypt(&self, key: &ArchiveSecretKey) -> Result<PrivateArchive, ArchiveEntryError> {
+        let mut archive = PrivateArchive::new();
+        for (path, entry) in self.map.iter() {
+            let data_map = entry.decrypt(&derive_entry_key(key, path)?, path)?;
+            archive.add_file(path.clone(), data_map, entry.meta.clone());
+        }
+        Ok(archive)
+    }
+
+    /// Export the access token of a single entry, allowing it to be decrypted without the archive key.
+    ///
+    /// `archive` is the network address the archive was uploaded to (see [`Client::encrypted_archive_put`]).
+    pub fn entry_access(
+        &self,
+        archive: ArchiveAddr,
+        key: &ArchiveSecretKey,
+        path: &Path,
+    ) -> Result<ArchiveEntryAccess, ArchiveEntryError> {
+        let entry = self
+            .map
+            .get(path)
+            .ok_or(ArchiveEntryError::FileNotFound(path.to_path_buf()))?;
+        let entry_key = derive_entry_key(key, path)?;
+
+        // Make sure the token we hand out actually works for this archive
+        let _ = entry.decrypt(&entry_key, path)?;
+
+        Ok(ArchiveEntryAccess {
+            archive,
+            path: path.to_path_buf(),
+            key: entry_key,
+        })
+    }
+
+    /// Decrypt a single entry using its access token.
+    pub fn decrypt_entry(
+        &self,
+        access: &ArchiveEntryAccess,
+    ) -> Result<(DataMapChunk, Metadata), ArchiveEntryError> {
+        let entry = self
+            .map
+            .get(&access.path)
+            .ok_or(ArchiveEntryError::FileNotFound(access.path.clone()))?;
+        let data_map = entry.decrypt(&access.key, &access.path)?;
+        Ok((data_map, entry.meta.clone()))
+    }
+
+    /// Deserialize from bytes.
+    pub fn from_bytes(data: Bytes) -> Result<EncryptedPrivateArchive, rmp_serde::decode::Error> {
+        let root: EncryptedPrivateArchive = rmp_serde::from_slice(&data[..])?;
+
+        Ok(root)
+    }
+
+    /// Serialize to bytes.
+    pub fn to_bytes(&self) -> Result<Bytes, rmp_serde::encode::Error> {
+        let root_serialized = rmp_serde::to_vec(&self)?;
+        let root_serialized = Bytes::from(root_serialized);
+
+        Ok(root_serialized)
+    }
+}
+
+/// Access token to a single entry of an [`EncryptedPrivateArchive`] stored on the network.
+///
+/// Holding this token only allows decrypting the entry at [`ArchiveEntryAccess::path`], not the rest of the archive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArchiveEntryAccess {
+    archive: ArchiveAddr,
+    path: PathBuf,
+    #[serde(with = "entry_key_serde")]
+    key: bls::SecretKey,
+}
+
+impl ArchiveEntryAccess {
+    /// The address of the [`EncryptedPrivateArchive`] containing the entry.
+    pub fn archive(&self) -> ArchiveAddr {
+        self.archive
+    }
+
+    /// The path of the entry within the archive.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Encode the token as hex, e.g. to share it with someone.
+    pub fn to_hex(&self) -> Result<String, rmp_serde::encode::Error> {
+        Ok(hex::encode(rmp_serde::to_vec(&self)?))
+    }
+
+    /// Decode a token from its hex encoding.
+    pub fn from_hex(hex: &str) -> Result<Self, ArchiveEntryError> {
+        let bytes =
+            hex::decode(hex).map_err(|e| ArchiveEntryError::InvalidAccess(format!("{e}")))?;
+        rmp_serde::from_slice(&bytes).map_err(|e| ArchiveEntryError::InvalidAccess(format!("{e}")))
+    }
+}
+
+// `bls::SecretKey` deliberately does not implement `Serialize`, so the entry key is stored as hex.
+mod entry_key_serde {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        key: &bls::SecretKey,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&key.to_hex())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<bls::SecretKey, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        bls::SecretKey::from_hex(&hex).map_err(D::Error::custom)
+    }
 }
 
 impl Client {
@@ -173,4 +414,142 @@ impl Client {
         debug!("Uploaded private archive {archive:?} to the network and address is {result:?}");
         result
     }
+
+    /// Fetch an [`EncryptedPrivateArchive`] from the network
+    pub async fn encrypted_archive_get(
+        &self,
+        addr: ArchiveAddr,
+    ) -> Result<EncryptedPrivateArchive, GetError> {
+        let data = self.data_get_public(addr).await?;
+        Ok(EncryptedPrivateArchive::from_bytes(data)?)
+    }
+
+    /// Upload an [`EncryptedPrivateArchive`] to the network
+    ///
+    /// The archive is stored publicly, as its entries can only be read with their keys.
+    pub async fn encrypted_archive_put(
+        &self,
+        archive: &EncryptedPrivateArchive,
+        payment_option: PaymentOption,
+    ) -> Result<ArchiveAddr, PutError> {
+        let bytes = archive
+            .to_bytes()
+            .map_err(|e| PutError::Serialization(format!("Failed to serialize archive: {e:?}")))?;
+        let result = self.data_put_public(bytes, payment_option).await;
+        debug!("Uploaded encrypted private archive to the network and address is {result:?}");
+        result
+    }
+
+    /// Fetch the data map and metadata of a single archive entry, using its [`ArchiveEntryAccess`]
+    ///
+    /// The returned [`DataMapChunk`] can be used with [`Client::data_get`] to fetch the file content.
+    pub async fn archive_entry_get(
+        &self,
+        access: &ArchiveEntryAccess,
+    ) -> Result<(DataMapChunk, Metadata), ArchiveEntryGetError> {
+        let archive = self.encrypted_archive_get(access.archive).await?;
+        let entry = archive.decrypt_entry(access)?;
+        debug!(
+            "Fetched archive entry {:?} from archive {:?}",
+            access.path, access.archive
+        );
+        Ok(entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn archive_with_files(paths: &[&str]) -> PrivateArchive {
+        let mut archive = PrivateArchive::new();
+        for path in paths {
+            let data_map = DataMapChunk::from(Chunk::new(Bytes::from(path.to_string())));
+            archive.add_file(PathBuf::from(path), data_map, Metadata::new_with_size(0));
+        }
+        archive
+    }
+
+    #[test]
+    fn encrypted_archive_roundtrip() {
+        let archive = archive_with_files(&["a.txt", "dir/b.txt"]);
+        let key = ArchiveSecretKey::random();
+
+        let encrypted = archive.encrypt_entries(&key).expect("Failed to encrypt");
+        let bytes = encrypted.to_bytes().expect("Failed to serialize");
+        let encrypted = EncryptedPrivateArchive::from_bytes(bytes).expect("Failed to deserialize");
+
+        assert_eq!(encrypted.decrypt(&key), Ok(archive));
+        assert!(encrypted.decrypt(&ArchiveSecretKey::random()).is_err());
+    }
+
+    #[test]
+    fn entry_access_only_grants_its_entry() {
+        let archive = archive_with_files(&["a.txt", "b.txt"]);
+        let key = ArchiveSecretKey::random();
+        let encrypted = archive.encrypt_entries(&key).expect("Failed to encrypt");
+        let addr = ArchiveAddr::random(&mut rand::thread_rng());
+
+        let access = encrypted
+            .entry_access(addr, &key, Path::new("a.txt"))
+            .expect("Entry should exist");
+        let access = ArchiveEntryAccess::from_hex(&access.to_hex().expect("Failed to encode"))
+            .expect("Failed to decode");
+        assert_eq!(access.archive(), addr);
+
+        let (data_map, _) = encrypted.decrypt_entry(&access).expect("Failed to decrypt");
+        assert_eq!(
+            Some(&data_map),
+            archive.map().get(Path::new("a.txt")).map(|(d, _)| d)
+        );
+
+        let wrong_entry = ArchiveEntryAccess {
+            path: PathBuf::from("b.txt"),
+            ..access
+        };
+        assert_eq!(
+            encrypted.decrypt_entry(&wrong_entry),
+            Err(ArchiveEntryError::Decryption(PathBuf::from("b.txt")))
+        );
+        assert_eq!(
+            encrypted
+                .entry_access(addr, &key, Path::new("c.txt"))
+                .map(|_| ()),
+            Err(ArchiveEntryError::FileNotFound(PathBuf::from("c.txt")))
+        );
+    }
+
+    #[test]
+    fn entry_access_does_not_reveal_the_archive_key() {
+        let archive = archive_with_files(&["a.txt", "b.txt"]);
+        let key = ArchiveSecretKey::random();
+        let encrypted = archive.encrypt_entries(&key).expect("Failed to encrypt");
+        let addr = ArchiveAddr::random(&mut rand::thread_rng());
+        let access = encrypted
+            .entry_access(addr, &key, Path::new("a.txt"))
+            .expect("Entry should exist");
+
+        // With BLS child derivation the entry key would be the archive key times a scalar hashed
+        // from the path, so the public keys would be linked and the archive key recoverable.
+        let entry_public_key = access.key.public_key();
+        assert_ne!(entry_public_key, key.public_key().derive_child(b"a.txt"));
+        assert_ne!(access.key, key.derive_child(b"a.txt"));
+        assert_ne!(access.key, key);
+
+        // Nothing derived from the entry key opens the other entry.
+        let other = Path::new("b.txt");
+        for candidate in [
+            access.key.clone(),
+            access.key.derive_child(b"b.txt"),
+            derive_entry_key(&access.key, other).expect("Failed to derive"),
+        ] {
+            let forged = ArchiveEntryAccess {
+                archive: addr,
+                path: other.to_path_buf(),
+                key: candidate,
+            };
+            assert!(encrypted.decrypt_entry(&forged).is_err());
+        }
+        assert!(encrypted.decrypt(&access.key).is_err());
+    }
 }
@@ -0,0 +1,381 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::client::data::PayError;
+use crate::client::payment::{receipt_from_store_quotes, Receipt};
+use crate::client::quote::StoreQuote;
+use ant_evm::{Amount, QuoteHash, QuotePayment, TxHash};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    future::Future,
+    path::PathBuf,
+    sync::{Arc, Mutex, MutexGuard},
+};
+use xor_name::XorName;
+
+/// A key chosen by the application to identify an upload across retries.
+///
+/// Uploading with a key that already has a recorded payment reuses that payment, rather than
+/// paying again, so an upload that failed ambiguously, e.g. after paying but before all the
+/// chunks were stored, or with only some of the payments made, can be retried safely.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct IdempotencyKey(String);
+
+impl IdempotencyKey {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self(key.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for IdempotencyKey {
+    fn from(key: &str) -> Self {
+        Self::new(key)
+    }
+}
+
+impl From<String> for IdempotencyKey {
+    fn from(key: String) -> Self {
+        Self(key)
+    }
+}
+
+impl std::fmt::Display for IdempotencyKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Where the payments recorded for idempotency keys are kept.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum IdempotencyPersistence {
+    /// Keep the records in memory, so they only cover retries made with the same client.
+    #[default]
+    Memory,
+    /// Keep the records in a file, so retries are also covered after the app restarts.
+    ///
+    /// The file is created on the first upload with a key.
+    File(PathBuf),
+}
+
+/// Errors reading or writing the idempotency records.
+#[derive(Debug, thiserror::Error)]
+pub enum IdempotencyStoreError {
+    #[error("Failed to read or write the idempotency records: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Failed to decode the idempotency records: {0}")]
+    Deserialization(#[from] rmp_serde::decode::Error),
+    #[error("Failed to encode the idempotency records: {0}")]
+    Serialization(#[from] rmp_serde::encode::Error),
+}
+
+/// The payment made for an upload, and whether the upload completed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct IdempotencyRecord {
+    /// The address of the data map chunk, so a key can't be reused for different data.
+    pub data_addr: XorName,
+    /// The quotes paid for. They are recorded before paying, so a retry pays for the same quotes.
+    pub receipt: Receipt,
+    pub completed: bool,
+    /// The payments that aren't known to have been made yet.
+    #[serde(default)]
+    pub unconfirmed_payments: Vec<QuotePayment>,
+}
+
+impl IdempotencyRecord {
+    /// Record the payments to make for the quotes, before any of them are made.
+    pub(crate) fn new(data_addr: XorName, quotes: StoreQuote) -> Self {
+        // Payments of nothing aren't made at all
+        let unconfirmed_payments = quotes
+            .payments()
+            .into_iter()
+            .filter(|(_, _, amount)| *amount > Amount::ZERO)
+            .collect();
+        Self {
+            data_addr,
+            receipt: receipt_from_store_quotes(quotes),
+            completed: false,
+            unconfirmed_payments,
+        }
+    }
+
+    /// Whether all the payments were made.
+    pub(crate) fn is_paid(&self) -> bool {
+        self.unconfirmed_payments.is_empty()
+    }
+
+    /// The hashes of the quotes whose payment wasn't confirmed yet.
+    pub(crate) fn unconfirmed_quotes(&self) -> BTreeSet<QuoteHash> {
+        self.unconfirmed_payments
+            .iter()
+            .map(|(quote_hash, _, _)| *quote_hash)
+            .collect()
+    }
+
+    /// Record that the payments for the quotes were made.
+    pub(crate) fn confirm_payments<'a>(&mut self, paid: impl IntoIterator<Item = &'a QuoteHash>) {
+        let paid: BTreeSet<_> = paid.into_iter().collect();
+        self.unconfirmed_payments
+            .retain(|(quote_hash, _, _)| !paid.contains(quote_hash));
+    }
+}
+
+/// Make the unconfirmed payments of the record of `key` with `pay`, recording the payments that
+/// were made even if paying fails part way, so a retry only makes the rest.
+///
+/// `pay` returns the transactions of the payments that were made, also when it fails.
+pub(crate) async fn pay_recorded<F, Fut>(
+    store: &IdempotencyStore,
+    key: &IdempotencyKey,
+    mut record: IdempotencyRecord,
+    pay: F,
+) -> Result<IdempotencyRecord, PayError>
+where
+    F: FnOnce(Vec<QuotePayment>) -> Fut,
+    Fut: Future<
+        Output = Result<BTreeMap<QuoteHash, TxHash>, (PayError, BTreeMap<QuoteHash, TxHash>)>,
+    >,
+{
+    if record.is_paid() {
+        return Ok(record);
+    }
+
+    let result = pay(record.unconfirmed_payments.clone()).await;
+    match &result {
+        Ok(_) => record.unconfirmed_payments.clear(),
+        Err((_, paid)) => record.confirm_payments(paid.keys()),
+    }
+    if let Err(err) = store.insert(key.clone(), record.clone()) {
+        // The payments were recorded as intended, so a retry checks which were made anyway
+        error!("Failed to record the payments for idempotency key {key}: {err}");
+    }
+
+    match result {
+        Ok(_) => Ok(record),
+        Err((err, _)) => {
+            info!(
+                "{} payments for idempotency key {key} are left to make after: {err:?}",
+                record.unconfirmed_payments.len()
+            );
+            Err(err)
+        }
+    }
+}
+
+/// The uploads recorded by idempotency key, optionally persisted to a file.
+///
+/// Clones of the store share the same records.
+#[derive(Clone, Default)]
+pub(crate) struct IdempotencyStore {
+    records: Arc<Mutex<HashMap<IdempotencyKey, IdempotencyRecord>>>,
+    path: Option<PathBuf>,
+}
+
+impl IdempotencyStore {
+    /// Open the store, loading the records persisted by earlier clients.
+    pub(crate) fn open(
+        persistence: &IdempotencyPersistence,
+    ) -> Result<Self, IdempotencyStoreError> {
+        let path = match persistence {
+            IdempotencyPersistence::Memory => return Ok(Self::default()),
+            IdempotencyPersistence::File(path) => path.clone(),
+        };
+
+        let records = if path.exists() {
+            let bytes = std::fs::read(&path)?;
+            let records: HashMap<IdempotencyKey, IdempotencyRecord> =
+                rmp_serde::from_slice(&bytes)?;
+            debug!("Loaded {} idempotency records from {path:?}", records.len());
+            records
+        } else {
+            HashMap::new()
+        };
+
+        Ok(Self {
+            records: Arc::new(Mutex::new(records)),
+            path: Some(path),
+        })
+    }
+
+    pub(crate) fn get(&self, key: &IdempotencyKey) -> Option<IdempotencyRecord> {
+        self.lock().get(key).cloned()
+    }
+
+    /// Record an upload, persisting the records before returning.
+    pub(crate) fn insert(
+        &self,
+        key: IdempotencyKey,
+        record: IdempotencyRecord,
+    ) -> Result<(), IdempotencyStoreError> {
+        let mut records = self.lock();
+        let _ = records.insert(key, record);
+        self.persist(&records)
+    }
+
+    /// Remove the record of a key, returning whether there was one.
+    pub(crate) fn remove(&self, key: &IdempotencyKey) -> Result<bool, IdempotencyStoreError> {
+        let mut records = self.lock();
+        if records.remove(key).is_none() {
+            return Ok(false);
+        }
+        self.persist(&records)?;
+        Ok(true)
+    }
+
+    fn persist(
+        &self,
+        records: &HashMap<IdempotencyKey, IdempotencyRecord>,
+    ) -> Result<(), IdempotencyStoreError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Write to a temporary file first, so a crash while writing doesn't lose the records.
+        let temp_path = path.with_extension("tmp");
+        std::fs::write(&temp_path, rmp_serde::to_vec(records)?)?;
+        std::fs::rename(&temp_path, path)?;
+        Ok(())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<IdempotencyKey, IdempotencyRecord>> {
+        // Records are only ever inserted or removed whole, so a panic while holding the lock doesn't matter.
+        self.records.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ant_evm::{AttoTokens, ProofOfPayment};
+
+    fn record(completed: bool) -> IdempotencyRecord {
+        let mut receipt = Receipt::new();
+        let _ = receipt.insert(
+            XorName::random(&mut rand::thread_rng()),
+            (
+                ProofOfPayment {
+                    peer_quotes: vec![],
+                },
+                AttoTokens::from_u64(10),
+            ),
+        );
+        IdempotencyRecord {
+            data_addr: XorName::random(&mut rand::thread_rng()),
+            receipt,
+            completed,
+            unconfirmed_payments: vec![],
+        }
+    }
+
+    #[test]
+    fn records_are_shared_by_clones() {
+        let store = IdempotencyStore::open(&IdempotencyPersistence::Memory).unwrap();
+        let clone = store.clone();
+        let key = IdempotencyKey::from("upload-1");
+
+        store.insert(key.clone(), record(false)).unwrap();
+        assert!(clone.get(&key).is_some());
+        assert!(clone.remove(&key).unwrap());
+        assert!(store.get(&key).is_none());
+        assert!(!store.remove(&key).unwrap());
+    }
+
+    #[test]
+    fn records_are_persisted_to_the_file() {
+        let path = std::env::temp_dir()
+            .join(format!("idempotency-{}", rand::random::<u64>()))
+            .join("records");
+        let persistence = IdempotencyPersistence::File(path.clone());
+        let key = IdempotencyKey::from("upload-1");
+        let inserted = record(true);
+
+        let store = IdempotencyStore::open(&persistence).unwrap();
+        store.insert(key.clone(), inserted.clone()).unwrap();
+        drop(store);
+
+        let reopened = IdempotencyStore::open(&persistence).unwrap();
+        let loaded = reopened.get(&key).expect("the record to be loaded");
+        assert_eq!(loaded.data_addr, inserted.data_addr);
+        assert_eq!(loaded.receipt, inserted.receipt);
+        assert!(loaded.completed);
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    fn quote_payment(n: u8) -> QuotePayment {
+        (
+            QuoteHash::repeat_byte(n),
+            ant_evm::RewardsAddress::repeat_byte(n),
+            Amount::from(10u64),
+        )
+    }
+
+    #[test]
+    fn a_retry_after_a_payment_error_only_makes_the_remaining_payments() {
+        let store = IdempotencyStore::open(&IdempotencyPersistence::Memory).unwrap();
+        let key = IdempotencyKey::from("upload-1");
+        let mut intended = record(false);
+        intended.unconfirmed_payments = vec![quote_payment(1), quote_payment(2), quote_payment(3)];
+        // The intent is recorded before paying
+        store.insert(key.clone(), intended.clone()).unwrap();
+        assert!(!intended.is_paid());
+
+        // The first batch goes through, then paying fails
+        let result = futures::executor::block_on(pay_recorded(
+            &store,
+            &key,
+            intended,
+            |payments| async move {
+                assert_eq!(payments.len(), 3);
+                let paid = BTreeMap::from([(payments[0].0, TxHash::repeat_byte(1))]);
+                Err((
+                    PayError::EvmWalletError(ant_evm::EvmWalletError::PrivateKeyInvalid),
+                    paid,
+                ))
+            },
+        ));
+        assert!(result.is_err());
+        let recorded = store.get(&key).expect("the record to be kept");
+        assert_eq!(
+            recorded.unconfirmed_payments,
+            vec![quote_payment(2), quote_payment(3)]
+        );
+
+        // The retry pays for the same quotes, without the payment that was made
+        let paid = futures::executor::block_on(pay_recorded(
+            &store,
+            &key,
+            recorded,
+            |payments| async move {
+                assert_eq!(payments, vec![quote_payment(2), quote_payment(3)]);
+                Ok(payments
+                    .iter()
+                    .map(|(quote_hash, _, _)| (*quote_hash, TxHash::repeat_byte(2)))
+                    .collect())
+            },
+        ))
+        .unwrap();
+        assert!(paid.is_paid());
+        assert!(store.get(&key).unwrap().is_paid());
+
+        // Once paid, nothing is paid again
+        let result = futures::executor::block_on(pay_recorded(&store, &key, paid, |_| async {
+            Err((
+                PayError::EvmWalletError(ant_evm::EvmWalletError::PrivateKeyInvalid),
+                BTreeMap::new(),
+            ))
+        }));
+        assert!(result.is_ok());
+    }
+}
@@ -10,6 +10,11 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod address;
+#[cfg(feature = "registers")]
+#[cfg_attr(docsrs, doc(cfg(feature = "registers")))]
+pub mod event_log;
+pub mod events;
+pub mod idempotency;
 pub mod payment;
 pub mod quote;
 
@@ -31,14 +36,21 @@ pub mod vault;
 pub mod wasm;
 
 // private module with utility functions
+mod data_map_cache;
 mod rate_limiter;
 mod utils;
 
+pub use ant_bootstrap::ProxyConfig;
 use ant_bootstrap::{BootstrapCacheConfig, BootstrapCacheStore, PeersArgs};
 pub use ant_evm::Amount;
 use ant_evm::EvmNetwork;
 use ant_networking::{interval, multiaddr_is_global, Network, NetworkBuilder, NetworkEvent};
 use ant_protocol::version::IDENTIFY_PROTOCOL_STR;
+use data::{ChunkRetryConfig, DataAddr, DataMapChunk};
+use data_map_cache::DataMapCache;
+pub use data_map_cache::DEFAULT_DATA_MAP_CACHE_SIZE;
+pub use events::{ClientEvent, UploadSummary};
+use idempotency::{IdempotencyPersistence, IdempotencyStore, IdempotencyStoreError};
 use libp2p::{identity::Keypair, Multiaddr};
 use std::{collections::HashSet, sync::Arc, time::Duration};
 use tokio::sync::mpsc;
@@ -70,6 +82,9 @@ pub struct Client {
     pub(crate) network: Network,
     pub(crate) client_event_sender: Arc<Option<mpsc::Sender<ClientEvent>>>,
     pub(crate) evm_network: EvmNetwork,
+    pub(crate) chunk_retry_config: ChunkRetryConfig,
+    pub(crate) data_map_cache: DataMapCache,
+    pub(crate) idempotency_store: IdempotencyStore,
 }
 
 /// Configuration for [`Client::init_with_config`].
@@ -84,6 +99,38 @@ pub struct ClientConfig {
     ///
     /// If not provided, the client will use the default bootstrap peers.
     pub peers: Option<Vec<Multiaddr>>,
+
+    /// Retry behaviour for chunk uploads.
+    ///
+    /// Can also be changed after initialization using [`Client::set_chunk_retry_config`].
+    pub chunk_retry: ChunkRetryConfig,
+
+    /// Maximum number of data maps kept in memory after being fetched, so that reading the same
+    /// data again doesn't need to resolve its data map again. Set to 0 to disable the cache.
+    ///
+    /// Defaults to [`DEFAULT_DATA_MAP_CACHE_SIZE`].
+    pub data_map_cache_size: usize,
+
+    /// Keep all the state of the client in memory, without reading or writing any files.
+    ///
+    /// The bootstrap cache is neither loaded nor saved, so the initial peers come from `peers`,
+    /// the `ANT_PEERS` environment variable or the network contacts. Everything else the client
+    /// holds, e.g. cached data maps, is already kept in memory and is dropped along with it.
+    /// Useful for tests and short-lived apps that should leave nothing behind.
+    pub in_memory: bool,
+
+    /// Where the payments made for uploads with an idempotency key are recorded, see
+    /// [`Client::data_put_idempotent`].
+    ///
+    /// Defaults to memory. A file is not used when `in_memory` is set.
+    pub idempotency: IdempotencyPersistence,
+
+    /// The proxy used to fetch the network contacts.
+    ///
+    /// Defaults to [`ProxyConfig::System`], which follows the `HTTPS_PROXY`, `HTTP_PROXY`,
+    /// `ALL_PROXY` and `NO_PROXY` environment variables, or the system proxy on Windows and macOS.
+    /// The connections to the peers are not proxied.
+    pub proxy: ProxyConfig,
 }
 
 impl Default for ClientConfig {
@@ -94,6 +141,11 @@ impl Default for ClientConfig {
             #[cfg(not(feature = "local"))]
             local: false,
             peers: None,
+            chunk_retry: Default::default(),
+            data_map_cache_size: DEFAULT_DATA_MAP_CACHE_SIZE,
+            in_memory: false,
+            idempotency: IdempotencyPersistence::Memory,
+            proxy: ProxyConfig::System,
         }
     }
 }
@@ -112,6 +164,10 @@ pub enum ConnectError {
     /// An error occurred while bootstrapping the client.
     #[error("Failed to bootstrap the client")]
     Bootstrap(#[from] ant_bootstrap::Error),
+
+    /// The idempotency records could not be loaded.
+    #[error("Failed to load the idempotency records")]
+    IdempotencyStore(#[from] IdempotencyStoreError),
 }
 
 impl Client {
@@ -153,6 +209,23 @@ impl Client {
         Self::init_with_config(ClientConfig {
             local,
             peers: Some(peers),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Initialize a client that keeps all of its state in memory, bootstrapping from a list of peers.
+    ///
+    /// See [`ClientConfig::in_memory`].
+    pub async fn init_in_memory(peers: Vec<Multiaddr>) -> Result<Self, ConnectError> {
+        // Any global address makes the client non-local
+        let local = !peers.iter().any(multiaddr_is_global);
+
+        Self::init_with_config(ClientConfig {
+            local,
+            peers: Some(peers),
+            in_memory: true,
+            ..Default::default()
         })
         .await
     }
@@ -172,11 +245,18 @@ impl Client {
     /// # }
     /// ```
     pub async fn init_with_config(config: ClientConfig) -> Result<Self, ConnectError> {
-        let (network, event_receiver) = build_client_and_run_swarm(config.local);
+        let idempotency_store = if config.in_memory {
+            IdempotencyStore::default()
+        } else {
+            IdempotencyStore::open(&config.idempotency)?
+        };
+        let (network, event_receiver) = build_client_and_run_swarm(config.local, config.in_memory);
 
         let peers_args = PeersArgs {
             disable_mainnet_contacts: config.local,
             addrs: config.peers.unwrap_or_default(),
+            ignore_cache: config.in_memory,
+            proxy: config.proxy,
             ..Default::default()
         };
 
@@ -205,6 +285,9 @@ impl Client {
             network,
             client_event_sender: Arc::new(None),
             evm_network: Default::default(),
+            chunk_retry_config: config.chunk_retry,
+            data_map_cache: DataMapCache::new(config.data_map_cache_size),
+            idempotency_store,
         })
     }
 
@@ -230,7 +313,7 @@ impl Client {
         // Any global address makes the client non-local
         let local = !peers.iter().any(multiaddr_is_global);
 
-        let (network, event_receiver) = build_client_and_run_swarm(local);
+        let (network, event_receiver) = build_client_and_run_swarm(local, false);
 
         // Spawn task to dial to the given peers
         let network_clone = network.clone();
@@ -260,10 +343,15 @@ impl Client {
             network,
             client_event_sender: Arc::new(None),
             evm_network: Default::default(),
+            chunk_retry_config: Default::default(),
+            data_map_cache: DataMapCache::new(DEFAULT_DATA_MAP_CACHE_SIZE),
+            idempotency_store: IdempotencyStore::default(),
         })
     }
 
     /// Receive events from the client.
+    ///
+    /// See [`events::EventMessage`] to serialize them, e.g. for a user interface.
     pub fn enable_client_events(&mut self) -> mpsc::Receiver<ClientEvent> {
         let (client_event_sender, client_event_receiver) =
             tokio::sync::mpsc::channel(CLIENT_EVENT_CHANNEL_SIZE);
@@ -276,12 +364,40 @@ impl Client {
     pub fn set_evm_network(&mut self, evm_network: EvmNetwork) {
         self.evm_network = evm_network;
     }
+
+    /// Set the retry behaviour for chunk uploads.
+    ///
+    /// See [`ChunkRetryConfig`].
+    pub fn set_chunk_retry_config(&mut self, config: ChunkRetryConfig) {
+        self.chunk_retry_config = config;
+    }
+
+    /// Remove the cached data map of the public data at the given address, returning whether it was cached.
+    pub fn data_map_cache_invalidate(&self, addr: &DataAddr) -> bool {
+        self.data_map_cache.invalidate(addr)
+    }
+
+    /// Remove the cached data map of the given private data, returning whether it was cached.
+    pub fn data_map_cache_invalidate_private(&self, data_map: &DataMapChunk) -> bool {
+        self.data_map_cache.invalidate(data_map.0.name())
+    }
+
+    /// Remove all the cached data maps.
+    pub fn data_map_cache_clear(&self) {
+        self.data_map_cache.clear();
+    }
 }
 
-fn build_client_and_run_swarm(local: bool) -> (Network, mpsc::Receiver<NetworkEvent>) {
+fn build_client_and_run_swarm(
+    local: bool,
+    in_memory: bool,
+) -> (Network, mpsc::Receiver<NetworkEvent>) {
     let mut network_builder = NetworkBuilder::new(Keypair::generate_ed25519(), local);
 
-    if let Ok(mut config) = BootstrapCacheConfig::default_config() {
+    // Creating the bootstrap cache creates its directory, so it's skipped altogether in memory.
+    if in_memory {
+        debug!("Running in memory, the bootstrap cache is disabled");
+    } else if let Ok(mut config) = BootstrapCacheConfig::default_config() {
         if local {
             config.disable_cache_writing = true;
         }
@@ -361,16 +477,3 @@ async fn handle_event_receiver(
 
     // TODO: Handle closing of network events sender
 }
-
-/// Events that can be broadcasted by the client.
-#[derive(Debug, Clone)]
-pub enum ClientEvent {
-    UploadComplete(UploadSummary),
-}
-
-/// Summary of an upload operation.
-#[derive(Debug, Clone)]
-pub struct UploadSummary {
-    pub record_count: usize,
-    pub tokens_spent: Amount,
-}
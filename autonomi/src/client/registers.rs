@@ -8,7 +8,8 @@
 
 #![allow(deprecated)]
 
-use crate::client::data::PayError;
+use crate::client::data::{PayError, CHUNK_UPLOAD_BATCH_SIZE};
+use crate::client::utils::process_tasks_with_max_concurrency;
 use crate::client::Client;
 use crate::client::ClientEvent;
 use crate::client::UploadSummary;
@@ -16,17 +17,17 @@ use crate::client::UploadSummary;
 pub use ant_registers::{Permissions as RegisterPermissions, RegisterAddress};
 pub use bls::SecretKey as RegisterSecretKey;
 
-use ant_evm::{Amount, AttoTokens, EvmWallet, EvmWalletError};
+use ant_evm::{Amount, AttoTokens, EvmWallet, EvmWalletError, ProofOfPayment};
 use ant_networking::{GetRecordCfg, GetRecordError, NetworkError, PutRecordCfg, VerificationKind};
 use ant_protocol::{
     storage::{try_deserialize_record, try_serialize_record, RecordKind, RetryStrategy},
     NetworkAddress,
 };
 use ant_registers::Register as BaseRegister;
-use ant_registers::{Permissions, RegisterCrdt, RegisterOp, SignedRegister};
+use ant_registers::{EntryHash, Permissions, RegisterCrdt, RegisterOp, SignedRegister};
 use bytes::Bytes;
 use libp2p::kad::{Quorum, Record};
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap};
 use xor_name::XorName;
 
 use super::data::CostError;
@@ -53,6 +54,8 @@ pub enum RegisterError {
     InvalidQuote,
     #[error("The payment proof contains no payees.")]
     PayeesMissing,
+    #[error("Register {0} appears more than once in the batch")]
+    DuplicateInBatch(RegisterAddress),
 }
 
 #[deprecated(
@@ -70,6 +73,11 @@ impl Register {
         self.signed_reg.address()
     }
 
+    /// The keys allowed to write to the register.
+    pub(crate) fn permissions(&self) -> &RegisterPermissions {
+        self.signed_reg.base_register().permissions()
+    }
+
     /// Retrieve the current values of the register. There can be multiple values
     /// in case a register was updated concurrently. This is because of the nature
     /// of registers, which allows for network concurrency.
@@ -81,6 +89,23 @@ impl Register {
             .collect()
     }
 
+    /// Every value written to the register, keyed by the hash of its entry, along with the hashes
+    /// of the entries it was written atop of.
+    pub(crate) fn history(&self) -> HashMap<EntryHash, (Bytes, Vec<EntryHash>)> {
+        let mut history = HashMap::new();
+        let mut to_visit: Vec<_> = self.crdt_reg.read().into_iter().collect();
+        while let Some((hash, value)) = to_visit.pop() {
+            if history.contains_key(&hash) {
+                continue;
+            }
+            let previous = self.crdt_reg.children(&hash);
+            let previous_hashes = previous.iter().map(|(hash, _)| *hash).collect();
+            to_visit.extend(previous);
+            let _ = history.insert(hash, (Bytes::from(value), previous_hashes));
+        }
+        history
+    }
+
     fn new(
         initial_value: Option<Bytes>,
         name: XorName,
@@ -128,6 +153,54 @@ impl Register {
     }
 }
 
+/// A Register to create with [`Client::register_create_many`].
+#[derive(Clone, Debug)]
+pub struct RegisterCreation {
+    pub value: Option<Bytes>,
+    pub name: String,
+    pub owner: RegisterSecretKey,
+    pub permissions: RegisterPermissions,
+}
+
+impl RegisterCreation {
+    /// A Register with the owner as the only writer, like `register_create`.
+    pub fn new(value: Option<Bytes>, name: &str, owner: RegisterSecretKey) -> Self {
+        let permissions = Permissions::new_with([owner.public_key()]);
+        Self {
+            value,
+            name: name.to_string(),
+            owner,
+            permissions,
+        }
+    }
+
+    /// The address the Register will be stored at.
+    pub fn address(&self) -> RegisterAddress {
+        Client::register_address(&self.name, &self.owner)
+    }
+}
+
+/// The outcome of [`Client::register_create_many`].
+#[derive(Debug, Default)]
+pub struct RegisterBatchResult {
+    /// Registers that were paid for and stored on the network.
+    pub created: Vec<Register>,
+    /// Registers that already exist on the network. These were skipped and not paid for.
+    pub already_exist: Vec<RegisterAddress>,
+    /// Registers that could not be created. Calling `register_create_many` again with the same
+    /// batch skips the ones that were created, so only these are retried.
+    pub failed: Vec<(RegisterAddress, RegisterError)>,
+    /// The total amount paid for the batch, including payments for registers that then failed to be stored.
+    pub tokens_spent: Amount,
+}
+
+impl RegisterBatchResult {
+    /// Whether every Register of the batch now exists on the network.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
 #[deprecated(
     since = "0.2.4",
     note = "Use transactions instead (see Client::transaction_put)"
@@ -330,6 +403,118 @@ impl Client {
             return Err(RegisterError::Network(NetworkError::RegisterAlreadyExists));
         };
 
+        self.register_upload_with_payment(&register, proof).await?;
+
+        if let Some(channel) = self.client_event_sender.as_ref() {
+            let summary = UploadSummary {
+                record_count: 1,
+                tokens_spent: price.as_atto(),
+            };
+            if let Err(err) = channel.send(ClientEvent::UploadComplete(summary)).await {
+                error!("Failed to send client event: {err}");
+            }
+        }
+
+        Ok(register)
+    }
+
+    /// Creates many Registers at once.
+    ///
+    /// All the Registers are quoted and paid for in a single payment, then stored on the network in parallel.
+    /// Registers that already exist are skipped without being paid for, which makes retrying a partially
+    /// failed batch safe. A failure to store one Register doesn't stop the others, see [`RegisterBatchResult`].
+    pub async fn register_create_many(
+        &self,
+        registers: impl IntoIterator<Item = RegisterCreation>,
+        wallet: &EvmWallet,
+    ) -> Result<RegisterBatchResult, RegisterError> {
+        let mut result = RegisterBatchResult::default();
+
+        let mut to_create = HashMap::new();
+        for creation in registers {
+            let address = creation.address();
+            let reg_xor = address.xorname();
+            if to_create.contains_key(&reg_xor) {
+                warn!("Register at address: {address} appears more than once in the batch");
+                result
+                    .failed
+                    .push((address, RegisterError::DuplicateInBatch(address)));
+                continue;
+            }
+
+            let name = XorName::from_content_parts(&[creation.name.as_bytes()]);
+            match Register::new(creation.value, name, creation.owner, creation.permissions) {
+                Ok(register) => {
+                    let _ = to_create.insert(reg_xor, register);
+                }
+                Err(err) => result.failed.push((address, err)),
+            }
+        }
+
+        if to_create.is_empty() {
+            return Ok(result);
+        }
+
+        info!("Creating a batch of {} registers", to_create.len());
+        let receipt = self
+            .pay(to_create.keys().copied(), wallet)
+            .await
+            .inspect_err(|err| error!("Failed to pay for the batch of registers: {err}"))?;
+
+        let mut upload_tasks = vec![];
+        for (reg_xor, register) in to_create {
+            let Some((proof, price)) = receipt.get(&reg_xor) else {
+                // register was skipped, meaning it was already paid for
+                debug!(
+                    "Register at address: {} already exists, skipping it",
+                    register.address()
+                );
+                result.already_exist.push(*register.address());
+                continue;
+            };
+            result.tokens_spent += price.as_atto();
+
+            upload_tasks.push(async move {
+                let outcome = self.register_upload_with_payment(&register, proof).await;
+                (register, outcome)
+            });
+        }
+
+        for (register, outcome) in
+            process_tasks_with_max_concurrency(upload_tasks, *CHUNK_UPLOAD_BATCH_SIZE).await
+        {
+            match outcome {
+                Ok(()) => result.created.push(register),
+                Err(err) => result.failed.push((*register.address(), err)),
+            }
+        }
+        debug!(
+            "Created {} registers, skipped {} existing ones and failed to create {}",
+            result.created.len(),
+            result.already_exist.len(),
+            result.failed.len()
+        );
+
+        if let Some(channel) = self.client_event_sender.as_ref() {
+            let summary = UploadSummary {
+                record_count: result.created.len(),
+                tokens_spent: result.tokens_spent,
+            };
+            if let Err(err) = channel.send(ClientEvent::UploadComplete(summary)).await {
+                error!("Failed to send client event: {err}");
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Store a newly created Register on the network, using the given proof of payment.
+    async fn register_upload_with_payment(
+        &self,
+        register: &Register,
+        proof: &ProofOfPayment,
+    ) -> Result<(), RegisterError> {
+        let address = register.address();
         let payees = proof.payees();
         let signed_register = register.signed_reg.clone();
 
@@ -368,16 +553,6 @@ impl Client {
                 error!("Failed to put record - register {address} to the network: {err}")
             })?;
 
-        if let Some(channel) = self.client_event_sender.as_ref() {
-            let summary = UploadSummary {
-                record_count: 1,
-                tokens_spent: price.as_atto(),
-            };
-            if let Err(err) = channel.send(ClientEvent::UploadComplete(summary)).await {
-                error!("Failed to send client event: {err}");
-            }
-        }
-
-        Ok(register)
+        Ok(())
     }
 }
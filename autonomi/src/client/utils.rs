@@ -17,7 +17,7 @@ use bytes::Bytes;
 use futures::stream::{FuturesUnordered, StreamExt};
 use libp2p::kad::{Quorum, Record};
 use rand::{thread_rng, Rng};
-use self_encryption::{decrypt_full_set, DataMap, EncryptedChunk};
+use self_encryption::{decrypt_full_set, decrypt_range, ChunkInfo, DataMap, EncryptedChunk};
 use std::{future::Future, num::NonZero};
 use xor_name::XorName;
 
@@ -31,8 +31,48 @@ impl Client {
     /// Fetch and decrypt all chunks in the data map.
     pub(crate) async fn fetch_from_data_map(&self, data_map: &DataMap) -> Result<Bytes, GetError> {
         debug!("Fetching encrypted data chunks from data map {data_map:?}");
+        let encrypted_chunks = self.fetch_encrypted_chunks(data_map.infos()).await?;
+
+        let data = decrypt_full_set(data_map, &encrypted_chunks).map_err(|e| {
+            error!("Error decrypting encrypted_chunks: {e:?}");
+            GetError::Decryption(crate::self_encryption::Error::SelfEncryption(e))
+        })?;
+        debug!("Successfully decrypted all the chunks");
+        Ok(data)
+    }
+
+    /// Fetch and decrypt `len` bytes of the data, starting at `offset`.
+    ///
+    /// Only the chunks covering the range are fetched. The range is truncated at the end of the data.
+    pub(crate) async fn fetch_range_from_data_map(
+        &self,
+        data_map: &DataMap,
+        offset: usize,
+        len: usize,
+    ) -> Result<Bytes, GetError> {
+        let Some((infos, relative_pos, len)) = chunks_covering_range(data_map, offset, len) else {
+            return Ok(Bytes::new());
+        };
+        debug!(
+            "Fetching {} encrypted data chunks for {len} bytes at offset {offset}",
+            infos.len()
+        );
+        let encrypted_chunks = self.fetch_encrypted_chunks(infos).await?;
+
+        let data = decrypt_range(data_map, &encrypted_chunks, relative_pos, len).map_err(|e| {
+            error!("Error decrypting encrypted_chunks: {e:?}");
+            GetError::Decryption(crate::self_encryption::Error::SelfEncryption(e))
+        })?;
+        debug!("Successfully decrypted the chunks of the range");
+        Ok(data)
+    }
+
+    async fn fetch_encrypted_chunks(
+        &self,
+        infos: Vec<ChunkInfo>,
+    ) -> Result<Vec<EncryptedChunk>, GetError> {
         let mut download_tasks = vec![];
-        for info in data_map.infos() {
+        for info in infos {
             download_tasks.push(async move {
                 match self
                     .chunk_get(info.dst_hash)
@@ -50,46 +90,33 @@ impl Client {
                 }
             });
         }
-        debug!("Successfully fetched all the encrypted chunks");
         let encrypted_chunks =
             process_tasks_with_max_concurrency(download_tasks, *CHUNK_DOWNLOAD_BATCH_SIZE)
                 .await
                 .into_iter()
                 .collect::<Result<Vec<EncryptedChunk>, GetError>>()?;
-
-        let data = decrypt_full_set(data_map, &encrypted_chunks).map_err(|e| {
-            error!("Error decrypting encrypted_chunks: {e:?}");
-            GetError::Decryption(crate::self_encryption::Error::SelfEncryption(e))
-        })?;
-        debug!("Successfully decrypted all the chunks");
-        Ok(data)
+        debug!("Successfully fetched all the encrypted chunks");
+        Ok(encrypted_chunks)
     }
 
-    /// Unpack a wrapped data map and fetch all bytes using self-encryption.
-    pub(crate) async fn fetch_from_data_map_chunk(
+    /// Unpack a wrapped data map, fetching the additional levels of large data maps.
+    pub(crate) async fn resolve_data_map(
         &self,
         data_map_bytes: &Bytes,
-    ) -> Result<Bytes, GetError> {
+    ) -> Result<DataMap, GetError> {
         let mut data_map_level: DataMapLevel = rmp_serde::from_slice(data_map_bytes)
             .map_err(GetError::InvalidDataMap)
             .inspect_err(|err| error!("Error deserializing data map: {err:?}"))?;
 
         loop {
-            let data_map = match &data_map_level {
-                DataMapLevel::First(map) => map,
-                DataMapLevel::Additional(map) => map,
-            };
-
-            let data = self.fetch_from_data_map(data_map).await?;
-
-            match &data_map_level {
-                DataMapLevel::First(_) => break Ok(data),
-                DataMapLevel::Additional(_) => {
+            match data_map_level {
+                DataMapLevel::First(data_map) => break Ok(data_map),
+                DataMapLevel::Additional(data_map) => {
+                    let data = self.fetch_from_data_map(&data_map).await?;
                     data_map_level = rmp_serde::from_slice(&data).map_err(|err| {
                         error!("Error deserializing data map: {err:?}");
                         GetError::InvalidDataMap(err)
                     })?;
-                    continue;
                 }
             };
         }
@@ -224,3 +251,75 @@ where
 
     results
 }
+
+/// The chunks covering `len` bytes from `offset`, with the position of `offset` within the first of
+/// them and the number of bytes of the range that are part of the data.
+///
+/// Returns `None` if the range is empty.
+fn chunks_covering_range(
+    data_map: &DataMap,
+    offset: usize,
+    len: usize,
+) -> Option<(Vec<ChunkInfo>, usize, usize)> {
+    let end = offset.saturating_add(len).min(data_map.file_size());
+    if offset >= end {
+        return None;
+    }
+
+    let mut infos = vec![];
+    let mut relative_pos = 0;
+    let mut chunk_start = 0;
+    // the chunk infos are sorted by index
+    for info in data_map.infos() {
+        let chunk_end = chunk_start + info.src_size;
+        if chunk_end > offset && chunk_start < end {
+            if infos.is_empty() {
+                relative_pos = offset - chunk_start;
+            }
+            infos.push(info);
+        }
+        chunk_start = chunk_end;
+    }
+
+    Some((infos, relative_pos, end - offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_is_decrypted_from_the_chunks_covering_it() {
+        let mut data = vec![0u8; 3 * 1024 * 1024 + 1000];
+        thread_rng().fill(&mut data[..]);
+        let data = Bytes::from(data);
+        let (data_map, encrypted_chunks) =
+            self_encryption::encrypt(data.clone()).expect("data to be encrypted");
+        assert!(data_map.infos().len() > 3);
+
+        let chunk_size = data_map.infos()[0].src_size;
+        for (offset, len) in [
+            (0, 10),
+            (chunk_size - 5, 10),
+            (chunk_size + 1, 2 * chunk_size),
+            (data.len() - 10, 100),
+        ] {
+            let (infos, relative_pos, range_len) =
+                chunks_covering_range(&data_map, offset, len).expect("range to not be empty");
+            let chunks: Vec<_> = encrypted_chunks
+                .iter()
+                .filter(|chunk| infos.iter().any(|info| info.index == chunk.index))
+                .cloned()
+                .collect();
+            assert!(chunks.len() < encrypted_chunks.len());
+
+            let range = decrypt_range(&data_map, &chunks, relative_pos, range_len)
+                .expect("range to be decrypted");
+            let end = (offset + len).min(data.len());
+            assert_eq!(range, data.slice(offset..end));
+        }
+
+        assert!(chunks_covering_range(&data_map, data.len(), 10).is_none());
+        assert!(chunks_covering_range(&data_map, 10, 0).is_none());
+    }
+}
@@ -50,11 +50,20 @@
 //! # Features
 //!
 //! - `fs`: Up/download files and directories from filesystem
-//! - `registers`: Operate on register datatype
-//! - `vault`: Operate on Vault datatype
+//! - `registers` (experimental): Operate on register datatype, and on event logs built on registers
+//! - `vault` (experimental): Operate on Vault datatype
+//! - `external-signer` (experimental): Delegate payments for uploads to an external signer
+//! - `experimental`: All of the experimental features
 //! - `full`: All of above
 //! - `local`: Discover local peers using mDNS. Useful for development.
 //! - `loud`: Print debug information to stdout
+//!
+//! # Stability
+//!
+//! The core API, to connect, put and get data, and pay with a wallet, is always available and
+//! follows semantic versioning. The APIs behind experimental features are still taking shape and
+//! may change in any release, even a patch release. Enable them individually, rather than through
+//! `experimental` or `full`, so it is clear which of them a crate depends on.
 
 // docs.rs generation will enable unstable `doc_cfg` feature
 #![cfg_attr(docsrs, feature(doc_cfg))]
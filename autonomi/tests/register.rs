@@ -10,7 +10,7 @@
 #![allow(deprecated)]
 
 use ant_logging::LogBuilder;
-use autonomi::Client;
+use autonomi::{client::registers::RegisterCreation, Client};
 use bytes::Bytes;
 use eyre::Result;
 use rand::Rng;
@@ -63,3 +63,41 @@ async fn register() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn register_create_many() -> Result<()> {
+    let _log_appender_guard =
+        LogBuilder::init_single_threaded_tokio_test("register_create_many", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+
+    let key = bls::SecretKey::random();
+    let batch: Vec<_> = (0..5)
+        .map(|i| {
+            let name: String = rand::thread_rng()
+                .sample_iter(&rand::distributions::Alphanumeric)
+                .take(10)
+                .map(char::from)
+                .collect();
+            RegisterCreation::new(Some(vec![i].into()), &name, key.clone())
+        })
+        .collect();
+
+    let result = client
+        .register_create_many(batch.clone(), &wallet)
+        .await
+        .unwrap();
+    assert!(result.is_complete());
+    assert_eq!(result.created.len(), 5);
+
+    sleep(Duration::from_secs(10)).await;
+
+    // Creating the same batch again skips the existing registers
+    let result = client.register_create_many(batch, &wallet).await.unwrap();
+    assert!(result.is_complete());
+    assert!(result.created.is_empty());
+    assert_eq!(result.already_exist.len(), 5);
+
+    Ok(())
+}
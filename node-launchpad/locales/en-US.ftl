@@ -0,0 +1,195 @@
+# English (United States) strings for the Autonomi Node Launchpad.
+# This is the reference locale: every other locale must define the same messages.
+
+## Header
+
+header-title = Autonomi Node Launchpad (v{ $version })
+header-status = [S]tatus
+header-options = [O]ptions
+header-help = [H]elp
+
+## Footer
+
+footer-manage-nodes = Manage Nodes
+footer-start-nodes = Start Nodes
+footer-open-logs = Open Logs
+footer-stop-all = Stop All
+
+## Help
+
+help-title = Get Help & Support
+help-quick-start = Read the quick start guides:
+help-direct-support = Get Direct Support:
+help-download-latest = Download the latest launchpad:
+help-terms = Terms & Conditions:
+
+## Options
+
+options-device-title = Device Options
+options-storage-drive = Storage Drive:
+options-change-drive = Change Drive
+options-connection-mode = Connection Mode:
+options-change-mode = Change Mode
+options-port-range = Port Range:
+options-port-range-auto = Auto
+options-edit-port-range = Edit Port Range
+options-language = Language:
+options-change-language = Change Language
+options-wallet-title = Wallet
+options-wallet-address = Wallet Address:
+options-add-wallet = Add Wallet
+options-change-wallet = Change Wallet
+options-access-logs-title = Access Logs
+options-access-logs = Access Logs
+options-open-logs-folder = Open the Logs folder on this device
+options-update-nodes-title = Update Nodes
+options-upgrade-all-nodes = Upgrade all Nodes
+options-begin-upgrade = Begin Upgrade
+options-reset-all-nodes = Reset all Nodes on this device
+options-begin-reset = Begin Reset
+options-quit = Quit
+options-close-launchpad = Close Launchpad (your nodes will keep running in the background)
+
+## Connection modes
+
+connection-mode-automatic = Automatic
+connection-mode-home-network = Home Network
+connection-mode-upnp = UPnP
+connection-mode-custom-ports = Custom Ports
+
+## Status
+
+status-device-title = Device Status
+status-storage-allocated = Storage Allocated
+status-memory-use = Memory Use
+status-connection = Connection
+status-attos-earned = Attos Earned
+status-press = Press
+status-to-add-your = to add your
+status-wallet-address = Wallet Address
+status-to-add-and = to Add and
+status-start-nodes = Start Nodes
+status-on-this-device = on this device
+status-node-resources = Each node will use { $gb_per_node }GB of storage and a small amount of memory, CPU, and Network bandwidth. Most computers can run many nodes at once, but we recommend you add them gradually
+status-nodes-title = Nodes
+status-column-node = Node
+status-column-version = Version
+status-column-attos = Attos
+status-column-memory = Memory
+status-column-records = Recs
+status-column-peers = Peers
+status-column-connections = Conns
+status-column-status = Status
+status-starting-nodes = Starting nodes...
+status-nat-detection = Please wait, performing initial NAT detection
+status-nat-detection-wait = This may take a couple minutes.
+status-stopping-nodes = Stopping nodes...
+status-resetting-nodes = Resetting nodes...
+status-manage-nodes-title = Manage Nodes
+status-error-registry-path = Error getting node registry path
+status-error-adding-nodes = Error adding new nodes
+status-error-stopping-nodes = Error stopping nodes
+status-error-upgrading-nodes = Error upgrading nodes
+status-error-resetting-nodes = Error resetting nodes
+
+node-status-added = Added
+node-status-running = Running
+node-status-starting = Starting
+node-status-stopped = Stopped
+node-status-removed = Removed
+node-status-updating = Updating
+
+## Errors
+
+error-title = Error
+
+## Shared popup strings
+
+popup-ok = OK
+popup-cancel = Cancel
+popup-no-cancel = No, Cancel
+popup-close = Close
+popup-back = Back
+popup-select = Select
+popup-are-you-sure = Are you sure you want to continue?
+popup-confirm-reset-title = Confirm & Reset
+popup-changing-connection-mode = Changing connection mode will
+popup-reset-all-nodes = reset all nodes.
+popup-you-need-to = You’ll need to
+popup-add = Add
+popup-and = and
+popup-start = Start
+popup-them-again-continue = them again afterwards. Are you sure you want to continue?
+popup-change-mode-confirm = Yes, Change Mode
+
+## Manage nodes popup
+
+manage-nodes-title = Manage Nodes
+manage-nodes-start = Start
+manage-nodes-nodes = Node(s)
+manage-nodes-using = Using
+manage-nodes-available-space = of { $available_space_gb }GB available space
+manage-nodes-note = Note: Each node will use a small amount of CPU Memory and Network Bandwidth. We recommend starting no more than 5 at a time (max { $max_node_count } nodes).
+manage-nodes-change-hint = ▲▼ to change the number of nodes to start.
+manage-nodes-confirm = Start Node(s)
+
+## Reset nodes popup
+
+reset-nodes-title = Reset Nodes
+reset-nodes-prompt = Type in 'reset' and press Enter to Reset all your nodes
+reset-nodes-warning = This will clear out all the nodes and all the stored data. You should still keep all your earned rewards.
+reset-nodes-confirm = Reset Nodes
+
+## Upgrade nodes popup
+
+upgrade-nodes-title = Upgrade all nodes
+upgrade-nodes-this-will = This will
+upgrade-nodes-stop-and-upgrade = stop and upgrade all nodes.
+upgrade-nodes-no-data-lost = No data will be lost.
+upgrade-nodes-time = Upgrade time ~ { $mins } mins ({ $nodes } nodes * { $secs } secs)
+upgrade-nodes-you-need-to = You’ll need to
+upgrade-nodes-start = Start
+upgrade-nodes-again-afterwards = them again afterwards.
+upgrade-nodes-confirm = Yes, Upgrade
+
+## Rewards address popup
+
+rewards-address-title = Add Your Wallet
+rewards-address-enter-new = Enter new
+rewards-address-enter-your = Enter your
+rewards-address-wallet-address = Wallet Address
+rewards-address-change-warning = Changing your Wallet will reset and restart
+rewards-address-change-warning-nodes = all your nodes.
+rewards-address-invalid = Invalid wallet address
+rewards-address-change-confirm = Change Wallet
+rewards-address-tcs-intro = Add your wallet to store your node earnings, and we'll pay you rewards to the same wallet after the Network's Token Generation Event.
+rewards-address-tcs-agree = By continuing you agree to the Terms and Conditions found here:
+rewards-address-tcs-accept = Yes, I agree! Continue
+rewards-address-find-out-more = Find out more about compatible wallets, and how to track your earnings:
+rewards-address-save-confirm = Save Wallet
+
+## Connection mode popup
+
+connection-mode-title = Connection Mode
+
+## Change drive popup
+
+change-drive-title = Select a Drive
+change-drive-select = Change Drive
+change-drive-changing-storage-to = Changing storage to
+change-drive-will = will
+change-drive-confirm = Yes, change drive
+change-drive-no-access = No Access
+
+## Port range popup
+
+port-range-title = Custom Ports
+port-range-prompt = Enter Port Number
+port-range-to = to
+port-range-choose-start = Choose the start of the range of { $ports } ports.
+port-range-must-be-between = This must be between { $min } and { $max }.
+port-range-save-confirm = Save Port Range
+port-range-forwarding-title = Port Forwarding For Private IPs
+port-range-private-ip = If you have a Private IP (which you probably do) you’ll now need to set your router to…
+port-range-forward-ports = Port Forward ports { $from }-{ $to }
+port-range-router-admin = You can do this in your router’s admin panel.
@@ -0,0 +1,194 @@
+# Spanish (Spain) strings for the Autonomi Node Launchpad.
+
+## Header
+
+header-title = Autonomi Node Launchpad (v{ $version })
+header-status = [S] Estado
+header-options = [O] Opciones
+header-help = [H] Ayuda
+
+## Footer
+
+footer-manage-nodes = Gestionar nodos
+footer-start-nodes = Iniciar nodos
+footer-open-logs = Abrir registros
+footer-stop-all = Detener todo
+
+## Help
+
+help-title = Ayuda y soporte
+help-quick-start = Lee las guías de inicio rápido:
+help-direct-support = Soporte directo:
+help-download-latest = Descarga el último launchpad:
+help-terms = Términos y condiciones:
+
+## Options
+
+options-device-title = Opciones del dispositivo
+options-storage-drive = Unidad de almacenamiento:
+options-change-drive = Cambiar unidad
+options-connection-mode = Modo de conexión:
+options-change-mode = Cambiar modo
+options-port-range = Rango de puertos:
+options-port-range-auto = Automático
+options-edit-port-range = Editar rango de puertos
+options-language = Idioma:
+options-change-language = Cambiar idioma
+options-wallet-title = Monedero
+options-wallet-address = Dirección del monedero:
+options-add-wallet = Añadir monedero
+options-change-wallet = Cambiar monedero
+options-access-logs-title = Acceso a los registros
+options-access-logs = Ver registros
+options-open-logs-folder = Abrir la carpeta de registros de este dispositivo
+options-update-nodes-title = Actualizar nodos
+options-upgrade-all-nodes = Actualizar todos los nodos
+options-begin-upgrade = Iniciar actualización
+options-reset-all-nodes = Restablecer todos los nodos de este dispositivo
+options-begin-reset = Iniciar restablecimiento
+options-quit = Salir
+options-close-launchpad = Cerrar el Launchpad (tus nodos seguirán funcionando en segundo plano)
+
+## Connection modes
+
+connection-mode-automatic = Automático
+connection-mode-home-network = Red doméstica
+connection-mode-upnp = UPnP
+connection-mode-custom-ports = Puertos personalizados
+
+## Status
+
+status-device-title = Estado del dispositivo
+status-storage-allocated = Almacenamiento asignado
+status-memory-use = Uso de memoria
+status-connection = Conexión
+status-attos-earned = Attos ganados
+status-press = Pulsa
+status-to-add-your = para añadir tu
+status-wallet-address = dirección de monedero
+status-to-add-and = para añadir e
+status-start-nodes = iniciar nodos
+status-on-this-device = en este dispositivo
+status-node-resources = Cada nodo usará { $gb_per_node }GB de almacenamiento y una pequeña cantidad de memoria, CPU y ancho de banda de red. La mayoría de los ordenadores pueden ejecutar muchos nodos a la vez, pero recomendamos añadirlos poco a poco
+status-nodes-title = Nodos
+status-column-node = Nodo
+status-column-version = Versión
+status-column-attos = Attos
+status-column-memory = Memoria
+status-column-records = Regs
+status-column-peers = Pares
+status-column-connections = Conex
+status-column-status = Estado
+status-starting-nodes = Iniciando nodos...
+status-nat-detection = Espera, realizando la detección inicial de NAT
+status-nat-detection-wait = Esto puede tardar un par de minutos.
+status-stopping-nodes = Deteniendo nodos...
+status-resetting-nodes = Restableciendo nodos...
+status-manage-nodes-title = Gestionar nodos
+status-error-registry-path = Error al obtener la ruta del registro de nodos
+status-error-adding-nodes = Error al añadir nuevos nodos
+status-error-stopping-nodes = Error al detener los nodos
+status-error-upgrading-nodes = Error al actualizar los nodos
+status-error-resetting-nodes = Error al restablecer los nodos
+
+node-status-added = Añadido
+node-status-running = Activo
+node-status-starting = Iniciando
+node-status-stopped = Detenido
+node-status-removed = Eliminado
+node-status-updating = Actualizando
+
+## Errors
+
+error-title = Error
+
+## Shared popup strings
+
+popup-ok = Aceptar
+popup-cancel = Cancelar
+popup-no-cancel = No, cancelar
+popup-close = Cerrar
+popup-back = Atrás
+popup-select = Seleccionar
+popup-are-you-sure = ¿Seguro que quieres continuar?
+popup-confirm-reset-title = Confirmar y restablecer
+popup-changing-connection-mode = Cambiar el modo de conexión
+popup-reset-all-nodes = restablecerá todos los nodos.
+popup-you-need-to = Tendrás que
+popup-add = añadirlos
+popup-and = e
+popup-start = iniciarlos
+popup-them-again-continue = de nuevo después. ¿Seguro que quieres continuar?
+popup-change-mode-confirm = Sí, cambiar modo
+
+## Manage nodes popup
+
+manage-nodes-title = Gestionar nodos
+manage-nodes-start = Iniciar
+manage-nodes-nodes = nodo(s)
+manage-nodes-using = Usando
+manage-nodes-available-space = de { $available_space_gb }GB de espacio disponible
+manage-nodes-note = Nota: cada nodo usará una pequeña cantidad de CPU, memoria y ancho de banda de red. Recomendamos no iniciar más de 5 a la vez (máximo { $max_node_count } nodos).
+manage-nodes-change-hint = ▲▼ para cambiar el número de nodos a iniciar.
+manage-nodes-confirm = Iniciar nodo(s)
+
+## Reset nodes popup
+
+reset-nodes-title = Restablecer nodos
+reset-nodes-prompt = Escribe 'reset' y pulsa Enter para restablecer todos tus nodos
+reset-nodes-warning = Esto eliminará todos los nodos y todos los datos almacenados. Conservarás todas las recompensas que hayas ganado.
+reset-nodes-confirm = Restablecer nodos
+
+## Upgrade nodes popup
+
+upgrade-nodes-title = Actualizar todos los nodos
+upgrade-nodes-this-will = Esto
+upgrade-nodes-stop-and-upgrade = detendrá y actualizará todos los nodos.
+upgrade-nodes-no-data-lost = No se perderá ningún dato.
+upgrade-nodes-time = Tiempo de actualización ~ { $mins } min ({ $nodes } nodos * { $secs } s)
+upgrade-nodes-you-need-to = Tendrás que
+upgrade-nodes-start = iniciarlos
+upgrade-nodes-again-afterwards = de nuevo después.
+upgrade-nodes-confirm = Sí, actualizar
+
+## Rewards address popup
+
+rewards-address-title = Añade tu monedero
+rewards-address-enter-new = Introduce la nueva
+rewards-address-enter-your = Introduce tu
+rewards-address-wallet-address = dirección de monedero
+rewards-address-change-warning = Cambiar tu monedero restablecerá y reiniciará
+rewards-address-change-warning-nodes = todos tus nodos.
+rewards-address-invalid = Dirección de monedero no válida
+rewards-address-change-confirm = Cambiar monedero
+rewards-address-tcs-intro = Añade tu monedero para guardar las ganancias de tus nodos; te pagaremos las recompensas en ese mismo monedero tras el evento de generación de tokens de la red.
+rewards-address-tcs-agree = Al continuar aceptas los términos y condiciones que encontrarás aquí:
+rewards-address-tcs-accept = ¡Sí, acepto! Continuar
+rewards-address-find-out-more = Descubre qué monederos son compatibles y cómo seguir tus ganancias:
+rewards-address-save-confirm = Guardar monedero
+
+## Connection mode popup
+
+connection-mode-title = Modo de conexión
+
+## Change drive popup
+
+change-drive-title = Selecciona una unidad
+change-drive-select = Cambiar unidad
+change-drive-changing-storage-to = Cambiar el almacenamiento a
+change-drive-will = {""}
+change-drive-confirm = Sí, cambiar unidad
+change-drive-no-access = Sin acceso
+
+## Port range popup
+
+port-range-title = Puertos personalizados
+port-range-prompt = Introduce el número de puerto
+port-range-to = a
+port-range-choose-start = Elige el inicio del rango de { $ports } puertos.
+port-range-must-be-between = Debe estar entre { $min } y { $max }.
+port-range-save-confirm = Guardar rango de puertos
+port-range-forwarding-title = Redirección de puertos para IP privadas
+port-range-private-ip = Si tienes una IP privada (lo más probable) tendrás que configurar tu router para…
+port-range-forward-ports = Redirigir los puertos { $from }-{ $to }
+port-range-router-admin = Puedes hacerlo en el panel de administración de tu router.
@@ -8,6 +8,7 @@
 
 use crate::{
     connection_mode::ConnectionMode,
+    i18n::Language,
     mode::{InputMode, Scene},
     node_stats::NodeStats,
 };
@@ -28,6 +29,7 @@ pub enum Action {
     StorePortRange(u32, u32),
     StoreRewardsAddress(String),
     StoreNodesToStart(usize),
+    StoreLanguage(Language),
 
     Tick,
     Render,
@@ -79,6 +81,7 @@ pub enum OptionsActions {
     TriggerUpdateNodes,
     TriggerResetNodes,
     TriggerAccessLogs,
+    TriggerChangeLanguage,
     UpdateConnectionMode(ConnectionMode),
     UpdatePortRange(u32, u32),
     UpdateRewardsAddress(String),
@@ -23,6 +23,7 @@ use crate::{
     },
     config::{get_launchpad_nodes_data_dir_path, AppData, Config},
     connection_mode::ConnectionMode,
+    i18n,
     mode::{InputMode, Scene},
     node_mgmt::{PORT_MAX, PORT_MIN},
     style::SPACE_CADET,
@@ -88,6 +89,8 @@ impl App {
             .storage_drive
             .clone()
             .unwrap_or(get_primary_mount_point_name()?);
+        let language = app_data.language.unwrap_or_default();
+        i18n::set_language(language);
 
         // Main Screens
         let status_config = StatusConfig {
@@ -110,6 +113,7 @@ impl App {
             connection_mode,
             Some(port_from),
             Some(port_to),
+            language,
         )
         .await?;
         let help = Help::new().await?;
@@ -134,6 +138,7 @@ impl App {
                 connection_mode: Some(connection_mode),
                 port_from: Some(port_from),
                 port_to: Some(port_to),
+                language: Some(language),
             },
             tick_rate,
             frame_rate,
@@ -290,6 +295,11 @@ impl App {
                         self.app_data.nodes_to_start = *count;
                         self.app_data.save(None)?;
                     }
+                    Action::StoreLanguage(ref language) => {
+                        debug!("Storing language: {language:?}");
+                        self.app_data.language = Some(*language);
+                        self.app_data.save(None)?;
+                    }
                     _ => {}
                 }
                 for component in self.components.iter_mut() {
@@ -6,7 +6,10 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-use crate::style::{COOL_GREY, EUCALYPTUS, GHOST_WHITE, LIGHT_PERIWINKLE};
+use crate::{
+    i18n::tr,
+    style::{COOL_GREY, EUCALYPTUS, GHOST_WHITE, LIGHT_PERIWINKLE},
+};
 use ratatui::{prelude::*, widgets::*};
 
 pub enum NodesToStart {
@@ -36,17 +39,17 @@ impl StatefulWidget for Footer {
 
         let commands = vec![
             Span::styled("[Ctrl+G] ", Style::default().fg(GHOST_WHITE)),
-            Span::styled("Manage Nodes", Style::default().fg(EUCALYPTUS)),
+            Span::styled(tr("footer-manage-nodes"), Style::default().fg(EUCALYPTUS)),
             Span::styled(" ", Style::default()),
             Span::styled("[Ctrl+S] ", command_style),
-            Span::styled("Start Nodes", text_style),
+            Span::styled(tr("footer-start-nodes"), text_style),
             Span::styled(" ", Style::default()),
             Span::styled("[L] ", command_style),
-            Span::styled("Open Logs", Style::default().fg(EUCALYPTUS)),
+            Span::styled(tr("footer-open-logs"), Style::default().fg(EUCALYPTUS)),
             Span::styled(" ", Style::default()),
             Span::styled("[Ctrl+X] ", command_style),
             Span::styled(
-                "Stop All",
+                tr("footer-stop-all"),
                 if matches!(state, NodesToStart::Running) {
                     Style::default().fg(EUCALYPTUS)
                 } else {
@@ -6,7 +6,10 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-use crate::style::{GHOST_WHITE, LIGHT_PERIWINKLE, VIVID_SKY_BLUE};
+use crate::{
+    i18n::{tr, tr_args},
+    style::{GHOST_WHITE, LIGHT_PERIWINKLE, VIVID_SKY_BLUE},
+};
 use ratatui::{prelude::*, widgets::*};
 
 pub enum SelectedMenuItem {
@@ -45,7 +48,13 @@ impl StatefulWidget for Header {
 
         // Define content of the header
         let application_text = Span::styled(
-            format!(" Autonomi Node Launchpad (v{})", self.launchpad_version_str),
+            format!(
+                " {}",
+                tr_args(
+                    "header-title",
+                    &[("version", self.launchpad_version_str.clone())]
+                )
+            ),
             Style::default().fg(LIGHT_PERIWINKLE),
         );
 
@@ -69,9 +78,9 @@ impl StatefulWidget for Header {
         };
 
         // Create styled spans for each part of the menu
-        let status = Span::styled("[S]tatus", Style::default().fg(status_color));
-        let options = Span::styled("[O]ptions", Style::default().fg(options_color));
-        let help = Span::styled("[H]elp", Style::default().fg(help_color));
+        let status = Span::styled(tr("header-status"), Style::default().fg(status_color));
+        let options = Span::styled(tr("header-options"), Style::default().fg(options_color));
+        let help = Span::styled(tr("header-help"), Style::default().fg(help_color));
 
         // Combine the menu parts with separators
         let menu = vec![
@@ -13,6 +13,7 @@ use super::Component;
 use crate::{
     action::Action,
     components::header::Header,
+    i18n::tr,
     mode::{InputMode, Scene},
     style::{COOL_GREY, GHOST_WHITE, VIVID_SKY_BLUE},
     widgets::hyperlink::Hyperlink,
@@ -127,34 +128,28 @@ impl Component for Help {
             .borders(Borders::ALL)
             .border_style(Style::default().fg(COOL_GREY))
             .padding(Padding::uniform(1))
-            .title(" Get Help & Support ")
+            .title(format!(" {} ", tr("help-title")))
             .bold()
             .title_style(Style::default().bold().fg(GHOST_WHITE));
 
         // Render hyperlinks in the new area
         f.render_widget(
-            Span::styled(
-                "Read the quick start guides:",
-                Style::default().fg(GHOST_WHITE),
-            ),
+            Span::styled(tr("help-quick-start"), Style::default().fg(GHOST_WHITE)),
             left_column[0],
         );
         f.render_widget_ref(quickstart_guide_link, left_column[1]);
         f.render_widget(
-            Span::styled("Get Direct Support:", Style::default().fg(GHOST_WHITE)),
+            Span::styled(tr("help-direct-support"), Style::default().fg(GHOST_WHITE)),
             left_column[2],
         );
         f.render_widget_ref(get_direct_support_link, left_column[3]);
         f.render_widget(
-            Span::styled(
-                "Download the latest launchpad:",
-                Style::default().fg(GHOST_WHITE),
-            ),
+            Span::styled(tr("help-download-latest"), Style::default().fg(GHOST_WHITE)),
             right_column[0],
         );
         f.render_widget_ref(download_latest_link, right_column[1]);
         f.render_widget(
-            Span::styled("Terms & Conditions:", Style::default().fg(GHOST_WHITE)),
+            Span::styled(tr("help-terms"), Style::default().fg(GHOST_WHITE)),
             right_column[2],
         );
         f.render_widget_ref(terms_and_conditions_link, right_column[3]);
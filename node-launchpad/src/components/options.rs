@@ -15,6 +15,7 @@ use crate::{
     action::{Action, OptionsActions},
     components::header::Header,
     connection_mode::ConnectionMode,
+    i18n::{self, tr, Language},
     mode::{InputMode, Scene},
     style::{
         COOL_GREY, EUCALYPTUS, GHOST_WHITE, LIGHT_PERIWINKLE, VERY_LIGHT_AZURE, VIVID_SKY_BLUE,
@@ -30,6 +31,7 @@ pub struct Options {
     pub port_edit: bool,
     pub port_from: Option<u32>,
     pub port_to: Option<u32>,
+    pub language: Language,
     pub active: bool,
     pub action_tx: Option<UnboundedSender<Action>>,
 }
@@ -42,6 +44,7 @@ impl Options {
         connection_mode: ConnectionMode,
         port_from: Option<u32>,
         port_to: Option<u32>,
+        language: Language,
     ) -> Result<Self> {
         Ok(Self {
             storage_mountpoint,
@@ -51,6 +54,7 @@ impl Options {
             port_edit: false,
             port_from,
             port_to,
+            language,
             active: false,
             action_tx: None,
         })
@@ -68,7 +72,7 @@ impl Component for Options {
             .constraints(
                 [
                     Constraint::Length(1),
-                    Constraint::Length(7),
+                    Constraint::Length(8),
                     Constraint::Length(3),
                     Constraint::Length(3),
                     Constraint::Length(4),
@@ -83,10 +87,24 @@ impl Component for Options {
         f.render_stateful_widget(header, layout[0], &mut SelectedMenuItem::Options);
 
         // Storage Drive
-        let port_legend = " Edit Port Range ";
+        let port_legend = format!(" {} ", tr("options-edit-port-range"));
         let port_key = " [Ctrl+P] ";
+        let language_legend = format!(" {} ", tr("options-change-language"));
+        let language_key = " [Ctrl+T] ";
+        let device_labels = [
+            format!(" {} ", tr("options-storage-drive")),
+            format!(" {} ", tr("options-connection-mode")),
+            format!(" {} ", tr("options-port-range")),
+            format!(" {} ", tr("options-language")),
+        ];
+        let label_width = device_labels
+            .iter()
+            .map(|label| label.chars().count())
+            .max()
+            .unwrap_or_default() as u16;
+        let [storage_label, connection_label, port_label, language_label] = device_labels;
         let block1 = Block::default()
-            .title(" Device Options ")
+            .title(format!(" {} ", tr("options-device-title")))
             .title_style(Style::default().bold().fg(GHOST_WHITE))
             .style(Style::default().fg(GHOST_WHITE))
             .borders(Borders::ALL)
@@ -97,7 +115,7 @@ impl Component for Options {
                 Row::new(vec![
                     Cell::from(
                         Line::from(vec![Span::styled(
-                            " Storage Drive: ",
+                            storage_label,
                             Style::default().fg(LIGHT_PERIWINKLE),
                         )])
                         .alignment(Alignment::Left),
@@ -111,7 +129,10 @@ impl Component for Options {
                     ),
                     Cell::from(
                         Line::from(vec![
-                            Span::styled(" Change Drive ", Style::default().fg(VERY_LIGHT_AZURE)),
+                            Span::styled(
+                                format!(" {} ", tr("options-change-drive")),
+                                Style::default().fg(VERY_LIGHT_AZURE),
+                            ),
                             Span::styled(" [Ctrl+D] ", Style::default().fg(GHOST_WHITE)),
                         ])
                         .alignment(Alignment::Right),
@@ -120,7 +141,7 @@ impl Component for Options {
                 Row::new(vec![
                     Cell::from(
                         Line::from(vec![Span::styled(
-                            " Connection Mode: ",
+                            connection_label,
                             Style::default().fg(LIGHT_PERIWINKLE),
                         )])
                         .alignment(Alignment::Left),
@@ -134,7 +155,10 @@ impl Component for Options {
                     ),
                     Cell::from(
                         Line::from(vec![
-                            Span::styled(" Change Mode ", Style::default().fg(VERY_LIGHT_AZURE)),
+                            Span::styled(
+                                format!(" {} ", tr("options-change-mode")),
+                                Style::default().fg(VERY_LIGHT_AZURE),
+                            ),
                             Span::styled(" [Ctrl+K] ", Style::default().fg(GHOST_WHITE)),
                         ])
                         .alignment(Alignment::Right),
@@ -143,7 +167,7 @@ impl Component for Options {
                 Row::new(vec![
                     Cell::from(
                         Line::from(vec![Span::styled(
-                            " Port Range: ",
+                            port_label,
                             Style::default().fg(LIGHT_PERIWINKLE),
                         )])
                         .alignment(Alignment::Left),
@@ -160,7 +184,10 @@ impl Component for Options {
                                     Style::default().fg(VIVID_SKY_BLUE),
                                 )
                             } else {
-                                Span::styled(" Auto ", Style::default().fg(COOL_GREY))
+                                Span::styled(
+                                    format!(" {} ", tr("options-port-range-auto")),
+                                    Style::default().fg(COOL_GREY),
+                                )
                             },
                         ])
                         .alignment(Alignment::Left),
@@ -168,7 +195,10 @@ impl Component for Options {
                     Cell::from(
                         Line::from(if self.connection_mode == ConnectionMode::CustomPorts {
                             vec![
-                                Span::styled(port_legend, Style::default().fg(VERY_LIGHT_AZURE)),
+                                Span::styled(
+                                    port_legend.clone(),
+                                    Style::default().fg(VERY_LIGHT_AZURE),
+                                ),
                                 Span::styled(port_key, Style::default().fg(GHOST_WHITE)),
                             ]
                         } else {
@@ -177,12 +207,41 @@ impl Component for Options {
                         .alignment(Alignment::Right),
                     ),
                 ]),
+                Row::new(vec![
+                    Cell::from(
+                        Line::from(vec![Span::styled(
+                            language_label,
+                            Style::default().fg(LIGHT_PERIWINKLE),
+                        )])
+                        .alignment(Alignment::Left),
+                    ),
+                    Cell::from(
+                        Line::from(vec![Span::styled(
+                            format!(" {} ", self.language),
+                            Style::default().fg(VIVID_SKY_BLUE),
+                        )])
+                        .alignment(Alignment::Left),
+                    ),
+                    Cell::from(
+                        Line::from(vec![
+                            Span::styled(
+                                language_legend.clone(),
+                                Style::default().fg(VERY_LIGHT_AZURE),
+                            ),
+                            Span::styled(language_key, Style::default().fg(GHOST_WHITE)),
+                        ])
+                        .alignment(Alignment::Right),
+                    ),
+                ]),
                 Row::new(vec![Line::from(vec![])]),
             ],
             &[
-                Constraint::Length(18),
+                Constraint::Length(label_width),
                 Constraint::Fill(1),
-                Constraint::Length((port_legend.len() + port_key.len()) as u16),
+                Constraint::Length(
+                    (max(port_legend.len(), language_legend.len())
+                        + max(port_key.len(), language_key.len())) as u16,
+                ),
             ],
         )
         .block(block1)
@@ -190,13 +249,14 @@ impl Component for Options {
 
         // Beta Rewards Program
         let beta_legend = if self.rewards_address.is_empty() {
-            " Add Wallet "
+            format!(" {} ", tr("options-add-wallet"))
         } else {
-            " Change Wallet "
+            format!(" {} ", tr("options-change-wallet"))
         };
+        let wallet_label = format!(" {} ", tr("options-wallet-address"));
         let beta_key = " [Ctrl+B] ";
         let block2 = Block::default()
-            .title(" Wallet ")
+            .title(format!(" {} ", tr("options-wallet-title")))
             .title_style(Style::default().bold().fg(GHOST_WHITE))
             .style(Style::default().fg(GHOST_WHITE))
             .borders(Borders::ALL)
@@ -205,7 +265,7 @@ impl Component for Options {
             vec![Row::new(vec![
                 Cell::from(
                     Line::from(vec![Span::styled(
-                        " Wallet Address: ",
+                        wallet_label.clone(),
                         Style::default().fg(LIGHT_PERIWINKLE),
                     )])
                     .alignment(Alignment::Left),
@@ -219,14 +279,14 @@ impl Component for Options {
                 ),
                 Cell::from(
                     Line::from(vec![
-                        Span::styled(beta_legend, Style::default().fg(VERY_LIGHT_AZURE)),
+                        Span::styled(beta_legend.clone(), Style::default().fg(VERY_LIGHT_AZURE)),
                         Span::styled(beta_key, Style::default().fg(GHOST_WHITE)),
                     ])
                     .alignment(Alignment::Right),
                 ),
             ])],
             &[
-                Constraint::Length(18),
+                Constraint::Length(wallet_label.chars().count() as u16),
                 Constraint::Fill(1),
                 Constraint::Length((beta_legend.len() + beta_key.len()) as u16),
             ],
@@ -235,10 +295,10 @@ impl Component for Options {
         .style(Style::default().fg(GHOST_WHITE));
 
         // Access Logs
-        let logs_legend = " Access Logs ";
+        let logs_legend = format!(" {} ", tr("options-access-logs"));
         let logs_key = " [Ctrl+L] ";
         let block3 = Block::default()
-            .title(" Access Logs ")
+            .title(format!(" {} ", tr("options-access-logs-title")))
             .title_style(Style::default().bold().fg(GHOST_WHITE))
             .style(Style::default().fg(GHOST_WHITE))
             .borders(Borders::ALL)
@@ -247,14 +307,14 @@ impl Component for Options {
             vec![Row::new(vec![
                 Cell::from(
                     Line::from(vec![Span::styled(
-                        " Open the Logs folder on this device ",
+                        format!(" {} ", tr("options-open-logs-folder")),
                         Style::default().fg(LIGHT_PERIWINKLE),
                     )])
                     .alignment(Alignment::Left),
                 ),
                 Cell::from(
                     Line::from(vec![
-                        Span::styled(logs_legend, Style::default().fg(VERY_LIGHT_AZURE)),
+                        Span::styled(logs_legend.clone(), Style::default().fg(VERY_LIGHT_AZURE)),
                         Span::styled(logs_key, Style::default().fg(GHOST_WHITE)),
                     ])
                     .alignment(Alignment::Right),
@@ -269,12 +329,12 @@ impl Component for Options {
         .style(Style::default().fg(GHOST_WHITE));
 
         // Update Nodes
-        let reset_legend = " Begin Reset ";
+        let reset_legend = format!(" {} ", tr("options-begin-reset"));
         let reset_key = " [Ctrl+R] ";
-        let upgrade_legend = " Begin Upgrade ";
+        let upgrade_legend = format!(" {} ", tr("options-begin-upgrade"));
         let upgrade_key = " [Ctrl+U] ";
         let block4 = Block::default()
-            .title(" Update Nodes ")
+            .title(format!(" {} ", tr("options-update-nodes-title")))
             .title_style(Style::default().bold().fg(GHOST_WHITE))
             .style(Style::default().fg(GHOST_WHITE))
             .borders(Borders::ALL)
@@ -284,14 +344,14 @@ impl Component for Options {
                 Row::new(vec![
                     Cell::from(
                         Line::from(vec![Span::styled(
-                            " Upgrade all Nodes ",
+                            format!(" {} ", tr("options-upgrade-all-nodes")),
                             Style::default().fg(LIGHT_PERIWINKLE),
                         )])
                         .alignment(Alignment::Left),
                     ),
                     Cell::from(
                         Line::from(vec![
-                            Span::styled(upgrade_legend, Style::default().fg(EUCALYPTUS)),
+                            Span::styled(upgrade_legend.clone(), Style::default().fg(EUCALYPTUS)),
                             Span::styled(upgrade_key, Style::default().fg(GHOST_WHITE)),
                         ])
                         .alignment(Alignment::Right),
@@ -300,14 +360,14 @@ impl Component for Options {
                 Row::new(vec![
                     Cell::from(
                         Line::from(vec![Span::styled(
-                            " Reset all Nodes on this device ",
+                            format!(" {} ", tr("options-reset-all-nodes")),
                             Style::default().fg(LIGHT_PERIWINKLE),
                         )])
                         .alignment(Alignment::Left),
                     ),
                     Cell::from(
                         Line::from(vec![
-                            Span::styled(reset_legend, Style::default().fg(EUCALYPTUS)),
+                            Span::styled(reset_legend.clone(), Style::default().fg(EUCALYPTUS)),
                             Span::styled(reset_key, Style::default().fg(GHOST_WHITE)),
                         ])
                         .alignment(Alignment::Right),
@@ -326,7 +386,7 @@ impl Component for Options {
         .style(Style::default().fg(GHOST_WHITE));
 
         // Quit
-        let quit_legend = "Quit ";
+        let quit_legend = format!("{} ", tr("options-quit"));
         let quit_key = "[Q] ";
         let block5 = Block::default()
             .style(Style::default().fg(GHOST_WHITE))
@@ -336,14 +396,14 @@ impl Component for Options {
             vec![Row::new(vec![
                 Cell::from(
                     Line::from(vec![Span::styled(
-                        " Close Launchpad (your nodes will keep running in the background) ",
+                        format!(" {} ", tr("options-close-launchpad")),
                         Style::default().fg(LIGHT_PERIWINKLE),
                     )])
                     .alignment(Alignment::Left),
                 ),
                 Cell::from(
                     Line::from(vec![
-                        Span::styled(quit_legend, Style::default().fg(VIVID_SKY_BLUE)),
+                        Span::styled(quit_legend.clone(), Style::default().fg(VIVID_SKY_BLUE)),
                         Span::styled(quit_key, Style::default().fg(GHOST_WHITE)),
                     ])
                     .alignment(Alignment::Right),
@@ -421,6 +481,12 @@ impl Component for Options {
                 OptionsActions::TriggerResetNodes => {
                     return Ok(Some(Action::SwitchScene(Scene::ResetNodesPopUp)))
                 }
+                OptionsActions::TriggerChangeLanguage => {
+                    self.language = self.language.next();
+                    i18n::set_language(self.language);
+                    debug!("Language changed to {:?}", self.language);
+                    return Ok(Some(Action::StoreLanguage(self.language)));
+                }
                 _ => {}
             },
             _ => {}
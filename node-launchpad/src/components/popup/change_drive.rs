@@ -28,6 +28,7 @@ use crate::{
         Component,
     },
     config::get_launchpad_nodes_data_dir_path,
+    i18n::tr,
     mode::{InputMode, Scene},
     style::{
         clear_area, COOL_GREY, DARK_GUNMETAL, EUCALYPTUS, GHOST_WHITE, INDIGO, LIGHT_PERIWINKLE,
@@ -170,7 +171,7 @@ impl ChangeDrivePopup {
         let pop_up_border = Paragraph::new("").block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Select a Drive ")
+                .title(format!(" {} ", tr("change-drive-title")))
                 .bold()
                 .title_style(Style::new().fg(VIVID_SKY_BLUE))
                 .padding(Padding::uniform(2))
@@ -221,7 +222,7 @@ impl ChangeDrivePopup {
                 .split(layer_two[2]);
 
         let button_no = Line::from(vec![Span::styled(
-            "Cancel [Esc]",
+            format!("{} [Esc]", tr("popup-cancel")),
             Style::default().fg(LIGHT_PERIWINKLE),
         )]);
 
@@ -234,7 +235,7 @@ impl ChangeDrivePopup {
 
         let button_yes = Line::from(vec![
             Span::styled(
-                "Change Drive ",
+                format!("{} ", tr("change-drive-select")),
                 if self.can_select {
                     Style::default().fg(EUCALYPTUS)
                 } else {
@@ -265,7 +266,7 @@ impl ChangeDrivePopup {
         let pop_up_border = Paragraph::new("").block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Confirm & Reset ")
+                .title(format!(" {} ", tr("popup-confirm-reset-title")))
                 .bold()
                 .title_style(Style::new().fg(VIVID_SKY_BLUE))
                 .padding(Padding::uniform(2))
@@ -292,28 +293,46 @@ impl ChangeDrivePopup {
             Line::from(vec![]), // Empty line
             Line::from(vec![]), // Empty line
             Line::from(vec![
-                Span::styled("Changing storage to ", Style::default().fg(GHOST_WHITE)),
+                Span::styled(
+                    format!("{} ", tr("change-drive-changing-storage-to")),
+                    Style::default().fg(GHOST_WHITE),
+                ),
                 Span::styled(
                     format!("{} ", self.drive_selection.name),
                     Style::default().fg(VIVID_SKY_BLUE),
                 ),
-                Span::styled("will ", Style::default().fg(GHOST_WHITE)),
+                Span::styled(
+                    format!("{} ", tr("change-drive-will")),
+                    Style::default().fg(GHOST_WHITE),
+                ),
             ])
             .alignment(Alignment::Center),
             Line::from(vec![Span::styled(
-                "reset all nodes.",
+                tr("popup-reset-all-nodes"),
                 Style::default().fg(GHOST_WHITE),
             )])
             .alignment(Alignment::Center),
             Line::from(vec![]), // Empty line
             Line::from(vec![]), // Empty line
             Line::from(vec![
-                Span::styled("You’ll need to ", Style::default().fg(GHOST_WHITE)),
-                Span::styled("Add ", Style::default().fg(GHOST_WHITE).bold()),
-                Span::styled("and ", Style::default().fg(GHOST_WHITE)),
-                Span::styled("Start ", Style::default().fg(GHOST_WHITE).bold()),
                 Span::styled(
-                    "them again afterwards. Are you sure you want to continue?",
+                    format!("{} ", tr("popup-you-need-to")),
+                    Style::default().fg(GHOST_WHITE),
+                ),
+                Span::styled(
+                    format!("{} ", tr("popup-add")),
+                    Style::default().fg(GHOST_WHITE).bold(),
+                ),
+                Span::styled(
+                    format!("{} ", tr("popup-and")),
+                    Style::default().fg(GHOST_WHITE),
+                ),
+                Span::styled(
+                    format!("{} ", tr("popup-start")),
+                    Style::default().fg(GHOST_WHITE).bold(),
+                ),
+                Span::styled(
+                    tr("popup-them-again-continue"),
                     Style::default().fg(GHOST_WHITE),
                 ),
             ])
@@ -343,7 +362,7 @@ impl ChangeDrivePopup {
                 .split(layer_two[2]);
 
         let button_no = Line::from(vec![Span::styled(
-            "Back [Esc]",
+            format!("{} [Esc]", tr("popup-back")),
             Style::default().fg(LIGHT_PERIWINKLE),
         )]);
 
@@ -355,7 +374,10 @@ impl ChangeDrivePopup {
         );
 
         let button_yes = Line::from(vec![
-            Span::styled("Yes, change drive ", Style::default().fg(EUCALYPTUS)),
+            Span::styled(
+                format!("{} ", tr("change-drive-confirm")),
+                Style::default().fg(EUCALYPTUS),
+            ),
             Span::styled("[Enter]", Style::default().fg(LIGHT_PERIWINKLE).bold()),
         ])
         .alignment(Alignment::Right);
@@ -640,8 +662,9 @@ impl DriveItem {
                 Span::styled(self.size.clone(), Style::default().fg(COOL_GREY)),
             ]),
             DriveStatus::NotAvailable => {
-                let legend = "No Access";
-                let spaces = width - self.name.len() - legend.len() - "   ".len() - 4;
+                let legend = tr("change-drive-no-access");
+                let spaces = width
+                    .saturating_sub(self.name.len() + legend.chars().count() + "   ".len() + 4);
                 Line::from(vec![
                     Span::raw("   "),
                     Span::styled(self.name.clone(), Style::default().fg(COOL_GREY)),
@@ -26,6 +26,7 @@ use crate::{
     action::{Action, OptionsActions},
     components::Component,
     connection_mode::ConnectionMode,
+    i18n::tr,
     mode::{InputMode, Scene},
     style::{
         clear_area, COOL_GREY, DARK_GUNMETAL, EUCALYPTUS, GHOST_WHITE, INDIGO, LIGHT_PERIWINKLE,
@@ -129,7 +130,7 @@ impl ChangeConnectionModePopUp {
         let pop_up_border: Paragraph = Paragraph::new("").block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Connection Mode ")
+                .title(format!(" {} ", tr("connection-mode-title")))
                 .bold()
                 .title_style(Style::new().fg(VIVID_SKY_BLUE))
                 .padding(Padding::uniform(2))
@@ -180,7 +181,7 @@ impl ChangeConnectionModePopUp {
                 .split(layer_two[2]);
 
         let button_no = Line::from(vec![Span::styled(
-            "Cancel [Esc]",
+            format!("{} [Esc]", tr("popup-cancel")),
             Style::default().fg(LIGHT_PERIWINKLE),
         )]);
 
@@ -193,7 +194,7 @@ impl ChangeConnectionModePopUp {
 
         let button_yes = Line::from(vec![
             Span::styled(
-                "Select ",
+                format!("{} ", tr("popup-select")),
                 if self.can_select {
                     Style::default().fg(EUCALYPTUS)
                 } else {
@@ -224,7 +225,7 @@ impl ChangeConnectionModePopUp {
         let pop_up_border = Paragraph::new("").block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Confirm & Reset ")
+                .title(format!(" {} ", tr("popup-confirm-reset-title")))
                 .bold()
                 .title_style(Style::new().fg(VIVID_SKY_BLUE))
                 .padding(Padding::uniform(2))
@@ -251,21 +252,30 @@ impl ChangeConnectionModePopUp {
             Line::from(Span::styled("\n\n", Style::default())),
             Line::from(vec![
                 Span::styled(
-                    "Changing connection mode will ",
+                    format!("{} ", tr("popup-changing-connection-mode")),
                     Style::default().fg(LIGHT_PERIWINKLE),
                 ),
-                Span::styled("reset all nodes.", Style::default().fg(GHOST_WHITE)),
+                Span::styled(
+                    tr("popup-reset-all-nodes"),
+                    Style::default().fg(GHOST_WHITE),
+                ),
             ]),
             Line::from(Span::styled("\n\n", Style::default())),
             Line::from(Span::styled("\n\n", Style::default())),
             Line::from(Span::styled("\n\n", Style::default())),
             Line::from(vec![
-                Span::styled("You’ll need to ", Style::default().fg(LIGHT_PERIWINKLE)),
-                Span::styled("Add", Style::default().fg(GHOST_WHITE)),
-                Span::styled(" and ", Style::default().fg(LIGHT_PERIWINKLE)),
-                Span::styled("Start", Style::default().fg(GHOST_WHITE)),
                 Span::styled(
-                    " them again afterwards. Are you sure you want to continue?",
+                    format!("{} ", tr("popup-you-need-to")),
+                    Style::default().fg(LIGHT_PERIWINKLE),
+                ),
+                Span::styled(tr("popup-add"), Style::default().fg(GHOST_WHITE)),
+                Span::styled(
+                    format!(" {} ", tr("popup-and")),
+                    Style::default().fg(LIGHT_PERIWINKLE),
+                ),
+                Span::styled(tr("popup-start"), Style::default().fg(GHOST_WHITE)),
+                Span::styled(
+                    format!(" {}", tr("popup-them-again-continue")),
                     Style::default().fg(LIGHT_PERIWINKLE),
                 ),
             ]),
@@ -286,7 +296,7 @@ impl ChangeConnectionModePopUp {
                 .split(layer_two[2]);
 
         let button_no = Line::from(vec![Span::styled(
-            "  Cancel [Esc]",
+            format!("  {} [Esc]", tr("popup-cancel")),
             Style::default().fg(LIGHT_PERIWINKLE),
         )]);
         let button_yes_style = if self.can_select {
@@ -297,7 +307,10 @@ impl ChangeConnectionModePopUp {
         f.render_widget(button_no, buttons_layer[0]);
 
         let button_yes = Line::from(vec![
-            Span::styled("Yes, Change Mode ", button_yes_style),
+            Span::styled(
+                format!("{} ", tr("popup-change-mode-confirm")),
+                button_yes_style,
+            ),
             Span::styled("[Enter]", Style::default().fg(GHOST_WHITE)),
         ]);
         f.render_widget(button_yes, buttons_layer[1]);
@@ -17,6 +17,7 @@ use tui_input::{backend::crossterm::EventHandler, Input};
 
 use crate::{
     action::Action,
+    i18n::{tr, tr_args},
     mode::{InputMode, Scene},
     style::{clear_area, EUCALYPTUS, GHOST_WHITE, LIGHT_PERIWINKLE, VIVID_SKY_BLUE},
 };
@@ -218,7 +219,7 @@ impl Component for ManageNodes {
         let pop_up_border = Paragraph::new("").block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Manage Nodes ")
+                .title(format!(" {} ", tr("manage-nodes-title")))
                 .bold()
                 .title_style(Style::new().fg(GHOST_WHITE))
                 .title_style(Style::new().fg(EUCALYPTUS))
@@ -228,24 +229,26 @@ impl Component for ManageNodes {
         clear_area(f, layer_zero);
 
         // ==== input field ====
+        let start_text = format!("{} ", tr("manage-nodes-start"));
+        let nodes_text = tr("manage-nodes-nodes");
         let layer_input_field = Layout::new(
             Direction::Horizontal,
             [
                 // for the gap
                 Constraint::Min(5),
                 // Start
-                Constraint::Length(5),
+                Constraint::Length(start_text.chars().count() as u16 - 1),
                 // Input box
                 Constraint::Length(5),
                 // Nodes(s)
-                Constraint::Length(8),
+                Constraint::Length(nodes_text.chars().count() as u16 + 1),
                 // gap
                 Constraint::Min(5),
             ],
         )
         .split(layer_one[1]);
 
-        let start = Paragraph::new("Start ").style(Style::default().fg(GHOST_WHITE));
+        let start = Paragraph::new(start_text).style(Style::default().fg(GHOST_WHITE));
         f.render_widget(start, layer_input_field[1]);
 
         let width = layer_input_field[2].width.max(3) - 3;
@@ -257,20 +260,23 @@ impl Component for ManageNodes {
 
         f.render_widget(input, layer_input_field[2]);
 
-        let nodes_text = Paragraph::new("Node(s)").fg(GHOST_WHITE);
+        let nodes_text = Paragraph::new(nodes_text).fg(GHOST_WHITE);
         f.render_widget(nodes_text, layer_input_field[3]);
 
         // ==== info field ====
         let available_space_gb = self.available_disk_space_gb;
         let info_style = Style::default().fg(VIVID_SKY_BLUE);
         let info = Line::from(vec![
-            Span::styled("Using", info_style),
+            Span::styled(tr("manage-nodes-using"), info_style),
             Span::styled(
                 format!(" {}GB ", self.get_nodes_to_start_val() * GB_PER_NODE),
                 info_style.bold(),
             ),
             Span::styled(
-                format!("of {available_space_gb}GB available space"),
+                tr_args(
+                    "manage-nodes-available-space",
+                    &[("available_space_gb", available_space_gb.to_string())],
+                ),
                 info_style,
             ),
         ]);
@@ -279,12 +285,12 @@ impl Component for ManageNodes {
 
         // ==== help ====
         let help = Paragraph::new(vec![
-            Line::raw(format!(
-                "Note: Each node will use a small amount of CPU Memory and Network Bandwidth. \
-                 We recommend starting no more than 5 at a time (max {MAX_NODE_COUNT} nodes)."
+            Line::raw(tr_args(
+                "manage-nodes-note",
+                &[("max_node_count", MAX_NODE_COUNT.to_string())],
             )),
             Line::raw(""),
-            Line::raw("▲▼ to change the number of nodes to start."),
+            Line::raw(tr("manage-nodes-change-hint")),
         ])
         .wrap(Wrap { trim: false })
         .block(Block::default().padding(Padding::horizontal(4)))
@@ -304,12 +310,12 @@ impl Component for ManageNodes {
                 .split(layer_one[6]);
 
         let button_no = Line::from(vec![Span::styled(
-            "  Close [Esc]",
+            format!("  {} [Esc]", tr("popup-close")),
             Style::default().fg(LIGHT_PERIWINKLE),
         )]);
         f.render_widget(button_no, buttons_layer[0]);
         let button_yes = Line::from(vec![Span::styled(
-            "Start Node(s) [Enter]  ",
+            format!("{} [Enter]  ", tr("manage-nodes-confirm")),
             Style::default().fg(EUCALYPTUS),
         )]);
         let button_yes = Paragraph::new(button_yes).alignment(Alignment::Right);
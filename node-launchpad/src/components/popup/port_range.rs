@@ -16,6 +16,7 @@ use crate::style::RED;
 use crate::{
     action::{Action, OptionsActions},
     connection_mode::ConnectionMode,
+    i18n::{tr, tr_args},
     mode::{InputMode, Scene},
     style::{clear_area, EUCALYPTUS, GHOST_WHITE, INDIGO, LIGHT_PERIWINKLE, VIVID_SKY_BLUE},
 };
@@ -90,7 +91,7 @@ impl PortRangePopUp {
         let pop_up_border = Paragraph::new("").block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Custom Ports ")
+                .title(format!(" {} ", tr("port-range-title")))
                 .bold()
                 .title_style(Style::new().fg(VIVID_SKY_BLUE))
                 .padding(Padding::uniform(2))
@@ -116,7 +117,7 @@ impl PortRangePopUp {
         )
         .split(layer_one[1]);
 
-        let prompt = Paragraph::new("Enter Port Number")
+        let prompt = Paragraph::new(tr("port-range-prompt"))
             .bold()
             .alignment(Alignment::Center);
 
@@ -132,7 +133,10 @@ impl PortRangePopUp {
                     .bg(INDIGO)
                     .underlined(),
             ),
-            Span::styled(" to ", Style::default().fg(GHOST_WHITE)),
+            Span::styled(
+                format!(" {} ", tr("port-range-to")),
+                Style::default().fg(GHOST_WHITE),
+            ),
             Span::styled(self.port_to.value(), Style::default().fg(LIGHT_PERIWINKLE)),
         ])
         .alignment(Alignment::Center);
@@ -141,14 +145,17 @@ impl PortRangePopUp {
 
         let text = Paragraph::new(vec![
             Line::from(Span::styled(
-                format!(
-                    "Choose the start of the range of {} ports.",
-                    PORT_ALLOCATION + 1
+                tr_args(
+                    "port-range-choose-start",
+                    &[("ports", (PORT_ALLOCATION + 1).to_string())],
                 ),
                 Style::default().fg(LIGHT_PERIWINKLE),
             )),
             Line::from(Span::styled(
-                format!("This must be between {} and {}.", PORT_MIN, PORT_MAX),
+                tr_args(
+                    "port-range-must-be-between",
+                    &[("min", PORT_MIN.to_string()), ("max", PORT_MAX.to_string())],
+                ),
                 Style::default().fg(if self.can_save { LIGHT_PERIWINKLE } else { RED }),
             )),
         ])
@@ -167,7 +174,7 @@ impl PortRangePopUp {
                 .split(layer_two[4]);
 
         let button_no = Line::from(vec![Span::styled(
-            "  Cancel [Esc]",
+            format!("  {} [Esc]", tr("popup-cancel")),
             Style::default().fg(LIGHT_PERIWINKLE),
         )]);
         let button_yes_style = if self.can_save {
@@ -178,7 +185,10 @@ impl PortRangePopUp {
         f.render_widget(button_no, buttons_layer[0]);
 
         let button_yes = Line::from(vec![
-            Span::styled("Save Port Range ", button_yes_style),
+            Span::styled(
+                format!("{} ", tr("port-range-save-confirm")),
+                button_yes_style,
+            ),
             Span::styled("[Enter]", Style::default().fg(GHOST_WHITE)),
         ]);
         f.render_widget(button_yes, buttons_layer[1]);
@@ -197,7 +207,7 @@ impl PortRangePopUp {
         let pop_up_border = Paragraph::new("").block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Confirm & Reset ")
+                .title(format!(" {} ", tr("popup-confirm-reset-title")))
                 .bold()
                 .title_style(Style::new().fg(VIVID_SKY_BLUE))
                 .padding(Padding::uniform(2))
@@ -224,21 +234,30 @@ impl PortRangePopUp {
             Line::from(Span::styled("\n\n", Style::default())),
             Line::from(vec![
                 Span::styled(
-                    "Changing connection mode will ",
+                    format!("{} ", tr("popup-changing-connection-mode")),
                     Style::default().fg(LIGHT_PERIWINKLE),
                 ),
-                Span::styled("reset all nodes.", Style::default().fg(GHOST_WHITE)),
+                Span::styled(
+                    tr("popup-reset-all-nodes"),
+                    Style::default().fg(GHOST_WHITE),
+                ),
             ]),
             Line::from(Span::styled("\n\n", Style::default())),
             Line::from(Span::styled("\n\n", Style::default())),
             Line::from(Span::styled("\n\n", Style::default())),
             Line::from(vec![
-                Span::styled("You’ll need to ", Style::default().fg(LIGHT_PERIWINKLE)),
-                Span::styled("Add", Style::default().fg(GHOST_WHITE)),
-                Span::styled(" and ", Style::default().fg(LIGHT_PERIWINKLE)),
-                Span::styled("Start", Style::default().fg(GHOST_WHITE)),
                 Span::styled(
-                    " them again afterwards. Are you sure you want to continue?",
+                    format!("{} ", tr("popup-you-need-to")),
+                    Style::default().fg(LIGHT_PERIWINKLE),
+                ),
+                Span::styled(tr("popup-add"), Style::default().fg(GHOST_WHITE)),
+                Span::styled(
+                    format!(" {} ", tr("popup-and")),
+                    Style::default().fg(LIGHT_PERIWINKLE),
+                ),
+                Span::styled(tr("popup-start"), Style::default().fg(GHOST_WHITE)),
+                Span::styled(
+                    format!(" {}", tr("popup-them-again-continue")),
                     Style::default().fg(LIGHT_PERIWINKLE),
                 ),
             ]),
@@ -259,7 +278,7 @@ impl PortRangePopUp {
                 .split(layer_two[2]);
 
         let button_no = Line::from(vec![Span::styled(
-            "  Cancel [Esc]",
+            format!("  {} [Esc]", tr("popup-cancel")),
             Style::default().fg(LIGHT_PERIWINKLE),
         )]);
         let button_yes_style = if self.can_save {
@@ -270,7 +289,10 @@ impl PortRangePopUp {
         f.render_widget(button_no, buttons_layer[0]);
 
         let button_yes = Line::from(vec![
-            Span::styled("Yes, Change Mode ", button_yes_style),
+            Span::styled(
+                format!("{} ", tr("popup-change-mode-confirm")),
+                button_yes_style,
+            ),
             Span::styled("[Enter]", Style::default().fg(GHOST_WHITE)),
         ]);
         f.render_widget(button_yes, buttons_layer[1]);
@@ -289,7 +311,7 @@ impl PortRangePopUp {
         let pop_up_border = Paragraph::new("").block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Port Forwarding For Private IPs ")
+                .title(format!(" {} ", tr("port-range-forwarding-title")))
                 .bold()
                 .title_style(Style::new().fg(VIVID_SKY_BLUE))
                 .padding(Padding::uniform(2))
@@ -312,15 +334,30 @@ impl PortRangePopUp {
         .split(layer_one[1]);
 
         let paragraph_text = Paragraph::new(vec![
-            Line::from(Span::styled("\n\n",Style::default())),
-            Line::from(Span::styled("If you have a Private IP (which you probably do) you’ll now need to set your router to…\n\n", Style::default().fg(LIGHT_PERIWINKLE))),
-            Line::from(Span::styled("\n\n",Style::default())),
+            Line::from(Span::styled("\n\n", Style::default())),
+            Line::from(Span::styled(
+                format!("{}\n\n", tr("port-range-private-ip")),
+                Style::default().fg(LIGHT_PERIWINKLE),
+            )),
+            Line::from(Span::styled("\n\n", Style::default())),
             Line::from(Span::styled(
-                format!("Port Forward ports {}-{} ", self.port_from.value(), self.port_to.value()),
+                format!(
+                    "{} ",
+                    tr_args(
+                        "port-range-forward-ports",
+                        &[
+                            ("from", self.port_from.value().to_string()),
+                            ("to", self.port_to.value().to_string())
+                        ]
+                    )
+                ),
                 Style::default().fg(GHOST_WHITE),
             )),
-            Line::from(Span::styled("\n\n",Style::default())),
-            Line::from(Span::styled("You can do this in your router’s admin panel.\n\n", Style::default().fg(LIGHT_PERIWINKLE))),
+            Line::from(Span::styled("\n\n", Style::default())),
+            Line::from(Span::styled(
+                format!("{}\n\n", tr("port-range-router-admin")),
+                Style::default().fg(LIGHT_PERIWINKLE),
+            )),
         ])
         .alignment(Alignment::Left)
         .wrap(Wrap { trim: true })
@@ -338,7 +375,10 @@ impl PortRangePopUp {
                 .split(layer_two[2]);
 
         let button_ok = Line::from(vec![
-            Span::styled("OK ", Style::default().fg(EUCALYPTUS)),
+            Span::styled(
+                format!("{} ", tr("popup-ok")),
+                Style::default().fg(EUCALYPTUS),
+            ),
             Span::styled("[Enter]   ", Style::default().fg(GHOST_WHITE)),
         ])
         .alignment(Alignment::Right);
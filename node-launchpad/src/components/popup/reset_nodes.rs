@@ -9,6 +9,7 @@
 use super::super::{utils::centered_rect_fixed, Component};
 use crate::{
     action::{Action, OptionsActions},
+    i18n::tr,
     mode::{InputMode, Scene},
     style::{clear_area, EUCALYPTUS, GHOST_WHITE, INDIGO, LIGHT_PERIWINKLE, VIVID_SKY_BLUE},
 };
@@ -117,7 +118,7 @@ impl Component for ResetNodesPopup {
         let pop_up_border = Paragraph::new("").block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Reset Nodes ")
+                .title(format!(" {} ", tr("reset-nodes-title")))
                 .bold()
                 .title_style(Style::new().fg(VIVID_SKY_BLUE))
                 .padding(Padding::uniform(2))
@@ -143,7 +144,7 @@ impl Component for ResetNodesPopup {
         )
         .split(layer_one[1]);
 
-        let prompt = Paragraph::new("Type in 'reset' and press Enter to Reset all your nodes")
+        let prompt = Paragraph::new(tr("reset-nodes-prompt"))
             .wrap(Wrap { trim: false })
             .block(Block::new().padding(Padding::horizontal(2)))
             .alignment(Alignment::Center)
@@ -162,7 +163,7 @@ impl Component for ResetNodesPopup {
 
         f.render_widget(input, layer_two[1]);
 
-        let text = Paragraph::new("This will clear out all the nodes and all the stored data. You should still keep all your earned rewards.")
+        let text = Paragraph::new(tr("reset-nodes-warning"))
             .wrap(Wrap { trim: false })
             .block(Block::new().padding(Padding::horizontal(2)))
             .alignment(Alignment::Center)
@@ -179,7 +180,7 @@ impl Component for ResetNodesPopup {
                 .split(layer_two[4]);
 
         let button_no = Line::from(vec![Span::styled(
-            "No, Cancel [Esc]",
+            format!("{} [Esc]", tr("popup-no-cancel")),
             Style::default().fg(LIGHT_PERIWINKLE),
         )]);
 
@@ -191,7 +192,7 @@ impl Component for ResetNodesPopup {
         );
 
         let button_yes = Line::from(vec![Span::styled(
-            "Reset Nodes [Enter]",
+            format!("{} [Enter]", tr("reset-nodes-confirm")),
             if self.can_reset {
                 Style::default().fg(EUCALYPTUS)
             } else {
@@ -10,6 +10,7 @@ use super::super::utils::centered_rect_fixed;
 use super::super::Component;
 use crate::{
     action::{Action, OptionsActions},
+    i18n::tr,
     mode::{InputMode, Scene},
     style::{clear_area, EUCALYPTUS, GHOST_WHITE, INDIGO, LIGHT_PERIWINKLE, RED, VIVID_SKY_BLUE},
     widgets::hyperlink::Hyperlink,
@@ -226,7 +227,7 @@ impl Component for RewardsAddress {
         let pop_up_border = Paragraph::new("").block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Add Your Wallet ")
+                .title(format!(" {} ", tr("rewards-address-title")))
                 .bold()
                 .title_style(Style::new().fg(VIVID_SKY_BLUE))
                 .padding(Padding::uniform(2))
@@ -256,8 +257,14 @@ impl Component for RewardsAddress {
                 .split(layer_one[1]);
 
                 let prompt_text = Paragraph::new(Line::from(vec![
-                    Span::styled("Enter new ".to_string(), Style::default()),
-                    Span::styled("Wallet Address".to_string(), Style::default().bold()),
+                    Span::styled(
+                        format!("{} ", tr("rewards-address-enter-new")),
+                        Style::default(),
+                    ),
+                    Span::styled(
+                        tr("rewards-address-wallet-address"),
+                        Style::default().bold(),
+                    ),
                 ]))
                 .block(Block::default())
                 .alignment(Alignment::Center)
@@ -281,12 +288,12 @@ impl Component for RewardsAddress {
 
                 let text = Paragraph::new(Text::from(if self.can_save {
                     vec![
-                        Line::raw("Changing your Wallet will reset and restart"),
-                        Line::raw("all your nodes."),
+                        Line::raw(tr("rewards-address-change-warning")),
+                        Line::raw(tr("rewards-address-change-warning-nodes")),
                     ]
                 } else {
                     vec![Line::from(Span::styled(
-                        "Invalid wallet address".to_string(),
+                        tr("rewards-address-invalid"),
                         Style::default().fg(RED),
                     ))]
                 }))
@@ -311,14 +318,14 @@ impl Component for RewardsAddress {
                 .split(layer_two[4]);
 
                 let button_no = Line::from(vec![Span::styled(
-                    "  Cancel [Esc]",
+                    format!("  {} [Esc]", tr("popup-cancel")),
                     Style::default().fg(LIGHT_PERIWINKLE),
                 )]);
 
                 f.render_widget(button_no, buttons_layer[0]);
 
                 let button_yes = Line::from(vec![Span::styled(
-                    "Change Wallet [Enter]",
+                    format!("{} [Enter]", tr("rewards-address-change-confirm")),
                     if self.can_save {
                         Style::default().fg(EUCALYPTUS)
                     } else {
@@ -345,12 +352,17 @@ impl Component for RewardsAddress {
                 .split(layer_one[1]);
 
                 let text = Paragraph::new(vec![
-                    Line::from(Span::styled("Add your wallet to store your node earnings, and we'll pay you rewards to the same wallet after the Network's Token Generation Event.",Style::default())),
-                    Line::from(Span::styled("\n\n",Style::default())),
-                    Line::from(Span::styled("By continuing you agree to the Terms and Conditions found here:",Style::default())),
-                    Line::from(Span::styled("\n\n",Style::default())),
-                    ]
-                )
+                    Line::from(Span::styled(
+                        tr("rewards-address-tcs-intro"),
+                        Style::default(),
+                    )),
+                    Line::from(Span::styled("\n\n", Style::default())),
+                    Line::from(Span::styled(
+                        tr("rewards-address-tcs-agree"),
+                        Style::default(),
+                    )),
+                    Line::from(Span::styled("\n\n", Style::default())),
+                ])
                 .block(Block::default().padding(Padding::horizontal(2)))
                 .wrap(Wrap { trim: false });
 
@@ -378,13 +390,13 @@ impl Component for RewardsAddress {
                 .split(layer_two[3]);
 
                 let button_no = Line::from(vec![Span::styled(
-                    "  No, Cancel [Esc]",
+                    format!("  {} [Esc]", tr("popup-no-cancel")),
                     Style::default().fg(LIGHT_PERIWINKLE),
                 )]);
                 f.render_widget(button_no, buttons_layer[0]);
 
                 let button_yes = Paragraph::new(Line::from(vec![Span::styled(
-                    "Yes, I agree! Continue [Y]  ",
+                    format!("{} [Y]  ", tr("rewards-address-tcs-accept")),
                     Style::default().fg(EUCALYPTUS),
                 )]))
                 .alignment(Alignment::Right);
@@ -412,8 +424,14 @@ impl Component for RewardsAddress {
                 .split(layer_one[1]);
 
                 let prompt = Paragraph::new(Line::from(vec![
-                    Span::styled("Enter your ", Style::default()),
-                    Span::styled("Wallet Address", Style::default().fg(GHOST_WHITE)),
+                    Span::styled(
+                        format!("{} ", tr("rewards-address-enter-your")),
+                        Style::default(),
+                    ),
+                    Span::styled(
+                        tr("rewards-address-wallet-address"),
+                        Style::default().fg(GHOST_WHITE),
+                    ),
                 ]))
                 .alignment(Alignment::Center);
 
@@ -431,7 +449,7 @@ impl Component for RewardsAddress {
                 f.render_widget(input, layer_two[1]);
 
                 let text = Paragraph::new(vec![Line::from(Span::styled(
-                    "Find out more about compatible wallets, and how to track your earnings:",
+                    tr("rewards-address-find-out-more"),
                     Style::default(),
                 ))])
                 .block(Block::default().padding(Padding::horizontal(2)))
@@ -461,12 +479,12 @@ impl Component for RewardsAddress {
                 .split(layer_two[5]);
 
                 let button_no = Line::from(vec![Span::styled(
-                    "  Cancel [Esc]",
+                    format!("  {} [Esc]", tr("popup-cancel")),
                     Style::default().fg(LIGHT_PERIWINKLE),
                 )]);
                 f.render_widget(button_no, buttons_layer[0]);
                 let button_yes = Paragraph::new(Line::from(vec![Span::styled(
-                    "Save Wallet [Enter]  ",
+                    format!("{} [Enter]  ", tr("rewards-address-save-confirm")),
                     if self.can_save {
                         Style::default().fg(EUCALYPTUS)
                     } else {
@@ -11,6 +11,7 @@ use super::super::Component;
 use crate::{
     action::{Action, OptionsActions},
     components::status,
+    i18n::{tr, tr_args},
     mode::{InputMode, Scene},
     style::{clear_area, EUCALYPTUS, GHOST_WHITE, LIGHT_PERIWINKLE, VIVID_SKY_BLUE},
 };
@@ -101,7 +102,7 @@ impl Component for UpgradeNodesPopUp {
         let pop_up_border = Paragraph::new("").block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Upgrade all nodes ")
+                .title(format!(" {} ", tr("upgrade-nodes-title")))
                 .bold()
                 .title_style(Style::new().fg(VIVID_SKY_BLUE))
                 .padding(Padding::uniform(2))
@@ -126,38 +127,53 @@ impl Component for UpgradeNodesPopUp {
         let text = Paragraph::new(vec![
             Line::from(Span::styled("\n\n", Style::default())),
             Line::from(vec![
-                Span::styled("This will ", Style::default().fg(LIGHT_PERIWINKLE)),
                 Span::styled(
-                    "stop and upgrade all nodes. ",
+                    format!("{} ", tr("upgrade-nodes-this-will")),
+                    Style::default().fg(LIGHT_PERIWINKLE),
+                ),
+                Span::styled(
+                    format!("{} ", tr("upgrade-nodes-stop-and-upgrade")),
                     Style::default().fg(GHOST_WHITE),
                 ),
             ]),
             Line::from(Span::styled(
-                "No data will be lost.",
+                tr("upgrade-nodes-no-data-lost"),
                 Style::default().fg(LIGHT_PERIWINKLE),
             )),
             Line::from(Span::styled(
-                format!(
-                    "Upgrade time ~ {:.1?} mins ({:?} nodes * {:?} secs)",
-                    self.nodes_to_start * (status::FIXED_INTERVAL / 1_000) as usize / 60,
-                    self.nodes_to_start,
-                    status::FIXED_INTERVAL / 1_000,
+                tr_args(
+                    "upgrade-nodes-time",
+                    &[
+                        (
+                            "mins",
+                            format!(
+                                "{:.1?}",
+                                self.nodes_to_start * (status::FIXED_INTERVAL / 1_000) as usize
+                                    / 60
+                            ),
+                        ),
+                        ("nodes", format!("{:?}", self.nodes_to_start)),
+                        ("secs", format!("{:?}", status::FIXED_INTERVAL / 1_000)),
+                    ],
                 ),
                 Style::default().fg(LIGHT_PERIWINKLE),
             )),
             Line::from(Span::styled("\n\n", Style::default())),
             Line::from(vec![
-                Span::styled("You’ll need to ", Style::default().fg(LIGHT_PERIWINKLE)),
-                Span::styled("Start ", Style::default().fg(GHOST_WHITE)),
                 Span::styled(
-                    "them again afterwards.",
+                    format!("{} ", tr("upgrade-nodes-you-need-to")),
+                    Style::default().fg(LIGHT_PERIWINKLE),
+                ),
+                Span::styled(
+                    format!("{} ", tr("upgrade-nodes-start")),
+                    Style::default().fg(GHOST_WHITE),
+                ),
+                Span::styled(
+                    tr("upgrade-nodes-again-afterwards"),
                     Style::default().fg(LIGHT_PERIWINKLE),
                 ),
             ]),
-            Line::from(Span::styled(
-                "Are you sure you want to continue?",
-                Style::default(),
-            )),
+            Line::from(Span::styled(tr("popup-are-you-sure"), Style::default())),
         ])
         .block(Block::default().padding(Padding::horizontal(2)))
         .alignment(Alignment::Center)
@@ -175,13 +191,13 @@ impl Component for UpgradeNodesPopUp {
                 .split(layer_two[2]);
 
         let button_no = Line::from(vec![Span::styled(
-            "  No, Cancel [Esc]",
+            format!("  {} [Esc]", tr("popup-no-cancel")),
             Style::default().fg(LIGHT_PERIWINKLE),
         )]);
         f.render_widget(button_no, buttons_layer[0]);
 
         let button_yes = Paragraph::new(Line::from(vec![Span::styled(
-            "Yes, Upgrade [Enter]  ",
+            format!("{} [Enter]  ", tr("upgrade-nodes-confirm")),
             Style::default().fg(EUCALYPTUS),
         )]))
         .alignment(Alignment::Right);
@@ -18,6 +18,7 @@ use crate::components::utils::open_logs;
 use crate::config::get_launchpad_nodes_data_dir_path;
 use crate::connection_mode::ConnectionMode;
 use crate::error::ErrorPopup;
+use crate::i18n::{tr, tr_args};
 use crate::node_mgmt::{MaintainNodesArgs, NodeManagement, NodeManagementTask, UpgradeNodesArgs};
 use crate::node_mgmt::{PORT_MAX, PORT_MIN};
 use crate::style::{COOL_GREY, INDIGO};
@@ -511,8 +512,8 @@ impl Component for Status<'_> {
                 StatusActions::ErrorLoadingNodeRegistry { raw_error }
                 | StatusActions::ErrorGettingNodeRegistryPath { raw_error } => {
                     self.error_popup = Some(ErrorPopup::new(
-                        "Error".to_string(),
-                        "Error getting node registry path".to_string(),
+                        tr("error-title"),
+                        tr("status-error-registry-path"),
                         raw_error,
                     ));
                     if let Some(error_popup) = &mut self.error_popup {
@@ -523,8 +524,8 @@ impl Component for Status<'_> {
                 }
                 StatusActions::ErrorScalingUpNodes { raw_error } => {
                     self.error_popup = Some(ErrorPopup::new(
-                        "Error".to_string(),
-                        "Error adding new nodes".to_string(),
+                        tr("error-title"),
+                        tr("status-error-adding-nodes"),
                         raw_error,
                     ));
                     if let Some(error_popup) = &mut self.error_popup {
@@ -535,8 +536,8 @@ impl Component for Status<'_> {
                 }
                 StatusActions::ErrorStoppingNodes { raw_error } => {
                     self.error_popup = Some(ErrorPopup::new(
-                        "Error".to_string(),
-                        "Error stopping nodes".to_string(),
+                        tr("error-title"),
+                        tr("status-error-stopping-nodes"),
                         raw_error,
                     ));
                     if let Some(error_popup) = &mut self.error_popup {
@@ -547,8 +548,8 @@ impl Component for Status<'_> {
                 }
                 StatusActions::ErrorUpdatingNodes { raw_error } => {
                     self.error_popup = Some(ErrorPopup::new(
-                        "Error".to_string(),
-                        "Error upgrading nodes".to_string(),
+                        tr("error-title"),
+                        tr("status-error-upgrading-nodes"),
                         raw_error,
                     ));
                     if let Some(error_popup) = &mut self.error_popup {
@@ -559,8 +560,8 @@ impl Component for Status<'_> {
                 }
                 StatusActions::ErrorResettingNodes { raw_error } => {
                     self.error_popup = Some(ErrorPopup::new(
-                        "Error".to_string(),
-                        "Error resetting nodes".to_string(),
+                        tr("error-title"),
+                        tr("status-error-resetting-nodes"),
                         raw_error,
                     ));
                     if let Some(error_popup) = &mut self.error_popup {
@@ -770,7 +771,7 @@ impl Component for Status<'_> {
         // and preserve as much as we can information
 
         let combined_block = Block::default()
-            .title(" Device Status ")
+            .title(format!(" {} ", tr("status-device-title")))
             .bold()
             .title_style(Style::default().fg(GHOST_WHITE))
             .borders(Borders::ALL)
@@ -780,7 +781,7 @@ impl Component for Status<'_> {
         f.render_widget(combined_block.clone(), layout[1]);
 
         let storage_allocated_row = Row::new(vec![
-            Cell::new("Storage Allocated".to_string()).fg(GHOST_WHITE),
+            Cell::new(tr("status-storage-allocated")).fg(GHOST_WHITE),
             Cell::new(format!("{} GB", self.nodes_to_start * GB_PER_NODE)).fg(GHOST_WHITE),
         ]);
         let memory_use_val = if self.node_stats.total_memory_usage_mb as f64 / 1024_f64 > 1.0 {
@@ -793,23 +794,22 @@ impl Component for Status<'_> {
         };
 
         let memory_use_row = Row::new(vec![
-            Cell::new("Memory Use".to_string()).fg(GHOST_WHITE),
+            Cell::new(tr("status-memory-use")).fg(GHOST_WHITE),
             Cell::new(memory_use_val).fg(GHOST_WHITE),
         ]);
 
         let connection_mode_string = match self.connection_mode {
-            ConnectionMode::HomeNetwork => "Home Network",
-            ConnectionMode::UPnP => "UPnP",
-            ConnectionMode::CustomPorts => &format!(
-                "Custom Ports  {}-{}",
+            ConnectionMode::CustomPorts => format!(
+                "{}  {}-{}",
+                self.connection_mode,
                 self.port_from.unwrap_or(PORT_MIN),
                 self.port_to.unwrap_or(PORT_MIN + PORT_ALLOCATION)
             ),
-            ConnectionMode::Automatic => "Automatic",
+            _ => self.connection_mode.to_string(),
         };
 
         let connection_mode_row = Row::new(vec![
-            Cell::new("Connection".to_string()).fg(GHOST_WHITE),
+            Cell::new(tr("status-connection")).fg(GHOST_WHITE),
             Cell::new(connection_mode_string).fg(LIGHT_PERIWINKLE),
         ]);
 
@@ -820,14 +820,17 @@ impl Component for Status<'_> {
 
         let wallet_not_set = if self.rewards_address.is_empty() {
             vec![
-                Span::styled("Press ".to_string(), Style::default().fg(VIVID_SKY_BLUE)),
+                Span::styled(
+                    format!("{} ", tr("status-press")),
+                    Style::default().fg(VIVID_SKY_BLUE),
+                ),
                 Span::styled("[Ctrl+B] ".to_string(), Style::default().fg(GHOST_WHITE)),
                 Span::styled(
-                    "to add your ".to_string(),
+                    format!("{} ", tr("status-to-add-your")),
                     Style::default().fg(VIVID_SKY_BLUE),
                 ),
                 Span::styled(
-                    "Wallet Address".to_string(),
+                    tr("status-wallet-address"),
                     Style::default().fg(VIVID_SKY_BLUE).bold(),
                 ),
             ]
@@ -835,8 +838,10 @@ impl Component for Status<'_> {
             vec![]
         };
 
+        let wallet_not_set_width: usize = wallet_not_set.iter().map(|s| s.width()).sum();
+
         let total_attos_earned_and_wallet_row = Row::new(vec![
-            Cell::new("Attos Earned".to_string()).fg(VIVID_SKY_BLUE),
+            Cell::new(tr("status-attos-earned")).fg(VIVID_SKY_BLUE),
             Cell::new(format!(
                 "{:?}",
                 self.node_stats.total_rewards_wallet_balance
@@ -851,11 +856,7 @@ impl Component for Status<'_> {
         let column_constraints = [
             Constraint::Length(23),
             Constraint::Fill(1),
-            Constraint::Length(if self.rewards_address.is_empty() {
-                41 //TODO: make it dynamic with wallet_not_set
-            } else {
-                0
-            }),
+            Constraint::Length(wallet_not_set_width as u16),
         ];
         let attos_wallet_table =
             Table::new(attos_wallet_rows, attos_wallet_width).widths(column_constraints);
@@ -877,19 +878,29 @@ impl Component for Status<'_> {
         if let Some(ref items) = self.items {
             if items.items.is_empty() || self.rewards_address.is_empty() {
                 let line1 = Line::from(vec![
-                    Span::styled("Press ", Style::default().fg(LIGHT_PERIWINKLE)),
+                    Span::styled(
+                        format!("{} ", tr("status-press")),
+                        Style::default().fg(LIGHT_PERIWINKLE),
+                    ),
                     Span::styled("[Ctrl+G] ", Style::default().fg(GHOST_WHITE).bold()),
-                    Span::styled("to Add and ", Style::default().fg(LIGHT_PERIWINKLE)),
-                    Span::styled("Start Nodes ", Style::default().fg(GHOST_WHITE).bold()),
-                    Span::styled("on this device", Style::default().fg(LIGHT_PERIWINKLE)),
+                    Span::styled(
+                        format!("{} ", tr("status-to-add-and")),
+                        Style::default().fg(LIGHT_PERIWINKLE),
+                    ),
+                    Span::styled(
+                        format!("{} ", tr("status-start-nodes")),
+                        Style::default().fg(GHOST_WHITE).bold(),
+                    ),
+                    Span::styled(
+                        tr("status-on-this-device"),
+                        Style::default().fg(LIGHT_PERIWINKLE),
+                    ),
                 ]);
 
                 let line2 = Line::from(vec![Span::styled(
-                    format!(
-                        "Each node will use {}GB of storage and a small amount of memory, \
-                        CPU, and Network bandwidth. Most computers can run many nodes at once, \
-                        but we recommend you add them gradually",
-                        GB_PER_NODE
+                    tr_args(
+                        "status-node-resources",
+                        &[("gb_per_node", GB_PER_NODE.to_string())],
                     ),
                     Style::default().fg(LIGHT_PERIWINKLE),
                 )]);
@@ -901,7 +912,10 @@ impl Component for Status<'_> {
                         .block(
                             Block::default()
                                 .title(Line::from(vec![
-                                    Span::styled(" Nodes", Style::default().fg(GHOST_WHITE).bold()),
+                                    Span::styled(
+                                        format!(" {}", tr("status-nodes-title")),
+                                        Style::default().fg(GHOST_WHITE).bold(),
+                                    ),
                                     Span::styled(" (0) ", Style::default().fg(LIGHT_PERIWINKLE)),
                                 ]))
                                 .title_style(Style::default().fg(LIGHT_PERIWINKLE))
@@ -915,7 +929,10 @@ impl Component for Status<'_> {
                 // Node/s block
                 let block_nodes = Block::default()
                     .title(Line::from(vec![
-                        Span::styled(" Nodes", Style::default().fg(GHOST_WHITE).bold()),
+                        Span::styled(
+                            format!(" {}", tr("status-nodes-title")),
+                            Style::default().fg(GHOST_WHITE).bold(),
+                        ),
                         Span::styled(
                             format!(" ({}) ", self.nodes_to_start),
                             Style::default().fg(LIGHT_PERIWINKLE),
@@ -945,18 +962,18 @@ impl Component for Status<'_> {
 
                 // Header
                 let header_row = Row::new(vec![
-                    Cell::new("Node").fg(COOL_GREY),
-                    Cell::new("Version").fg(COOL_GREY),
-                    Cell::new("Attos").fg(COOL_GREY),
-                    Cell::new("Memory").fg(COOL_GREY),
+                    Cell::new(tr("status-column-node")).fg(COOL_GREY),
+                    Cell::new(tr("status-column-version")).fg(COOL_GREY),
+                    Cell::new(tr("status-column-attos")).fg(COOL_GREY),
+                    Cell::new(tr("status-column-memory")).fg(COOL_GREY),
                     Cell::new(
                         format!("{}{}", " ".repeat(MBPS_WIDTH - "Mbps".len()), "Mbps")
                             .fg(COOL_GREY),
                     ),
-                    Cell::new("Recs").fg(COOL_GREY),
-                    Cell::new("Peers").fg(COOL_GREY),
-                    Cell::new("Conns").fg(COOL_GREY),
-                    Cell::new("Status").fg(COOL_GREY),
+                    Cell::new(tr("status-column-records")).fg(COOL_GREY),
+                    Cell::new(tr("status-column-peers")).fg(COOL_GREY),
+                    Cell::new(tr("status-column-connections")).fg(COOL_GREY),
+                    Cell::new(tr("status-column-status")).fg(COOL_GREY),
                     Cell::new(" ").fg(COOL_GREY), // Spinner
                 ])
                 .style(Style::default().add_modifier(Modifier::BOLD));
@@ -1017,11 +1034,11 @@ impl Component for Status<'_> {
                 LockRegistryState::StartingNodes => {
                     if self.should_we_run_nat_detection() {
                         vec![
-                            Line::raw("Starting nodes..."),
+                            Line::raw(tr("status-starting-nodes")),
                             Line::raw(""),
                             Line::raw(""),
-                            Line::raw("Please wait, performing initial NAT detection"),
-                            Line::raw("This may take a couple minutes."),
+                            Line::raw(tr("status-nat-detection")),
+                            Line::raw(tr("status-nat-detection-wait")),
                         ]
                     } else {
                         // We avoid rendering the popup as we have status lines now
@@ -1033,7 +1050,7 @@ impl Component for Status<'_> {
                         Line::raw(""),
                         Line::raw(""),
                         Line::raw(""),
-                        Line::raw("Stopping nodes..."),
+                        Line::raw(tr("status-stopping-nodes")),
                     ]
                 }
                 LockRegistryState::ResettingNodes => {
@@ -1041,7 +1058,7 @@ impl Component for Status<'_> {
                         Line::raw(""),
                         Line::raw(""),
                         Line::raw(""),
-                        Line::raw("Resetting nodes..."),
+                        Line::raw(tr("status-resetting-nodes")),
                     ]
                 }
                 LockRegistryState::UpdatingNodes => {
@@ -1055,7 +1072,7 @@ impl Component for Status<'_> {
                 let popup_border = Paragraph::new("").block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .title(" Manage Nodes ")
+                        .title(format!(" {} ", tr("status-manage-nodes-title")))
                         .bold()
                         .title_style(Style::new().fg(VIVID_SKY_BLUE))
                         .padding(Padding::uniform(2))
@@ -1169,12 +1186,12 @@ enum NodeStatus {
 impl fmt::Display for NodeStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            NodeStatus::Added => write!(f, "Added"),
-            NodeStatus::Running => write!(f, "Running"),
-            NodeStatus::Starting => write!(f, "Starting"),
-            NodeStatus::Stopped => write!(f, "Stopped"),
-            NodeStatus::Removed => write!(f, "Removed"),
-            NodeStatus::Updating => write!(f, "Updating"),
+            NodeStatus::Added => write!(f, "{}", tr("node-status-added")),
+            NodeStatus::Running => write!(f, "{}", tr("node-status-running")),
+            NodeStatus::Starting => write!(f, "{}", tr("node-status-starting")),
+            NodeStatus::Stopped => write!(f, "{}", tr("node-status-stopped")),
+            NodeStatus::Removed => write!(f, "{}", tr("node-status-removed")),
+            NodeStatus::Updating => write!(f, "{}", tr("node-status-updating")),
         }
     }
 }
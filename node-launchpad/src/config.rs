@@ -7,6 +7,7 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::connection_mode::ConnectionMode;
+use crate::i18n::Language;
 use crate::system::get_primary_mount_point;
 use crate::{action::Action, mode::Scene};
 use color_eyre::eyre::{eyre, Result};
@@ -107,6 +108,7 @@ pub struct AppData {
     pub connection_mode: Option<ConnectionMode>,
     pub port_from: Option<u32>,
     pub port_to: Option<u32>,
+    pub language: Option<Language>,
 }
 
 impl Default for AppData {
@@ -119,6 +121,7 @@ impl Default for AppData {
             connection_mode: None,
             port_from: None,
             port_to: None,
+            language: None,
         }
     }
 }
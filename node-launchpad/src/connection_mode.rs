@@ -3,6 +3,8 @@ use std::fmt::{Display, Formatter, Result};
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
+use crate::i18n::tr;
+
 #[derive(Clone, Copy, Debug, Default, EnumIter, Eq, Hash, PartialEq)]
 pub enum ConnectionMode {
     #[default]
@@ -15,10 +17,10 @@ pub enum ConnectionMode {
 impl Display for ConnectionMode {
     fn fmt(&self, f: &mut Formatter) -> Result {
         match self {
-            ConnectionMode::HomeNetwork => write!(f, "Home Network"),
-            ConnectionMode::UPnP => write!(f, "UPnP"),
-            ConnectionMode::CustomPorts => write!(f, "Custom Ports"),
-            ConnectionMode::Automatic => write!(f, "Automatic"),
+            ConnectionMode::HomeNetwork => write!(f, "{}", tr("connection-mode-home-network")),
+            ConnectionMode::UPnP => write!(f, "{}", tr("connection-mode-upnp")),
+            ConnectionMode::CustomPorts => write!(f, "{}", tr("connection-mode-custom-ports")),
+            ConnectionMode::Automatic => write!(f, "{}", tr("connection-mode-automatic")),
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::{
     components::utils::centered_rect_fixed,
+    i18n::tr,
     style::{clear_area, EUCALYPTUS, GHOST_WHITE, RED},
     tui::Frame,
 };
@@ -168,7 +169,10 @@ impl ErrorPopup {
             Layout::horizontal(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
                 .split(layer_two[3]);
         let button_ok = Line::from(vec![
-            Span::styled("OK ", Style::default().fg(EUCALYPTUS)),
+            Span::styled(
+                format!("{} ", tr("popup-ok")),
+                Style::default().fg(EUCALYPTUS),
+            ),
             Span::styled("[Enter]   ", Style::default().fg(GHOST_WHITE)),
         ])
         .alignment(Alignment::Right);
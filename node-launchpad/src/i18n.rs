@@ -0,0 +1,179 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Translation layer for the strings shown by the launchpad.
+//!
+//! Each locale is a [Fluent](https://projectfluent.org) file under `locales/`, embedded into the binary.
+//! English is the reference locale: any message missing from another locale falls back to it.
+
+use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{LazyLock, RwLock},
+};
+use strum::{EnumIter, IntoEnumIterator};
+use unic_langid::LanguageIdentifier;
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, EnumIter)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Language {
+    /// The BCP 47 identifier of the locale.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en-US",
+            Language::Spanish => "es-ES",
+        }
+    }
+
+    /// The next language, used to cycle through the available languages in the options.
+    pub fn next(&self) -> Self {
+        let languages: Vec<_> = Language::iter().collect();
+        let index = languages.iter().position(|l| l == self).unwrap_or(0);
+        languages[(index + 1) % languages.len()]
+    }
+
+    fn resource(&self) -> &'static str {
+        match self {
+            Language::English => include_str!("../locales/en-US.ftl"),
+            Language::Spanish => include_str!("../locales/es-ES.ftl"),
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    /// Languages are always displayed in their own language, so they can be recognised by everyone.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Language::English => write!(f, "English"),
+            Language::Spanish => write!(f, "Español"),
+        }
+    }
+}
+
+static LANGUAGE: RwLock<Language> = RwLock::new(Language::English);
+
+static BUNDLES: LazyLock<HashMap<Language, FluentBundle<FluentResource>>> = LazyLock::new(|| {
+    Language::iter()
+        .map(|language| (language, build_bundle(language)))
+        .collect()
+});
+
+fn build_bundle(language: Language) -> FluentBundle<FluentResource> {
+    let lang_id: LanguageIdentifier = language.code().parse().unwrap_or_default();
+    let mut bundle = FluentBundle::new_concurrent(vec![lang_id]);
+    // The unicode isolation marks are not rendered properly by terminals.
+    bundle.set_use_isolating(false);
+
+    let resource = match FluentResource::try_new(language.resource().to_string()) {
+        Ok(resource) => resource,
+        Err((resource, errors)) => {
+            error!("Errors while parsing the {language:?} locale: {errors:?}");
+            resource
+        }
+    };
+    if let Err(errors) = bundle.add_resource(resource) {
+        error!("Errors while loading the {language:?} locale: {errors:?}");
+    }
+    bundle
+}
+
+/// Set the language used by [`tr`] and [`tr_args`].
+pub fn set_language(language: Language) {
+    match LANGUAGE.write() {
+        Ok(mut current) => *current = language,
+        Err(err) => error!("Failed to set the language to {language:?}: {err:?}"),
+    }
+}
+
+/// The language currently used to translate strings.
+pub fn current_language() -> Language {
+    LANGUAGE.read().map(|l| *l).unwrap_or_default()
+}
+
+/// Translate the message with the given key into the current language.
+pub fn tr(key: &str) -> String {
+    translate(key, None)
+}
+
+/// Translate the message with the given key into the current language, filling in its arguments.
+pub fn tr_args(key: &str, args: &[(&str, String)]) -> String {
+    let mut fluent_args = FluentArgs::new();
+    for (name, value) in args {
+        fluent_args.set(*name, value.clone());
+    }
+    translate(key, Some(&fluent_args))
+}
+
+fn translate(key: &str, args: Option<&FluentArgs>) -> String {
+    let language = current_language();
+    [language, Language::English]
+        .iter()
+        .find_map(|language| format_message(*language, key, args))
+        .unwrap_or_else(|| {
+            warn!("No translation found for {key:?}");
+            key.to_string()
+        })
+}
+
+fn format_message(language: Language, key: &str, args: Option<&FluentArgs>) -> Option<String> {
+    let bundle = BUNDLES.get(&language)?;
+    let pattern = bundle.get_message(key)?.value()?;
+    let mut errors = vec![];
+    let value = bundle.format_pattern(pattern, args, &mut errors);
+    if !errors.is_empty() {
+        error!("Errors while formatting {key:?} in {language:?}: {errors:?}");
+    }
+    Some(value.into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn message_ids(language: Language) -> BTreeSet<String> {
+        let resource = FluentResource::try_new(language.resource().to_string())
+            .unwrap_or_else(|(_, errors)| panic!("{language:?} does not parse: {errors:?}"));
+        resource
+            .entries()
+            .filter_map(|entry| match entry {
+                fluent_syntax::ast::Entry::Message(message) => Some(message.id.name.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn all_locales_have_the_english_messages() {
+        let english = message_ids(Language::English);
+        for language in Language::iter() {
+            let ids = message_ids(language);
+            let missing: Vec<_> = english.difference(&ids).collect();
+            assert!(missing.is_empty(), "{language:?} is missing {missing:?}");
+            let unknown: Vec<_> = ids.difference(&english).collect();
+            assert!(unknown.is_empty(), "{language:?} has unknown {unknown:?}");
+        }
+    }
+
+    #[test]
+    fn translate_falls_back_to_english_then_to_the_key() {
+        assert_eq!(format_message(Language::Spanish, "no-such-key", None), None);
+        assert_eq!(translate("no-such-key", None), "no-such-key");
+        assert_eq!(
+            format_message(Language::English, "options-language", None),
+            Some("Language:".to_string())
+        );
+    }
+}
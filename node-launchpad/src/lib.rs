@@ -12,6 +12,7 @@ pub mod components;
 pub mod config;
 pub mod connection_mode;
 pub mod error;
+pub mod i18n;
 pub mod mode;
 pub mod node_mgmt;
 pub mod node_stats;
@@ -219,6 +219,7 @@ pub struct UpgradeNodesArgs {
 async fn upgrade_nodes(args: UpgradeNodesArgs) {
     if let Err(err) = ant_node_manager::cmd::node::upgrade(
         args.connection_timeout_s,
+        None,
         args.do_not_start,
         args.custom_bin_path,
         args.force,